@@ -0,0 +1,54 @@
+//! A small `readelf` clone built on the formatters provided by [`elf::display`].
+//!
+//! Usage: `cargo run --example readelf -- <FILE>`
+
+use std::{env, fs, process::ExitCode};
+
+use elf::{
+    class::AnyClass,
+    display::{
+        ElfDynamicDisplay, ElfHeaderDisplay, ElfNotesDisplay, ElfProgramHeaderTableDisplay,
+        ElfRelocationsDisplay, ElfSectionHeaderTableDisplay, ElfSymbolTablesDisplay,
+    },
+    encoding::AnyEncoding,
+    ElfFile,
+};
+
+fn main() -> ExitCode {
+    let Some(path) = env::args_os().nth(1) else {
+        eprintln!("usage: readelf <FILE>");
+        return ExitCode::FAILURE;
+    };
+
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!("readelf: {}: {error}", path.to_string_lossy());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let file = match ElfFile::<AnyClass, AnyEncoding>::parse(&bytes) {
+        Ok(file) => file,
+        Err(error) => {
+            eprintln!("readelf: {}: {error:?}", path.to_string_lossy());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", ElfHeaderDisplay::new(file.header()));
+
+    println!("{}", ElfSectionHeaderTableDisplay::new(file));
+
+    match file.program_header_table() {
+        Some(table) => println!("{}", ElfProgramHeaderTableDisplay::new(table)),
+        None => println!("There are no program headers in this file.\n"),
+    }
+
+    println!("{}", ElfDynamicDisplay::new(file));
+    println!("{}", ElfRelocationsDisplay::new(file));
+    println!("{}", ElfSymbolTablesDisplay::new(file));
+    println!("{}", ElfNotesDisplay::new(file));
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,287 @@
+//! Facilities for summarizing and comparing the exported ABI of shared objects.
+
+use core::hash::{BuildHasher, Hasher};
+
+use crate::{
+    class::ClassParse,
+    elf_symbol::{SymbolBinding, SymbolVisibility},
+    elf_version::VersionedSymbols,
+    encoding::EncodingParse,
+    raw::{elf_section_header::SectionIndex, elf_symbol::SymbolType},
+    ElfFile,
+};
+
+/// A symbol exported by a shared object, reduced to the properties that make up its ABI.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExportedSymbol<'slice> {
+    /// The name of the symbol.
+    pub name: &'slice [u8],
+    /// The name of the version node the symbol is defined in, if the symbol is versioned.
+    pub version: Option<&'slice [u8]>,
+    /// The type of the symbol.
+    pub symbol_type: SymbolType,
+    /// The size of the symbol.
+    pub size: u64,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns an iterator over the [`ExportedSymbol`]s of this [`ElfFile`], which are the
+    /// defined [`SymbolBinding::Global`] and [`SymbolBinding::Weak`] dynamic symbols with
+    /// [`SymbolVisibility::Default`] or [`SymbolVisibility::Protected`] visibility.
+    ///
+    /// The iterator is empty if this [`ElfFile`] has no dynamic symbol table.
+    pub fn exported_symbols(&self) -> ExportedSymbols<'slice, C, E> {
+        ExportedSymbols {
+            symbols: self.versioned_dynamic_symbols(),
+        }
+    }
+
+    /// Returns an iterator over the [`ImportedSymbol`]s of this [`ElfFile`], which are the
+    /// undefined, named dynamic symbols that are not [`SymbolBinding::Local`].
+    ///
+    /// The iterator is empty if this [`ElfFile`] has no dynamic symbol table.
+    pub fn imported_symbols(&self) -> ImportedSymbols<'slice, C, E> {
+        ImportedSymbols {
+            symbols: self.versioned_dynamic_symbols(),
+        }
+    }
+
+    /// Computes the [`digest`] of the [`ExportedSymbol`]s of this [`ElfFile`], using
+    /// [`Hasher`]s created by `build_hasher`.
+    pub fn abi_digest<B: BuildHasher>(&self, build_hasher: &B) -> u64 {
+        digest(self.exported_symbols(), build_hasher)
+    }
+}
+
+/// An iterator over the [`ExportedSymbol`]s of an [`ElfFile`], returned by
+/// [`ElfFile::exported_symbols`].
+#[derive(Clone)]
+pub struct ExportedSymbols<'slice, C: ClassParse, E: EncodingParse> {
+    /// The dynamic symbols of the [`ElfFile`], or [`None`] if it has no dynamic symbol table.
+    symbols: Option<VersionedSymbols<'slice, C, E>>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for ExportedSymbols<'slice, C, E> {
+    type Item = ExportedSymbol<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.symbols.as_mut()?.find_map(|versioned| {
+            let symbol = versioned.symbol;
+            if symbol.section_index() == SectionIndex::UNDEF.0
+                || !matches!(
+                    symbol.binding(),
+                    SymbolBinding::Global | SymbolBinding::Weak
+                )
+                || !matches!(
+                    symbol.visibility(),
+                    SymbolVisibility::Default | SymbolVisibility::Protected
+                )
+            {
+                return None;
+            }
+
+            Some(ExportedSymbol {
+                name: symbol.name().ok()?,
+                version: versioned.version.map(|version| version.name),
+                symbol_type: symbol.info().symbol_type(),
+                size: symbol.size(),
+            })
+        })
+    }
+}
+
+/// A symbol a shared object imports from its dependencies.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ImportedSymbol<'slice> {
+    /// The name of the symbol.
+    pub name: &'slice [u8],
+    /// The name of the version node the symbol is required from, if the symbol is versioned.
+    pub version: Option<&'slice [u8]>,
+    /// The name of the file the version node is required from, if the symbol is versioned.
+    pub file: Option<&'slice [u8]>,
+    /// Whether the reference to the symbol is weak, in which case it may remain unresolved.
+    pub weak: bool,
+}
+
+/// An iterator over the [`ImportedSymbol`]s of an [`ElfFile`], returned by
+/// [`ElfFile::imported_symbols`].
+#[derive(Clone)]
+pub struct ImportedSymbols<'slice, C: ClassParse, E: EncodingParse> {
+    /// The dynamic symbols of the [`ElfFile`], or [`None`] if it has no dynamic symbol table.
+    symbols: Option<VersionedSymbols<'slice, C, E>>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for ImportedSymbols<'slice, C, E> {
+    type Item = ImportedSymbol<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.symbols.as_mut()?.find_map(|versioned| {
+            let symbol = versioned.symbol;
+            if symbol.section_index() != SectionIndex::UNDEF.0
+                || symbol.binding() == SymbolBinding::Local
+            {
+                return None;
+            }
+
+            let name = symbol.name().ok().filter(|name| !name.is_empty())?;
+            Some(ImportedSymbol {
+                name,
+                version: versioned.version.map(|version| version.name),
+                file: versioned.version.and_then(|version| version.file),
+                weak: symbol.binding() == SymbolBinding::Weak,
+            })
+        })
+    }
+}
+
+/// Computes a digest over the set of `symbols`, using [`Hasher`]s created by `build_hasher`.
+///
+/// The digest does not depend on the order in which `symbols` are yielded, and so two builds of a
+/// shared object that export the same symbols, with the same versions, types and sizes, produce
+/// the same digest. The encoding fed to the [`Hasher`]s is independent of the host platform, and
+/// so the digest is stable as long as the [`Hasher`] itself is.
+pub fn digest<'slice, I: IntoIterator<Item = ExportedSymbol<'slice>>, B: BuildHasher>(
+    symbols: I,
+    build_hasher: &B,
+) -> u64 {
+    let mut count = 0u64;
+    let mut combined = 0u64;
+    for symbol in symbols {
+        let mut hasher = build_hasher.build_hasher();
+        hash_symbol(&symbol, &mut hasher);
+
+        count = count.wrapping_add(1);
+        combined = combined.wrapping_add(hasher.finish());
+    }
+
+    let mut hasher = build_hasher.build_hasher();
+    hasher.write_u64(count);
+    hasher.write_u64(combined);
+    hasher.finish()
+}
+
+/// Feeds a platform-independent encoding of `symbol` into `hasher`.
+fn hash_symbol<H: Hasher>(symbol: &ExportedSymbol, hasher: &mut H) {
+    hasher.write_u64(symbol.name.len() as u64);
+    hasher.write(symbol.name);
+    match symbol.version {
+        Some(version) => {
+            hasher.write_u8(1);
+            hasher.write_u64(version.len() as u64);
+            hasher.write(version);
+        }
+        None => hasher.write_u8(0),
+    }
+    hasher.write_u8(symbol.symbol_type.0);
+    hasher.write_u64(symbol.size);
+}
+
+/// Compares the symbols exported by an `old` and a `new` build of a shared object, returning an
+/// iterator over the [`AbiChange`]s that may break consumers of the `old` build.
+///
+/// This requires no allocation, and so each exported symbol of `old` is searched for in `new`
+/// linearly.
+pub fn compare<'slice, I: Iterator<Item = ExportedSymbol<'slice>> + Clone>(
+    old: I,
+    new: I,
+) -> Compare<'slice, I> {
+    Compare {
+        old_symbols: old.clone(),
+        old_versions: old.clone().enumerate(),
+        old,
+        new,
+    }
+}
+
+/// Compares the [`ExportedSymbol`]s of an `old` and a `new` build of a shared object, as
+/// described by [`compare`].
+pub fn compare_files<'slice, C: ClassParse, E: EncodingParse>(
+    old: &ElfFile<'slice, C, E>,
+    new: &ElfFile<'slice, C, E>,
+) -> Compare<'slice, ExportedSymbols<'slice, C, E>> {
+    compare(old.exported_symbols(), new.exported_symbols())
+}
+
+/// A change to the exported ABI of a shared object, as reported by [`compare`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum AbiChange<'slice> {
+    /// The symbol is no longer exported.
+    Removed(ExportedSymbol<'slice>),
+    /// The symbol is still exported with the same version, but its type or size changed.
+    Changed {
+        /// The symbol as exported by the old build.
+        old: ExportedSymbol<'slice>,
+        /// The symbol as exported by the new build.
+        new: ExportedSymbol<'slice>,
+    },
+    /// The symbol is no longer exported with its old version, but is exported with another
+    /// version.
+    VersionChanged {
+        /// The symbol as exported by the old build.
+        old: ExportedSymbol<'slice>,
+        /// The symbol as exported by the new build.
+        new: ExportedSymbol<'slice>,
+    },
+    /// No symbol is exported with the given version node anymore.
+    VersionNodeRemoved(&'slice [u8]),
+}
+
+/// An iterator over the [`AbiChange`]s between two builds of a shared object.
+#[derive(Clone)]
+pub struct Compare<'slice, I: Iterator<Item = ExportedSymbol<'slice>> + Clone> {
+    /// The exported symbols of the old build.
+    old: I,
+    /// The exported symbols of the new build.
+    new: I,
+    /// The remaining exported symbols of the old build to check.
+    old_symbols: I,
+    /// The remaining exported symbols of the old build to check the version nodes of.
+    old_versions: core::iter::Enumerate<I>,
+}
+
+impl<'slice, I: Iterator<Item = ExportedSymbol<'slice>> + Clone> Iterator for Compare<'slice, I> {
+    type Item = AbiChange<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        'symbols: for old in self.old_symbols.by_ref() {
+            let mut other_version = None;
+            for new in self.new.clone().filter(|new| new.name == old.name) {
+                if new.version != old.version {
+                    other_version = Some(new);
+                    continue;
+                }
+
+                if new.symbol_type != old.symbol_type || new.size != old.size {
+                    return Some(AbiChange::Changed { old, new });
+                }
+                continue 'symbols;
+            }
+
+            match other_version {
+                Some(new) => return Some(AbiChange::VersionChanged { old, new }),
+                None => return Some(AbiChange::Removed(old)),
+            }
+        }
+
+        for (index, old) in self.old_versions.by_ref() {
+            let Some(version) = old.version else {
+                continue;
+            };
+
+            let reported = self
+                .old
+                .clone()
+                .take(index)
+                .any(|earlier| earlier.version == Some(version));
+            if reported {
+                continue;
+            }
+
+            if !self.new.clone().any(|new| new.version == Some(version)) {
+                return Some(AbiChange::VersionNodeRemoved(version));
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,71 @@
+//! An owned ELF file, available with the `alloc` feature.
+
+use alloc::vec::Vec;
+
+use crate::{class::ClassParse, encoding::EncodingParse, ElfFile, ParseElfFileError};
+
+/// An ELF file that owns the buffer holding it.
+///
+/// This avoids tying the lifetime of an [`ElfFile`] to a buffer created at runtime. The buffer
+/// is validated once, when the [`ElfFileBuf`] is created, and cannot be modified afterwards.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct ElfFileBuf<C: ClassParse, E: EncodingParse> {
+    /// The bytes of the ELF file.
+    bytes: Vec<u8>,
+    /// The [`ClassParse`] used to parse the ELF file.
+    class: C,
+    /// The [`EncodingParse`] used to parse the ELF file.
+    encoding: E,
+}
+
+impl<C: ClassParse, E: EncodingParse> ElfFileBuf<C, E> {
+    /// Parses an [`ElfFileBuf`] from `bytes`, performing the checks of [`ElfFile::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfFileError`] if `bytes` does not hold a valid [`ElfFile`].
+    pub fn parse(bytes: Vec<u8>) -> Result<Self, ParseElfFileError> {
+        let elf_file = ElfFile::<C, E>::parse(&bytes)?;
+        let (class, encoding) = (elf_file.class, elf_file.encoding);
+
+        Ok(Self {
+            bytes,
+            class,
+            encoding,
+        })
+    }
+
+    /// Returns the [`ElfFile`] view of this [`ElfFileBuf`], through which the rest of this
+    /// crate's API can be used.
+    pub fn elf_file(&self) -> ElfFile<'_, C, E> {
+        ElfFile {
+            slice: &self.bytes,
+            class: self.class,
+            encoding: self.encoding,
+        }
+    }
+
+    /// Returns the bytes of the ELF file.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the buffer holding the ELF file.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl<C: ClassParse, E: EncodingParse> TryFrom<Vec<u8>> for ElfFileBuf<C, E> {
+    type Error = ParseElfFileError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl<C: ClassParse, E: EncodingParse> AsRef<[u8]> for ElfFileBuf<C, E> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
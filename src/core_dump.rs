@@ -0,0 +1,753 @@
+//! Definitions and interfaces for interacting with ELF core dumps.
+
+use core::{array, mem};
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_auxv::ElfAuxv,
+    elf_note::{ElfNote, SegmentNotes},
+    elf_program_header::OfType,
+    encoding::EncodingParse,
+    loader::AddressMode,
+    raw::{
+        elf_core::{
+            Aarch64UserRegisters, Elf64PrPsInfo, Elf64PrStatus, ElfSignalInfo, X86_64UserRegisters,
+        },
+        elf_header::{ElfType, Machine},
+        elf_note::{
+            ELF_NOTE_CORE, NT_AUXV, NT_FILE, NT_PRPSINFO, NT_PRSTATUS, NT_SIGINFO, NT_TASKSTRUCT,
+        },
+        elf_program_header::SegmentType,
+    },
+    ElfFile,
+};
+
+/// An ELF core dump, which captures the memory and thread state of a process.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct CoreFile<'slice, C: ClassParse, E: EncodingParse> {
+    /// The underlying [`ElfFile`].
+    file: ElfFile<'slice, C, E>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> CoreFile<'slice, C, E> {
+    /// Creates a [`CoreFile`] from `file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseCoreFileError::NotCore`] if `file` is not of type [`ElfType::CORE`].
+    pub fn new(file: ElfFile<'slice, C, E>) -> Result<Self, ParseCoreFileError> {
+        if file.header().elf_type() != ElfType::CORE {
+            return Err(ParseCoreFileError::NotCore);
+        }
+
+        Ok(Self { file })
+    }
+
+    /// Returns the underlying [`ElfFile`].
+    pub fn file(&self) -> ElfFile<'slice, C, E> {
+        self.file
+    }
+
+    /// Returns an iterator over the [`SegmentType::NOTE`] segments of the [`CoreFile`].
+    pub fn note_segments(&self) -> OfType<'slice, C, E> {
+        self.file.segments_of_type(SegmentType::NOTE)
+    }
+
+    /// Returns an iterator over the notes of the [`CoreFile`], each classified as describing
+    /// either the process as a whole or one of its threads.
+    pub fn notes(&self) -> CoreNotes<'slice, C, E> {
+        CoreNotes {
+            notes: self.file.segment_notes(),
+            thread: None,
+        }
+    }
+
+    /// Returns an iterator over the decoded [`NT_PRSTATUS`] notes of the [`CoreFile`], one for
+    /// each thread of the process.
+    pub fn thread_statuses(&self) -> ThreadStatuses<'slice, C, E> {
+        ThreadStatuses {
+            notes: self.notes(),
+            machine: self.file.header().machine(),
+            encoding: self.file.encoding,
+        }
+    }
+
+    /// Fills `buffer` with the memory of the process starting at the virtual address `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadMemoryError::Unmapped`] if part of the range was not mapped into the process,
+    /// and [`ReadMemoryError::NotDumped`] if part of the range was mapped but its contents were
+    /// not included in the core dump. In the latter case, the file that backed the memory is
+    /// reported if the core dump records it.
+    pub fn read_memory(
+        &self,
+        address: u64,
+        buffer: &mut [u8],
+    ) -> Result<(), ReadMemoryError<'slice>> {
+        let mut address = address;
+        let mut remaining = buffer;
+        while !remaining.is_empty() {
+            let segment = self
+                .file
+                .segment_containing(address, AddressMode::Virtual)
+                .ok_or(ReadMemoryError::Unmapped { address })?;
+            let not_dumped = || ReadMemoryError::NotDumped {
+                address,
+                backing: self.file_backing(address),
+            };
+
+            let offset = address.wrapping_sub(segment.virtual_address());
+            let available = usize::try_from(offset)
+                .ok()
+                .and_then(|offset| segment.segment_data(&self.file).ok()?.0.get(offset..))
+                .filter(|available| !available.is_empty())
+                .ok_or_else(not_dumped)?;
+
+            let count = available.len().min(remaining.len());
+            let (head, tail) = mem::take(&mut remaining).split_at_mut(count);
+            head.copy_from_slice(available.get(..count).ok_or_else(not_dumped)?);
+            remaining = tail;
+            address = address.wrapping_add(count as u64);
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the [`MappedFile`]s recorded by the [`NT_FILE`] note of the
+    /// [`CoreFile`].
+    pub fn mapped_files(&self) -> Option<MappedFiles<'slice, C, E>> {
+        let descriptor = self
+            .notes()
+            .map(CoreNote::note)
+            .find(|note| note.name == ELF_NOTE_CORE && note.kind == NT_FILE)?
+            .descriptor;
+
+        MappedFiles::parse(descriptor, self.file.class, self.file.encoding)
+    }
+
+    /// Returns the [`PrPsInfo`] decoded from the [`NT_PRPSINFO`] note of the [`CoreFile`].
+    pub fn process_info(&self) -> Option<Result<PrPsInfo<'slice>, ParsePrPsInfoError>> {
+        let descriptor = self
+            .notes()
+            .map(CoreNote::note)
+            .find(|note| note.name == ELF_NOTE_CORE && note.kind == NT_PRPSINFO)?
+            .descriptor;
+
+        Some(PrPsInfo::parse(descriptor, self.file.encoding))
+    }
+
+    /// Returns the [`ElfAuxv`] recorded by the [`NT_AUXV`] note of the [`CoreFile`].
+    pub fn auxv(&self) -> Option<ElfAuxv<'slice, C, E>> {
+        let descriptor = self
+            .notes()
+            .map(CoreNote::note)
+            .find(|note| note.name == ELF_NOTE_CORE && note.kind == NT_AUXV)?
+            .descriptor;
+
+        Some(ElfAuxv::parse(
+            descriptor,
+            self.file.class,
+            self.file.encoding,
+        ))
+    }
+
+    /// Returns the [`FileBacking`] of the memory located at `address`, as recorded by the
+    /// [`NT_FILE`] note.
+    fn file_backing(&self, address: u64) -> Option<FileBacking<'slice>> {
+        let mut mapped_files = self.mapped_files()?;
+        let page_size = mapped_files.page_size();
+        let mapped_file = mapped_files
+            .find(|mapped_file| (mapped_file.start..mapped_file.end).contains(&address))?;
+
+        Some(FileBacking {
+            path: mapped_file.path,
+            offset: mapped_file
+                .page_offset
+                .checked_mul(page_size)?
+                .checked_add(address.checked_sub(mapped_file.start)?)?,
+        })
+    }
+}
+
+/// A note of a [`CoreFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum CoreNote<'slice> {
+    /// A note describing the process as a whole, which is an [`NT_PRPSINFO`], [`NT_TASKSTRUCT`],
+    /// [`NT_AUXV`], [`NT_SIGINFO`] or [`NT_FILE`] note, or a note that precedes the first
+    /// [`NT_PRSTATUS`] note.
+    Process(ElfNote<'slice>),
+    /// A note describing a single thread of the process.
+    ///
+    /// Each thread is introduced by an [`NT_PRSTATUS`] note, which is followed by the other
+    /// notes describing that thread.
+    Thread {
+        /// The index of the thread, in the order the threads appear in the [`CoreFile`].
+        thread: usize,
+        /// The note describing the thread.
+        note: ElfNote<'slice>,
+    },
+}
+
+impl<'slice> CoreNote<'slice> {
+    /// Returns the underlying [`ElfNote`].
+    pub fn note(self) -> ElfNote<'slice> {
+        match self {
+            Self::Process(note) | Self::Thread { note, .. } => note,
+        }
+    }
+}
+
+/// An iterator over the [`CoreNote`]s of a [`CoreFile`].
+pub struct CoreNotes<'slice, C: ClassParse, E: EncodingParse> {
+    /// The notes of the [`SegmentType::NOTE`] segments that have not been iterated over yet.
+    notes: SegmentNotes<'slice, C, E>,
+    /// The index of the thread introduced by the most recent [`NT_PRSTATUS`] note.
+    thread: Option<usize>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for CoreNotes<'slice, C, E> {
+    type Item = CoreNote<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let note = self.notes.next()?;
+
+        if note.name == ELF_NOTE_CORE {
+            match note.kind {
+                NT_PRSTATUS => {
+                    self.thread = Some(self.thread.map_or(0, |thread| thread.saturating_add(1)));
+                }
+                NT_PRPSINFO | NT_TASKSTRUCT | NT_AUXV | NT_SIGINFO | NT_FILE => {
+                    return Some(CoreNote::Process(note))
+                }
+                _ => {}
+            }
+        }
+
+        match self.thread {
+            Some(thread) => Some(CoreNote::Thread { thread, note }),
+            None => Some(CoreNote::Process(note)),
+        }
+    }
+}
+
+/// An iterator over the [`PrStatus`]es of a [`CoreFile`].
+pub struct ThreadStatuses<'slice, C: ClassParse, E: EncodingParse> {
+    /// The notes of the [`CoreFile`] that have not been iterated over yet.
+    notes: CoreNotes<'slice, C, E>,
+    /// The [`Machine`] the process was running on.
+    machine: Machine,
+    /// The [`EncodingParse`] used to parse the [`PrStatus`]es.
+    encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for ThreadStatuses<'slice, C, E> {
+    type Item = Result<PrStatus, ParsePrStatusError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.notes.find_map(|note| {
+            let CoreNote::Thread { note, .. } = note else {
+                return None;
+            };
+            if note.name != ELF_NOTE_CORE || note.kind != NT_PRSTATUS {
+                return None;
+            }
+
+            Some(PrStatus::parse(
+                note.descriptor,
+                self.machine,
+                self.encoding,
+            ))
+        })
+    }
+}
+
+/// The decoded contents of an [`NT_PRSTATUS`] note, which describes the state of a single
+/// thread.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PrStatus {
+    /// Information about the signal that terminated the process.
+    pub signal_info: ElfSignalInfo,
+    /// The signal currently being delivered to the thread.
+    pub current_signal: i16,
+    /// The ID of the thread.
+    pub pid: i32,
+    /// The ID of the parent process.
+    pub ppid: i32,
+    /// The ID of the process group.
+    pub pgrp: i32,
+    /// The ID of the session.
+    pub sid: i32,
+    /// The general-purpose registers of the thread.
+    pub registers: GeneralRegisters,
+}
+
+impl PrStatus {
+    /// Parses a [`PrStatus`] from the `descriptor` of an [`NT_PRSTATUS`] note written by a
+    /// process running on `machine`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParsePrStatusError::UnsupportedMachine`] if the register set of `machine` is not
+    /// supported and [`ParsePrStatusError::SliceTooSmall`] if `descriptor` is too small to contain
+    /// the [`PrStatus`].
+    pub fn parse<E: EncodingParse>(
+        descriptor: &[u8],
+        machine: Machine,
+        encoding: E,
+    ) -> Result<Self, ParsePrStatusError> {
+        let header = descriptor
+            .get(..mem::size_of::<Elf64PrStatus>())
+            .ok_or(ParsePrStatusError::SliceTooSmall)?;
+        let registers = descriptor
+            .get(mem::size_of::<Elf64PrStatus>()..)
+            .ok_or(ParsePrStatusError::SliceTooSmall)?;
+        let registers = match machine {
+            Machine::X86_64 => {
+                GeneralRegisters::X86_64(X86_64UserRegisters::parse(registers, encoding)?)
+            }
+            Machine::AARCH64 => {
+                GeneralRegisters::Aarch64(Aarch64UserRegisters::parse(registers, encoding)?)
+            }
+            machine => return Err(ParsePrStatusError::UnsupportedMachine(machine)),
+        };
+
+        let signal_info_offset = mem::offset_of!(Elf64PrStatus, signal_info);
+        let i32_at = |offset: usize| encoding.parse_i32_at(offset, header);
+        Ok(Self {
+            signal_info: ElfSignalInfo {
+                signal_number: i32_at(
+                    signal_info_offset
+                        .saturating_add(mem::offset_of!(ElfSignalInfo, signal_number)),
+                ),
+                code: i32_at(
+                    signal_info_offset.saturating_add(mem::offset_of!(ElfSignalInfo, code)),
+                ),
+                errno: i32_at(
+                    signal_info_offset.saturating_add(mem::offset_of!(ElfSignalInfo, errno)),
+                ),
+            },
+            current_signal: encoding
+                .parse_u16_at(mem::offset_of!(Elf64PrStatus, current_signal), header)
+                as i16,
+            pid: i32_at(mem::offset_of!(Elf64PrStatus, pid)),
+            ppid: i32_at(mem::offset_of!(Elf64PrStatus, ppid)),
+            pgrp: i32_at(mem::offset_of!(Elf64PrStatus, pgrp)),
+            sid: i32_at(mem::offset_of!(Elf64PrStatus, sid)),
+            registers,
+        })
+    }
+}
+
+/// Various errors that can occur while parsing a [`PrStatus`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParsePrStatusError {
+    /// The register set of the [`Machine`] is not supported.
+    UnsupportedMachine(Machine),
+    /// The given slice was too small to contain the [`PrStatus`].
+    SliceTooSmall,
+}
+
+impl From<ParseRegisterSetError> for ParsePrStatusError {
+    fn from(value: ParseRegisterSetError) -> Self {
+        match value {
+            ParseRegisterSetError::SliceTooSmall => Self::SliceTooSmall,
+        }
+    }
+}
+
+/// The general-purpose register set of a thread, as stored in an [`NT_PRSTATUS`] note.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GeneralRegisters {
+    /// The register set of an x86_64 thread.
+    X86_64(X86_64UserRegisters),
+    /// The register set of an aarch64 thread.
+    Aarch64(Aarch64UserRegisters),
+}
+
+impl GeneralRegisters {
+    /// Returns the address of the instruction the thread was executing.
+    pub fn program_counter(&self) -> u64 {
+        match self {
+            Self::X86_64(registers) => registers.program_counter(),
+            Self::Aarch64(registers) => registers.program_counter(),
+        }
+    }
+
+    /// Returns the stack pointer of the thread.
+    pub fn stack_pointer(&self) -> u64 {
+        match self {
+            Self::X86_64(registers) => registers.stack_pointer(),
+            Self::Aarch64(registers) => registers.stack_pointer(),
+        }
+    }
+
+    /// Returns the register conventionally used to hold the frame pointer of the thread.
+    pub fn frame_pointer(&self) -> u64 {
+        match self {
+            Self::X86_64(registers) => registers.frame_pointer(),
+            Self::Aarch64(registers) => registers.frame_pointer(),
+        }
+    }
+}
+
+/// Various errors that can occur while creating a [`CoreFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseCoreFileError {
+    /// The [`ElfFile`] is not of type [`ElfType::CORE`].
+    NotCore,
+}
+
+/// The decoded contents of an [`NT_PRPSINFO`] note, which describes the process as a whole.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PrPsInfo<'slice> {
+    /// The numeric state of the process.
+    pub state: u8,
+    /// The character describing the state of the process, as displayed by `ps`.
+    pub state_name: u8,
+    /// Whether the process is a zombie.
+    pub zombie: bool,
+    /// The nice value of the process.
+    pub nice: i8,
+    /// The flags of the process.
+    pub flags: u64,
+    /// The user ID of the process.
+    pub uid: u32,
+    /// The group ID of the process.
+    pub gid: u32,
+    /// The ID of the process.
+    pub pid: i32,
+    /// The ID of the parent process.
+    pub ppid: i32,
+    /// The ID of the process group.
+    pub pgrp: i32,
+    /// The ID of the session.
+    pub sid: i32,
+    /// The file name of the program, without its NUL padding.
+    pub file_name: &'slice [u8],
+    /// The initial part of the argument list of the program, without its NUL padding.
+    pub arguments: &'slice [u8],
+}
+
+impl<'slice> PrPsInfo<'slice> {
+    /// Parses a [`PrPsInfo`] from the `descriptor` of an [`NT_PRPSINFO`] note written by a
+    /// 64-bit process.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParsePrPsInfoError::SliceTooSmall`] if `descriptor` is too small to contain the
+    /// [`PrPsInfo`].
+    pub fn parse<E: EncodingParse>(
+        descriptor: &'slice [u8],
+        encoding: E,
+    ) -> Result<Self, ParsePrPsInfoError> {
+        let descriptor = descriptor
+            .get(..mem::size_of::<Elf64PrPsInfo>())
+            .ok_or(ParsePrPsInfoError::SliceTooSmall)?;
+        let string = |offset: usize, size: usize| {
+            let field = descriptor.get(offset..offset.saturating_add(size))?;
+            let length = field
+                .iter()
+                .position(|&byte| byte == 0)
+                .unwrap_or(field.len());
+            field.get(..length)
+        };
+        let i32_at = |offset: usize| encoding.parse_i32_at(offset, descriptor);
+
+        Ok(Self {
+            state: encoding.parse_u8_at(mem::offset_of!(Elf64PrPsInfo, state), descriptor),
+            state_name: encoding
+                .parse_u8_at(mem::offset_of!(Elf64PrPsInfo, state_name), descriptor),
+            zombie: encoding.parse_u8_at(mem::offset_of!(Elf64PrPsInfo, zombie), descriptor) != 0,
+            nice: encoding.parse_u8_at(mem::offset_of!(Elf64PrPsInfo, nice), descriptor) as i8,
+            flags: encoding.parse_u64_at(mem::offset_of!(Elf64PrPsInfo, flags), descriptor),
+            uid: encoding.parse_u32_at(mem::offset_of!(Elf64PrPsInfo, uid), descriptor),
+            gid: encoding.parse_u32_at(mem::offset_of!(Elf64PrPsInfo, gid), descriptor),
+            pid: i32_at(mem::offset_of!(Elf64PrPsInfo, pid)),
+            ppid: i32_at(mem::offset_of!(Elf64PrPsInfo, ppid)),
+            pgrp: i32_at(mem::offset_of!(Elf64PrPsInfo, pgrp)),
+            sid: i32_at(mem::offset_of!(Elf64PrPsInfo, sid)),
+            file_name: string(mem::offset_of!(Elf64PrPsInfo, file_name), 16)
+                .ok_or(ParsePrPsInfoError::SliceTooSmall)?,
+            arguments: string(mem::offset_of!(Elf64PrPsInfo, arguments), 80)
+                .ok_or(ParsePrPsInfoError::SliceTooSmall)?,
+        })
+    }
+}
+
+/// Various errors that can occur while parsing a [`PrPsInfo`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParsePrPsInfoError {
+    /// The given slice was too small to contain the [`PrPsInfo`].
+    SliceTooSmall,
+}
+
+/// A file mapped into the address space of the process, as recorded by an [`NT_FILE`] note.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct MappedFile<'slice> {
+    /// The first virtual address of the mapping.
+    pub start: u64,
+    /// The virtual address one past the end of the mapping.
+    pub end: u64,
+    /// The offset into the file at which the mapping starts, in units of
+    /// [`MappedFiles::page_size`].
+    pub page_offset: u64,
+    /// The path of the file.
+    pub path: &'slice [u8],
+}
+
+/// An iterator over the [`MappedFile`]s recorded by an [`NT_FILE`] note.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct MappedFiles<'slice, C: ClassParse, E: EncodingParse> {
+    /// The descriptor of the [`NT_FILE`] note.
+    descriptor: &'slice [u8],
+    /// The paths of the [`MappedFile`]s that have not been iterated over yet.
+    paths: &'slice [u8],
+    /// The number of [`MappedFile`]s recorded by the note.
+    count: u64,
+    /// The index of the next [`MappedFile`].
+    index: u64,
+    /// The [`ClassParse`] used to parse the note.
+    class: C,
+    /// The [`EncodingParse`] used to parse the note.
+    encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> MappedFiles<'slice, C, E> {
+    /// Parses a [`MappedFiles`] from the `descriptor` of an [`NT_FILE`] note.
+    ///
+    /// Returns [`None`] if `descriptor` is too small to contain the number of mappings it
+    /// records.
+    pub fn parse(descriptor: &'slice [u8], class: C, encoding: E) -> Option<Self> {
+        let count = read_word(descriptor, 0, class, encoding)?;
+        let paths_offset = usize::try_from(count)
+            .ok()?
+            .checked_mul(3)?
+            .checked_add(2)?
+            .checked_mul(word_size(class))?;
+
+        Some(Self {
+            descriptor,
+            paths: descriptor.get(paths_offset..)?,
+            count,
+            index: 0,
+            class,
+            encoding,
+        })
+    }
+
+    /// Returns the size of the pages in which [`MappedFile::page_offset`] is measured.
+    pub fn page_size(&self) -> u64 {
+        read_word(self.descriptor, 1, self.class, self.encoding).unwrap_or(0)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for MappedFiles<'slice, C, E> {
+    type Item = MappedFile<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let base = usize::try_from(self.index)
+            .ok()?
+            .checked_mul(3)?
+            .checked_add(2)?;
+        let word = |offset: usize| {
+            read_word(
+                self.descriptor,
+                base.checked_add(offset)?,
+                self.class,
+                self.encoding,
+            )
+        };
+        let (start, end, page_offset) = (word(0)?, word(1)?, word(2)?);
+
+        let length = self.paths.iter().position(|&byte| byte == 0)?;
+        let path = self.paths.get(..length)?;
+        self.paths = self.paths.get(length.checked_add(1)?..)?;
+        self.index = self.index.checked_add(1)?;
+
+        Some(MappedFile {
+            start,
+            end,
+            page_offset,
+            path,
+        })
+    }
+}
+
+/// Returns the size of a word of a process of class `class`.
+fn word_size<C: ClassParse>(class: C) -> usize {
+    match class.into_class() {
+        Class::Class32 => mem::size_of::<u32>(),
+        Class::Class64 => mem::size_of::<u64>(),
+    }
+}
+
+/// Returns the word at `index` of `data`, whose width depends on `class`.
+fn read_word<C: ClassParse, E: EncodingParse>(
+    data: &[u8],
+    index: usize,
+    class: C,
+    encoding: E,
+) -> Option<u64> {
+    let offset = index.checked_mul(word_size(class))?;
+    data.get(offset..offset.checked_add(word_size(class))?)?;
+    Some(match class.into_class() {
+        Class::Class32 => encoding.parse_u32_at(offset, data).into(),
+        Class::Class64 => encoding.parse_u64_at(offset, data),
+    })
+}
+
+/// The file whose contents backed a range of memory of the process.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FileBacking<'slice> {
+    /// The path of the file.
+    pub path: &'slice [u8],
+    /// The offset into the file of the byte that backed the requested address.
+    pub offset: u64,
+}
+
+/// Various errors that can occur while reading memory from a [`CoreFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ReadMemoryError<'slice> {
+    /// The memory at `address` was not mapped into the process.
+    Unmapped {
+        /// The first address that could not be read.
+        address: u64,
+    },
+    /// The memory at `address` was mapped into the process, but was not included in the core
+    /// dump.
+    NotDumped {
+        /// The first address that could not be read.
+        address: u64,
+        /// The file that backed the memory at `address`, if known.
+        backing: Option<FileBacking<'slice>>,
+    },
+}
+
+/// A general-purpose register set stored in the `pr_reg` field of an `NT_PRSTATUS` note.
+pub trait RegisterSet: Sized {
+    /// The size, in bytes, of the register set.
+    const SIZE: usize;
+
+    /// Parses the register set from the start of `slice`, using `encoding` to decode each
+    /// register.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseRegisterSetError::SliceTooSmall`] if `slice` is too small to contain the
+    /// register set.
+    fn parse<E: EncodingParse>(slice: &[u8], encoding: E) -> Result<Self, ParseRegisterSetError>;
+
+    /// Returns the address of the instruction the thread was executing.
+    fn program_counter(&self) -> u64;
+
+    /// Returns the stack pointer of the thread.
+    fn stack_pointer(&self) -> u64;
+
+    /// Returns the register conventionally used to hold the frame pointer of the thread.
+    fn frame_pointer(&self) -> u64;
+}
+
+impl RegisterSet for X86_64UserRegisters {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn parse<E: EncodingParse>(slice: &[u8], encoding: E) -> Result<Self, ParseRegisterSetError> {
+        let [r15, r14, r13, r12, rbp, rbx, r11, r10, r9, r8, rax, rcx, rdx, rsi, rdi, orig_rax, rip, cs, eflags, rsp, ss, fs_base, gs_base, ds, es, fs, gs] =
+            parse_words(slice, encoding)?;
+
+        Ok(Self {
+            r15,
+            r14,
+            r13,
+            r12,
+            rbp,
+            rbx,
+            r11,
+            r10,
+            r9,
+            r8,
+            rax,
+            rcx,
+            rdx,
+            rsi,
+            rdi,
+            orig_rax,
+            rip,
+            cs,
+            eflags,
+            rsp,
+            ss,
+            fs_base,
+            gs_base,
+            ds,
+            es,
+            fs,
+            gs,
+        })
+    }
+
+    fn program_counter(&self) -> u64 {
+        self.rip
+    }
+
+    fn stack_pointer(&self) -> u64 {
+        self.rsp
+    }
+
+    fn frame_pointer(&self) -> u64 {
+        self.rbp
+    }
+}
+
+impl RegisterSet for Aarch64UserRegisters {
+    const SIZE: usize = mem::size_of::<Self>();
+
+    fn parse<E: EncodingParse>(slice: &[u8], encoding: E) -> Result<Self, ParseRegisterSetError> {
+        let words: [u64; 34] = parse_words(slice, encoding)?;
+        let [regs @ .., sp, pc, pstate] = words;
+
+        Ok(Self {
+            regs,
+            sp,
+            pc,
+            pstate,
+        })
+    }
+
+    fn program_counter(&self) -> u64 {
+        self.pc
+    }
+
+    fn stack_pointer(&self) -> u64 {
+        self.sp
+    }
+
+    fn frame_pointer(&self) -> u64 {
+        self.regs[29]
+    }
+}
+
+/// Various errors that can occur while parsing a [`RegisterSet`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseRegisterSetError {
+    /// The given slice was too small to contain the [`RegisterSet`].
+    SliceTooSmall,
+}
+
+/// Parses `N` consecutive [`u64`]s from the start of `slice`.
+fn parse_words<const N: usize, E: EncodingParse>(
+    slice: &[u8],
+    encoding: E,
+) -> Result<[u64; N], ParseRegisterSetError> {
+    if slice.len() < mem::size_of::<[u64; N]>() {
+        return Err(ParseRegisterSetError::SliceTooSmall);
+    }
+
+    Ok(array::from_fn(|index| {
+        encoding.parse_u64_at(index.saturating_mul(mem::size_of::<u64>()), slice)
+    }))
+}
@@ -0,0 +1,1894 @@
+//! Human-readable, `readelf`-style formatting of ELF structures.
+
+use core::fmt;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_dynamic::ElfDynamicTable,
+    elf_header::ElfHeader,
+    elf_note::{self, ElfNote},
+    elf_program_header::{ElfProgramHeader, ElfProgramHeaderTable},
+    elf_relocation::ElfRelocationTable,
+    elf_section_header::ElfSectionHeader,
+    elf_string_table::ElfStringTable,
+    encoding::{Encoding, EncodingParse},
+    ext::{MachineExt, OsAbiExt},
+    raw::{
+        elf_dynamic::ElfDynamicTag,
+        elf_header::{ElfType, Machine},
+        elf_ident::OsAbi,
+        elf_note::{
+            ELF_NOTE_CORE, ELF_NOTE_GNU, ELF_NOTE_LINUX, NT_AUXV, NT_FILE, NT_GNU_ABI_TAG,
+            NT_GNU_BUILD_ID, NT_GNU_GOLD_VERSION, NT_GNU_HWCAP, NT_GNU_PROPERTY_TYPE_0, NT_PRFPREG,
+            NT_PRPSINFO, NT_PRSTATUS, NT_PRXFPREG, NT_SIGINFO, NT_TASKSTRUCT, NT_X86_XSTATE,
+        },
+        elf_program_header::{SegmentFlags, SegmentType},
+        elf_section_header::{SectionFlags, SectionIndex, SectionType},
+        elf_symbol::{SymbolBinding, SymbolType, SymbolVisibility},
+    },
+    ElfFile,
+};
+
+/// Returns the name `readelf` uses for the given [`ElfType`].
+pub fn elf_type_name(elf_type: ElfType) -> Option<&'static str> {
+    let name = match elf_type {
+        ElfType::NONE => "NONE (None)",
+        ElfType::RELOCATABLE => "REL (Relocatable file)",
+        ElfType::EXECUTABLE => "EXEC (Executable file)",
+        ElfType::SHARED => "DYN (Shared object file)",
+        ElfType::CORE => "CORE (Core file)",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`Machine`].
+pub fn machine_name(machine: Machine) -> Option<&'static str> {
+    let name = match machine {
+        Machine::NONE => "None",
+        Machine::I386 => "Intel 80386",
+        Machine::MIPS => "MIPS R3000",
+        Machine::PPC => "PowerPC",
+        Machine::PPC64 => "PowerPC64",
+        Machine::ARM => "ARM",
+        Machine::X86_64 => "Advanced Micro Devices X86-64",
+        Machine::AARCH64 => "AArch64",
+        Machine::RISCV => "RISC-V",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`OsAbi`].
+pub fn os_abi_name(os_abi: OsAbi) -> Option<&'static str> {
+    let name = match os_abi {
+        OsAbi::NONE => "UNIX - System V",
+        OsAbi::HP_UX => "UNIX - HP-UX",
+        OsAbi::NETBSD => "UNIX - NetBSD",
+        OsAbi::GNU => "UNIX - GNU",
+        OsAbi::SUN_SOLARIS => "UNIX - Solaris",
+        OsAbi::AIX => "UNIX - AIX",
+        OsAbi::IRIX => "UNIX - IRIX",
+        OsAbi::FREEBSD => "UNIX - FreeBSD",
+        OsAbi::COMPAQ_TRU64_UNIX => "UNIX - TRU64",
+        OsAbi::NOVELL_MODESTO => "Novell - Modesto",
+        OsAbi::OPENBSD => "UNIX - OpenBSD",
+        OsAbi::OPEN_VMS => "VMS - OpenVMS",
+        OsAbi::HP_NSK => "HP - Non-Stop Kernel",
+        OsAbi::AMIGA_RESEARCH => "AROS",
+        OsAbi::FENIXOS => "FenixOS",
+        OsAbi::CLOUD_ABI => "Nuxi CloudABI",
+        OsAbi::OPENVOS => "Stratus Technologies OpenVOS",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`SegmentType`].
+pub fn segment_type_name(segment_type: SegmentType) -> Option<&'static str> {
+    let name = match segment_type {
+        SegmentType::NULL => "NULL",
+        SegmentType::LOAD => "LOAD",
+        SegmentType::DYNAMIC => "DYNAMIC",
+        SegmentType::INTERP => "INTERP",
+        SegmentType::NOTE => "NOTE",
+        SegmentType::SHLIB => "SHLIB",
+        SegmentType::PHDR => "PHDR",
+        SegmentType::TLS => "TLS",
+        SegmentType::GNU_EH_FRAME => "GNU_EH_FRAME",
+        SegmentType::GNU_STACK => "GNU_STACK",
+        SegmentType::GNU_RELRO => "GNU_RELRO",
+        SegmentType::GNU_PROPERTY => "GNU_PROPERTY",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`SectionType`].
+pub fn section_type_name(section_type: SectionType) -> Option<&'static str> {
+    let name = match section_type {
+        SectionType::NULL => "NULL",
+        SectionType::PROGBITS => "PROGBITS",
+        SectionType::SYMTAB => "SYMTAB",
+        SectionType::STRTAB => "STRTAB",
+        SectionType::RELA => "RELA",
+        SectionType::HASH => "HASH",
+        SectionType::DYNAMIC => "DYNAMIC",
+        SectionType::NOTE => "NOTE",
+        SectionType::NOBITS => "NOBITS",
+        SectionType::REL => "REL",
+        SectionType::SHLIB => "SHLIB",
+        SectionType::DYNSYM => "DYNSYM",
+        SectionType::INIT_ARRAY => "INIT_ARRAY",
+        SectionType::FINI_ARRAY => "FINI_ARRAY",
+        SectionType::PREINIT_ARRAY => "PREINIT_ARRAY",
+        SectionType::GROUP => "GROUP",
+        SectionType::SYMTAB_SHNDX => "SYMTAB SECTION INDICES",
+        SectionType::GNU_ATTRIBUTES => "GNU_ATTRIBUTES",
+        SectionType::GNU_HASH => "GNU_HASH",
+        SectionType::GNU_LIBLIST => "GNU_LIBLIST",
+        SectionType::GNU_VERDEF => "VERDEF",
+        SectionType::GNU_VERNEED => "VERNEED",
+        SectionType::GNU_VERSYM => "VERSYM",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the canonical `R_*` name of the relocation type `relocation_type` on `machine`.
+pub fn relocation_type_name(machine: Machine, relocation_type: u32) -> Option<&'static str> {
+    let name = match machine {
+        Machine::I386 => match relocation_type {
+            0 => "R_386_NONE",
+            1 => "R_386_32",
+            2 => "R_386_PC32",
+            3 => "R_386_GOT32",
+            4 => "R_386_PLT32",
+            5 => "R_386_COPY",
+            6 => "R_386_GLOB_DAT",
+            7 => "R_386_JUMP_SLOT",
+            8 => "R_386_RELATIVE",
+            9 => "R_386_GOTOFF",
+            10 => "R_386_GOTPC",
+            11 => "R_386_32PLT",
+            14 => "R_386_TLS_TPOFF",
+            15 => "R_386_TLS_IE",
+            16 => "R_386_TLS_GOTIE",
+            17 => "R_386_TLS_LE",
+            18 => "R_386_TLS_GD",
+            19 => "R_386_TLS_LDM",
+            20 => "R_386_16",
+            21 => "R_386_PC16",
+            22 => "R_386_8",
+            23 => "R_386_PC8",
+            24 => "R_386_TLS_GD_32",
+            25 => "R_386_TLS_GD_PUSH",
+            26 => "R_386_TLS_GD_CALL",
+            27 => "R_386_TLS_GD_POP",
+            28 => "R_386_TLS_LDM_32",
+            29 => "R_386_TLS_LDM_PUSH",
+            30 => "R_386_TLS_LDM_CALL",
+            31 => "R_386_TLS_LDM_POP",
+            32 => "R_386_TLS_LDO_32",
+            33 => "R_386_TLS_IE_32",
+            34 => "R_386_TLS_LE_32",
+            35 => "R_386_TLS_DTPMOD32",
+            36 => "R_386_TLS_DTPOFF32",
+            37 => "R_386_TLS_TPOFF32",
+            38 => "R_386_SIZE32",
+            39 => "R_386_TLS_GOTDESC",
+            40 => "R_386_TLS_DESC_CALL",
+            41 => "R_386_TLS_DESC",
+            42 => "R_386_IRELATIVE",
+            43 => "R_386_GOT32X",
+            _ => return None,
+        },
+        Machine::ARM => match relocation_type {
+            0 => "R_ARM_NONE",
+            1 => "R_ARM_PC24",
+            2 => "R_ARM_ABS32",
+            3 => "R_ARM_REL32",
+            4 => "R_ARM_LDR_PC_G0",
+            5 => "R_ARM_ABS16",
+            6 => "R_ARM_ABS12",
+            7 => "R_ARM_THM_ABS5",
+            8 => "R_ARM_ABS8",
+            9 => "R_ARM_SBREL32",
+            10 => "R_ARM_THM_CALL",
+            11 => "R_ARM_THM_PC8",
+            12 => "R_ARM_BREL_ADJ",
+            13 => "R_ARM_TLS_DESC",
+            17 => "R_ARM_TLS_DTPMOD32",
+            18 => "R_ARM_TLS_DTPOFF32",
+            19 => "R_ARM_TLS_TPOFF32",
+            20 => "R_ARM_COPY",
+            21 => "R_ARM_GLOB_DAT",
+            22 => "R_ARM_JUMP_SLOT",
+            23 => "R_ARM_RELATIVE",
+            24 => "R_ARM_GOTOFF32",
+            25 => "R_ARM_BASE_PREL",
+            26 => "R_ARM_GOT_BREL",
+            27 => "R_ARM_PLT32",
+            28 => "R_ARM_CALL",
+            29 => "R_ARM_JUMP24",
+            30 => "R_ARM_THM_JUMP24",
+            38 => "R_ARM_TARGET1",
+            40 => "R_ARM_V4BX",
+            41 => "R_ARM_TARGET2",
+            42 => "R_ARM_PREL31",
+            43 => "R_ARM_MOVW_ABS_NC",
+            44 => "R_ARM_MOVT_ABS",
+            45 => "R_ARM_MOVW_PREL_NC",
+            46 => "R_ARM_MOVT_PREL",
+            47 => "R_ARM_THM_MOVW_ABS_NC",
+            48 => "R_ARM_THM_MOVT_ABS",
+            102 => "R_ARM_THM_JUMP11",
+            103 => "R_ARM_THM_JUMP8",
+            104 => "R_ARM_TLS_GD32",
+            105 => "R_ARM_TLS_LDM32",
+            106 => "R_ARM_TLS_LDO32",
+            107 => "R_ARM_TLS_IE32",
+            108 => "R_ARM_TLS_LE32",
+            160 => "R_ARM_IRELATIVE",
+            _ => return None,
+        },
+        Machine::X86_64 => match relocation_type {
+            0 => "R_X86_64_NONE",
+            1 => "R_X86_64_64",
+            2 => "R_X86_64_PC32",
+            3 => "R_X86_64_GOT32",
+            4 => "R_X86_64_PLT32",
+            5 => "R_X86_64_COPY",
+            6 => "R_X86_64_GLOB_DAT",
+            7 => "R_X86_64_JUMP_SLOT",
+            8 => "R_X86_64_RELATIVE",
+            9 => "R_X86_64_GOTPCREL",
+            10 => "R_X86_64_32",
+            11 => "R_X86_64_32S",
+            12 => "R_X86_64_16",
+            13 => "R_X86_64_PC16",
+            14 => "R_X86_64_8",
+            15 => "R_X86_64_PC8",
+            16 => "R_X86_64_DTPMOD64",
+            17 => "R_X86_64_DTPOFF64",
+            18 => "R_X86_64_TPOFF64",
+            19 => "R_X86_64_TLSGD",
+            20 => "R_X86_64_TLSLD",
+            21 => "R_X86_64_DTPOFF32",
+            22 => "R_X86_64_GOTTPOFF",
+            23 => "R_X86_64_TPOFF32",
+            24 => "R_X86_64_PC64",
+            25 => "R_X86_64_GOTOFF64",
+            26 => "R_X86_64_GOTPC32",
+            27 => "R_X86_64_GOT64",
+            28 => "R_X86_64_GOTPCREL64",
+            29 => "R_X86_64_GOTPC64",
+            30 => "R_X86_64_GOTPLT64",
+            31 => "R_X86_64_PLTOFF64",
+            32 => "R_X86_64_SIZE32",
+            33 => "R_X86_64_SIZE64",
+            34 => "R_X86_64_GOTPC32_TLSDESC",
+            35 => "R_X86_64_TLSDESC_CALL",
+            36 => "R_X86_64_TLSDESC",
+            37 => "R_X86_64_IRELATIVE",
+            38 => "R_X86_64_RELATIVE64",
+            41 => "R_X86_64_GOTPCRELX",
+            42 => "R_X86_64_REX_GOTPCRELX",
+            _ => return None,
+        },
+        Machine::AARCH64 => match relocation_type {
+            0 => "R_AARCH64_NONE",
+            257 => "R_AARCH64_ABS64",
+            258 => "R_AARCH64_ABS32",
+            259 => "R_AARCH64_ABS16",
+            260 => "R_AARCH64_PREL64",
+            261 => "R_AARCH64_PREL32",
+            262 => "R_AARCH64_PREL16",
+            263 => "R_AARCH64_MOVW_UABS_G0",
+            264 => "R_AARCH64_MOVW_UABS_G0_NC",
+            265 => "R_AARCH64_MOVW_UABS_G1",
+            266 => "R_AARCH64_MOVW_UABS_G1_NC",
+            267 => "R_AARCH64_MOVW_UABS_G2",
+            268 => "R_AARCH64_MOVW_UABS_G2_NC",
+            269 => "R_AARCH64_MOVW_UABS_G3",
+            274 => "R_AARCH64_LD_PREL_LO19",
+            275 => "R_AARCH64_ADR_PREL_LO21",
+            276 => "R_AARCH64_ADR_PREL_PG_HI21",
+            277 => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+            278 => "R_AARCH64_ADD_ABS_LO12_NC",
+            279 => "R_AARCH64_LDST8_ABS_LO12_NC",
+            280 => "R_AARCH64_TSTBR14",
+            281 => "R_AARCH64_CONDBR19",
+            282 => "R_AARCH64_JUMP26",
+            283 => "R_AARCH64_CALL26",
+            284 => "R_AARCH64_LDST16_ABS_LO12_NC",
+            285 => "R_AARCH64_LDST32_ABS_LO12_NC",
+            286 => "R_AARCH64_LDST64_ABS_LO12_NC",
+            299 => "R_AARCH64_LDST128_ABS_LO12_NC",
+            311 => "R_AARCH64_ADR_GOT_PAGE",
+            312 => "R_AARCH64_LD64_GOT_LO12_NC",
+            1024 => "R_AARCH64_COPY",
+            1025 => "R_AARCH64_GLOB_DAT",
+            1026 => "R_AARCH64_JUMP_SLOT",
+            1027 => "R_AARCH64_RELATIVE",
+            1028 => "R_AARCH64_TLS_DTPMOD",
+            1029 => "R_AARCH64_TLS_DTPREL",
+            1030 => "R_AARCH64_TLS_TPREL",
+            1031 => "R_AARCH64_TLSDESC",
+            1032 => "R_AARCH64_IRELATIVE",
+            _ => return None,
+        },
+        Machine::RISCV => match relocation_type {
+            0 => "R_RISCV_NONE",
+            1 => "R_RISCV_32",
+            2 => "R_RISCV_64",
+            3 => "R_RISCV_RELATIVE",
+            4 => "R_RISCV_COPY",
+            5 => "R_RISCV_JUMP_SLOT",
+            6 => "R_RISCV_TLS_DTPMOD32",
+            7 => "R_RISCV_TLS_DTPMOD64",
+            8 => "R_RISCV_TLS_DTPREL32",
+            9 => "R_RISCV_TLS_DTPREL64",
+            10 => "R_RISCV_TLS_TPREL32",
+            11 => "R_RISCV_TLS_TPREL64",
+            12 => "R_RISCV_TLSDESC",
+            16 => "R_RISCV_BRANCH",
+            17 => "R_RISCV_JAL",
+            18 => "R_RISCV_CALL",
+            19 => "R_RISCV_CALL_PLT",
+            20 => "R_RISCV_GOT_HI20",
+            21 => "R_RISCV_TLS_GOT_HI20",
+            22 => "R_RISCV_TLS_GD_HI20",
+            23 => "R_RISCV_PCREL_HI20",
+            24 => "R_RISCV_PCREL_LO12_I",
+            25 => "R_RISCV_PCREL_LO12_S",
+            26 => "R_RISCV_HI20",
+            27 => "R_RISCV_LO12_I",
+            28 => "R_RISCV_LO12_S",
+            29 => "R_RISCV_TPREL_HI20",
+            30 => "R_RISCV_TPREL_LO12_I",
+            31 => "R_RISCV_TPREL_LO12_S",
+            32 => "R_RISCV_TPREL_ADD",
+            33 => "R_RISCV_ADD8",
+            34 => "R_RISCV_ADD16",
+            35 => "R_RISCV_ADD32",
+            36 => "R_RISCV_ADD64",
+            37 => "R_RISCV_SUB8",
+            38 => "R_RISCV_SUB16",
+            39 => "R_RISCV_SUB32",
+            40 => "R_RISCV_SUB64",
+            43 => "R_RISCV_ALIGN",
+            44 => "R_RISCV_RVC_BRANCH",
+            45 => "R_RISCV_RVC_JUMP",
+            51 => "R_RISCV_RELAX",
+            52 => "R_RISCV_SUB6",
+            53 => "R_RISCV_SET6",
+            54 => "R_RISCV_SET8",
+            55 => "R_RISCV_SET16",
+            56 => "R_RISCV_SET32",
+            57 => "R_RISCV_32_PCREL",
+            58 => "R_RISCV_IRELATIVE",
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`ElfDynamicTag`].
+pub fn dynamic_tag_name(tag: ElfDynamicTag) -> Option<&'static str> {
+    let name = match tag {
+        ElfDynamicTag::NULL => "NULL",
+        ElfDynamicTag::NEEDED => "NEEDED",
+        ElfDynamicTag::PLT_REL_SIZE => "PLTRELSZ",
+        ElfDynamicTag::PLT_GOT => "PLTGOT",
+        ElfDynamicTag::HASH => "HASH",
+        ElfDynamicTag::STRING_TABLE => "STRTAB",
+        ElfDynamicTag::SYMBOL_TABLE => "SYMTAB",
+        ElfDynamicTag::RELA_TABLE => "RELA",
+        ElfDynamicTag::RELA_SIZE => "RELASZ",
+        ElfDynamicTag::RELA_ENTRY_SIZE => "RELAENT",
+        ElfDynamicTag::STRING_TABLE_SIZE => "STRSZ",
+        ElfDynamicTag::SYMBOL_ENTRY_SIZE => "SYMENT",
+        ElfDynamicTag::INIT => "INIT",
+        ElfDynamicTag::FINI => "FINI",
+        ElfDynamicTag::SO_NAME => "SONAME",
+        ElfDynamicTag::RPATH => "RPATH",
+        ElfDynamicTag::SYMBOLIC => "SYMBOLIC",
+        ElfDynamicTag::REL_TABLE => "REL",
+        ElfDynamicTag::REL_SIZE => "RELSZ",
+        ElfDynamicTag::REL_ENTRY_SIZE => "RELENT",
+        ElfDynamicTag::PLT_REL => "PLTREL",
+        ElfDynamicTag::DEBUG => "DEBUG",
+        ElfDynamicTag::TEXT_REL => "TEXTREL",
+        ElfDynamicTag::JMP_REL => "JMPREL",
+        ElfDynamicTag::BIND_NOW => "BIND_NOW",
+        ElfDynamicTag::INIT_ARRAY => "INIT_ARRAY",
+        ElfDynamicTag::FINI_ARRAY => "FINI_ARRAY",
+        ElfDynamicTag::INIT_ARRAY_SIZE => "INIT_ARRAYSZ",
+        ElfDynamicTag::FINI_ARRAY_SIZE => "FINI_ARRAYSZ",
+        ElfDynamicTag::RUNPATH => "RUNPATH",
+        ElfDynamicTag::FLAGS => "FLAGS",
+        ElfDynamicTag::PREINIT_ARRAY => "PREINIT_ARRAY",
+        ElfDynamicTag::PREINIT_ARRAY_SIZE => "PREINIT_ARRAYSZ",
+        ElfDynamicTag::SYMBOL_TABLE_SECTION_INDEX => "SYMTAB_SHNDX",
+        ElfDynamicTag::RELR_SIZE => "RELRSZ",
+        ElfDynamicTag::RELR_TABLE => "RELR",
+        ElfDynamicTag::RELR_ENTRY_SIZE => "RELRENT",
+        ElfDynamicTag::GNU_HASH => "GNU_HASH",
+        ElfDynamicTag::VERSYM => "VERSYM",
+        ElfDynamicTag::RELA_COUNT => "RELACOUNT",
+        ElfDynamicTag::REL_COUNT => "RELCOUNT",
+        ElfDynamicTag::FLAGS_1 => "FLAGS_1",
+        ElfDynamicTag::VERDEF => "VERDEF",
+        ElfDynamicTag::VERDEF_NUM => "VERDEFNUM",
+        ElfDynamicTag::VERNEED => "VERNEED",
+        ElfDynamicTag::VERNEED_NUM => "VERNEEDNUM",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the description `readelf` uses for notes of type `kind` owned by the entity named
+/// `owner`.
+pub fn note_type_name(owner: &[u8], kind: u32) -> Option<&'static str> {
+    let name = match owner {
+        ELF_NOTE_GNU => match kind {
+            NT_GNU_ABI_TAG => "NT_GNU_ABI_TAG (ABI version tag)",
+            NT_GNU_HWCAP => "NT_GNU_HWCAP (DSO-supplied software HWCAP info)",
+            NT_GNU_BUILD_ID => "NT_GNU_BUILD_ID (unique build ID bitstring)",
+            NT_GNU_GOLD_VERSION => "NT_GNU_GOLD_VERSION (gold version)",
+            NT_GNU_PROPERTY_TYPE_0 => "NT_GNU_PROPERTY_TYPE_0",
+            _ => return None,
+        },
+        ELF_NOTE_CORE | ELF_NOTE_LINUX => match kind {
+            NT_PRSTATUS => "NT_PRSTATUS (prstatus structure)",
+            NT_PRFPREG => "NT_FPREGSET (floating point registers)",
+            NT_PRPSINFO => "NT_PRPSINFO (prpsinfo structure)",
+            NT_TASKSTRUCT => "NT_TASKSTRUCT (task structure)",
+            NT_AUXV => "NT_AUXV (auxiliary vector)",
+            NT_SIGINFO => "NT_SIGINFO (siginfo_t data)",
+            NT_FILE => "NT_FILE (mapped files)",
+            NT_PRXFPREG => "NT_PRXFPREG (user_xfpregs structure)",
+            NT_X86_XSTATE => "NT_X86_XSTATE (x86 XSAVE extended state)",
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`SymbolType`].
+pub fn symbol_type_name(symbol_type: SymbolType) -> Option<&'static str> {
+    let name = match symbol_type {
+        SymbolType::NO_TYPE => "NOTYPE",
+        SymbolType::OBJECT => "OBJECT",
+        SymbolType::FUNCTION => "FUNC",
+        SymbolType::SECTION => "SECTION",
+        SymbolType::FILE => "FILE",
+        SymbolType::COMMON => "COMMON",
+        SymbolType::TLS => "TLS",
+        SymbolType(10) => "IFUNC",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`SymbolBinding`].
+pub fn symbol_binding_name(binding: SymbolBinding) -> Option<&'static str> {
+    let name = match binding {
+        SymbolBinding::LOCAL => "LOCAL",
+        SymbolBinding::GLOBAL => "GLOBAL",
+        SymbolBinding::WEAK => "WEAK",
+        SymbolBinding(10) => "UNIQUE",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name `readelf` uses for the given [`SymbolVisibility`].
+pub fn symbol_visibility_name(visibility: SymbolVisibility) -> Option<&'static str> {
+    let name = match visibility {
+        SymbolVisibility::DEFAULT => "DEFAULT",
+        SymbolVisibility::INTERNAL => "INTERNAL",
+        SymbolVisibility::HIDDEN => "HIDDEN",
+        SymbolVisibility::PROTECTED => "PROTECTED",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Formats a name taken from an ELF file, such as a symbol or section name, escaping any bytes
+/// that are not valid UTF-8 as `\xNN` instead of failing.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct LossyName<'slice>(pub &'slice [u8]);
+
+impl<'slice> fmt::Display for LossyName<'slice> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut length = 0usize;
+        for chunk in self.0.utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            length = length.saturating_add(chunk.valid().chars().count());
+            for byte in chunk.invalid() {
+                write!(f, "\\x{byte:02x}")?;
+                length = length.saturating_add(4);
+            }
+        }
+
+        for _ in length..f.width().unwrap_or(0) {
+            f.write_str(" ")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'slice> fmt::Debug for LossyName<'slice> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for chunk in self.0.utf8_chunks() {
+            write!(f, "{}", chunk.valid().escape_debug())?;
+            for byte in chunk.invalid() {
+                write!(f, "\\x{byte:02x}")?;
+            }
+        }
+        f.write_str("\"")
+    }
+}
+
+/// Formats `flags` as the `R`, `W` and `E` letters `readelf` prints for segments.
+pub struct SegmentFlagsDisplay(pub SegmentFlags);
+
+impl fmt::Display for SegmentFlagsDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = |flag: SegmentFlags, letter: char| {
+            if self.0 .0 & flag.0 == flag.0 {
+                letter
+            } else {
+                ' '
+            }
+        };
+
+        write!(
+            f,
+            "{}{}{}",
+            letter(SegmentFlags::READ, 'R'),
+            letter(SegmentFlags::WRITE, 'W'),
+            letter(SegmentFlags::EXECUTE, 'E'),
+        )
+    }
+}
+
+/// Formats `flags` as the letters `readelf` prints for sections.
+pub struct SectionFlagsDisplay(pub SectionFlags);
+
+impl fmt::Display for SectionFlagsDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ExtendedSectionFlagsDisplay {
+            flags: self.0,
+            machine: None,
+        }
+        .fmt(f)
+    }
+}
+
+/// Formats [`SectionFlags`] like [`SectionFlagsDisplay`], consulting a [`MachineExt`] for the
+/// letters of processor-specific flags.
+struct ExtendedSectionFlagsDisplay<'ext> {
+    /// The [`SectionFlags`] to format.
+    flags: SectionFlags,
+    /// The [`MachineExt`] consulted for processor-specific flags.
+    machine: Option<&'ext dyn MachineExt>,
+}
+
+impl<'ext> fmt::Display for ExtendedSectionFlagsDisplay<'ext> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// The flag marking a section as retained by the GNU linker's garbage collection.
+        const RETAIN: u64 = 0x0020_0000;
+        /// The flag marking a section as excluded from executables and shared objects.
+        const EXCLUDE: u64 = 0x8000_0000;
+
+        let mut letters = [' '; 64];
+        let mut count = 0usize;
+        for bit in 0..u64::BITS {
+            let flag = 1u64.wrapping_shl(bit);
+            if self.flags.0 & flag == 0 {
+                continue;
+            }
+
+            let machine_letter = self
+                .machine
+                .and_then(|machine| machine.section_flag_letter(SectionFlags(flag)));
+            let letter = match SectionFlags(flag) {
+                SectionFlags::WRITE => 'W',
+                SectionFlags::ALLOC => 'A',
+                SectionFlags::EXECINSTR => 'X',
+                SectionFlags::MERGE => 'M',
+                SectionFlags::STRINGS => 'S',
+                SectionFlags::INFO_LINK => 'I',
+                SectionFlags::LINK_ORDER => 'L',
+                SectionFlags::OS_NONCONFORMING => 'O',
+                SectionFlags::GROUP => 'G',
+                SectionFlags::TLS => 'T',
+                SectionFlags::COMPRESSED => 'C',
+                SectionFlags(RETAIN) => 'R',
+                flags if flags.has_os_specific_bits() => 'o',
+                flags if flags.has_processor_specific_bits() => match machine_letter {
+                    Some(letter) => letter,
+                    None if flags == SectionFlags(EXCLUDE) => 'E',
+                    None => 'p',
+                },
+                SectionFlags(_) => 'x',
+            };
+            if let Some(slot) = letters.get_mut(count) {
+                *slot = letter;
+                count = count.saturating_add(1);
+            }
+        }
+
+        let width = f.width().unwrap_or(0);
+        for _ in count..width {
+            f.write_str(" ")?;
+        }
+        for letter in letters.iter().take(count) {
+            write!(f, "{letter}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats an [`ElfHeader`] like `readelf --file-header`.
+pub struct ElfHeaderDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfHeader`] to format.
+    header: ElfHeader<'slice, C, E>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfHeaderDisplay<'slice, C, E> {
+    /// Creates a new [`ElfHeaderDisplay`] that formats `header`.
+    pub fn new(header: ElfHeader<'slice, C, E>) -> Self {
+        Self { header }
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display for ElfHeaderDisplay<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = &self.header;
+        let ident = header.elf_ident();
+
+        writeln!(f, "ELF Header:")?;
+        write!(f, "  Magic:  ")?;
+        for byte in ident.bytes() {
+            write!(f, " {byte:02x}")?;
+        }
+        writeln!(f)?;
+
+        let class = match ident.class() {
+            Class::Class32 => "ELF32",
+            Class::Class64 => "ELF64",
+        };
+        writeln!(f, "  {:<35}{class}", "Class:")?;
+
+        let data = match ident.encoding() {
+            Encoding::TwosComplementLittleEndian => "2's complement, little endian",
+            Encoding::TwosComplementBigEndian => "2's complement, big endian",
+        };
+        writeln!(f, "  {:<35}{data}", "Data:")?;
+        writeln!(
+            f,
+            "  {:<35}{} (current)",
+            "Version:",
+            ident.header_version()
+        )?;
+
+        write!(f, "  {:<35}", "OS/ABI:")?;
+        match os_abi_name(ident.os_abi()) {
+            Some(name) => writeln!(f, "{name}")?,
+            None => writeln!(f, "<unknown: {:x}>", ident.os_abi().0)?,
+        }
+        writeln!(f, "  {:<35}{}", "ABI Version:", ident.abi_version())?;
+
+        write!(f, "  {:<35}", "Type:")?;
+        match elf_type_name(header.elf_type()) {
+            Some(name) => writeln!(f, "{name}")?,
+            None => writeln!(f, "<unknown>: {:x}", header.elf_type().0)?,
+        }
+
+        write!(f, "  {:<35}", "Machine:")?;
+        match machine_name(header.machine()) {
+            Some(name) => writeln!(f, "{name}")?,
+            None => writeln!(f, "<unknown>: 0x{:x}", header.machine().0)?,
+        }
+
+        writeln!(
+            f,
+            "  {:<35}0x{:x}",
+            "Version:",
+            header.object_file_version()
+        )?;
+        writeln!(f, "  {:<35}0x{:x}", "Entry point address:", header.entry())?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes into file)",
+            "Start of program headers:",
+            header.program_header_offset()
+        )?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes into file)",
+            "Start of section headers:",
+            header.section_header_offset()
+        )?;
+        writeln!(f, "  {:<35}0x{:x}", "Flags:", header.flags())?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes)",
+            "Size of this header:",
+            header.elf_header_size()
+        )?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes)",
+            "Size of program headers:",
+            header.program_header_entry_size()
+        )?;
+        writeln!(
+            f,
+            "  {:<35}{}",
+            "Number of program headers:",
+            header.program_header_count()
+        )?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes)",
+            "Size of section headers:",
+            header.section_header_entry_size()
+        )?;
+        write!(
+            f,
+            "  {:<35}{}",
+            "Number of section headers:",
+            header.section_header_count()
+        )?;
+        match header.real_section_header_count() {
+            Some(count) if count != u64::from(header.section_header_count()) => {
+                writeln!(f, " ({count})")?
+            }
+            _ => writeln!(f)?,
+        }
+        write!(
+            f,
+            "  {:<35}{}",
+            "Section header string table index:",
+            header.section_header_string_table_index()
+        )?;
+        match header.real_section_header_string_table_index() {
+            Some(index) if index != u32::from(header.section_header_string_table_index()) => {
+                writeln!(f, " ({index})")
+            }
+            _ => writeln!(f),
+        }
+    }
+}
+
+/// Formats a single [`ElfProgramHeader`] as one entry of `readelf --segments`.
+///
+/// Without access to the [`ElfHeader`], the given extensions are consulted as is, so they should
+/// be chosen using [`select_os_abi_ext`][crate::ext::select_os_abi_ext] and
+/// [`select_machine_ext`][crate::ext::select_machine_ext].
+pub struct ElfProgramHeaderDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfProgramHeader`] to format.
+    program_header: ElfProgramHeader<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderDisplay<'slice, C, E> {
+    /// Creates a new [`ElfProgramHeaderDisplay`] that formats `program_header`.
+    pub fn new(program_header: ElfProgramHeader<'slice, C, E>) -> Self {
+        Self {
+            program_header,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display
+    for ElfProgramHeaderDisplay<'slice, C, E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let program_header = &self.program_header;
+
+        match self
+            .extensions
+            .segment_type_name(program_header.segment_type())
+        {
+            Some(name) => write!(f, "  {name:<14}")?,
+            None => write!(f, "  0x{:<12x}", program_header.segment_type().0)?,
+        }
+
+        match program_header.class.into_class() {
+            Class::Class32 => write!(
+                f,
+                " 0x{:06x} 0x{:08x} 0x{:08x} 0x{:05x} 0x{:05x} {} 0x{:x}",
+                program_header.file_offset(),
+                program_header.virtual_address(),
+                program_header.physical_address(),
+                program_header.file_size(),
+                program_header.memory_size(),
+                SegmentFlagsDisplay(program_header.flags()),
+                program_header.alignment(),
+            ),
+            Class::Class64 => {
+                writeln!(
+                    f,
+                    " 0x{:016x} 0x{:016x} 0x{:016x}",
+                    program_header.file_offset(),
+                    program_header.virtual_address(),
+                    program_header.physical_address(),
+                )?;
+                write!(
+                    f,
+                    "                 0x{:016x} 0x{:016x}  {}    0x{:x}",
+                    program_header.file_size(),
+                    program_header.memory_size(),
+                    SegmentFlagsDisplay(program_header.flags()),
+                    program_header.alignment(),
+                )
+            }
+        }
+    }
+}
+
+/// Formats an [`ElfProgramHeaderTable`] like `readelf --segments`.
+///
+/// Without access to the [`ElfHeader`], the given extensions are consulted as is, so they should
+/// be chosen using [`select_os_abi_ext`][crate::ext::select_os_abi_ext] and
+/// [`select_machine_ext`][crate::ext::select_machine_ext].
+pub struct ElfProgramHeaderTableDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfProgramHeaderTable`] to format.
+    table: ElfProgramHeaderTable<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderTableDisplay<'slice, C, E> {
+    /// Creates a new [`ElfProgramHeaderTableDisplay`] that formats `table`.
+    pub fn new(table: ElfProgramHeaderTable<'slice, C, E>) -> Self {
+        Self {
+            table,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display
+    for ElfProgramHeaderTableDisplay<'slice, C, E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Program Headers:")?;
+        match self.table.class.into_class() {
+            Class::Class32 => writeln!(
+                f,
+                "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align"
+            )?,
+            Class::Class64 => {
+                writeln!(
+                    f,
+                    "  Type           Offset             VirtAddr           PhysAddr"
+                )?;
+                writeln!(
+                    f,
+                    "                 FileSiz            MemSiz              Flags  Align"
+                )?;
+            }
+        }
+        for program_header in self.table.iter() {
+            let display = ElfProgramHeaderDisplay {
+                program_header,
+                extensions: self.extensions,
+            };
+            writeln!(f, "{display}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a single [`ElfSectionHeader`] as one entry of `readelf --wide --section-headers`,
+/// resolving its name through the section name string table if one is available.
+///
+/// Without access to the [`ElfHeader`], the given extensions are consulted as is, so they should
+/// be chosen using [`select_os_abi_ext`][crate::ext::select_os_abi_ext] and
+/// [`select_machine_ext`][crate::ext::select_machine_ext].
+pub struct ElfSectionHeaderDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The index of the [`ElfSectionHeader`] in its table.
+    index: usize,
+    /// The [`ElfSectionHeader`] to format.
+    section_header: ElfSectionHeader<'slice, C, E>,
+    /// The [`ElfStringTable`] holding the names of the sections.
+    names: Option<ElfStringTable<'slice>>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSectionHeaderDisplay<'slice, C, E> {
+    /// Creates a new [`ElfSectionHeaderDisplay`] that formats `section_header`, located at
+    /// `index` in its table, using `names` to resolve its name.
+    pub fn new(
+        index: usize,
+        section_header: ElfSectionHeader<'slice, C, E>,
+        names: Option<ElfStringTable<'slice>>,
+    ) -> Self {
+        Self {
+            index,
+            section_header,
+            names,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+
+    /// Returns the resolved name of the section, if any.
+    fn name(&self) -> Option<&'slice [u8]> {
+        self.section_header.name_str(self.names?).ok()
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display
+    for ElfSectionHeaderDisplay<'slice, C, E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let section_header = &self.section_header;
+
+        write!(f, "  [{:>2}] ", self.index)?;
+        match self.name() {
+            Some(name) => write!(f, "{:<17}", LossyName(name))?,
+            None => write!(f, "<no-strings>     ")?,
+        }
+        match self.extensions.section_type_name(section_header.kind()) {
+            Some(name) => write!(f, " {name:<15}")?,
+            None => write!(f, " {:<#15x}", section_header.kind().0)?,
+        }
+        match section_header.class.into_class() {
+            Class::Class32 => write!(f, " {:08x}", section_header.address())?,
+            Class::Class64 => write!(f, " {:016x}", section_header.address())?,
+        }
+
+        write!(
+            f,
+            " {:06x} {:06x} {:02x} {:>3} {:>2} {:>3} {:>2}",
+            section_header.offset(),
+            section_header.size(),
+            section_header.entry_size(),
+            self.extensions.section_flags(section_header.flags()),
+            section_header.link(),
+            section_header.info(),
+            section_header.address_align(),
+        )
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfSectionHeaderDisplay<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Formats the [`SectionType`] of a section by name if possible.
+        struct KindDebug(SectionType);
+
+        impl fmt::Debug for KindDebug {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match section_type_name(self.0) {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "{:#x}", self.0 .0),
+                }
+            }
+        }
+
+        /// Formats the [`SectionFlags`] of a section as `readelf` letters.
+        struct FlagsDebug(SectionFlags);
+
+        impl fmt::Debug for FlagsDebug {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", SectionFlagsDisplay(self.0))
+            }
+        }
+
+        let section_header = &self.section_header;
+        f.debug_struct("ElfSectionHeader")
+            .field("index", &self.index)
+            .field("name", &self.name().map(LossyName))
+            .field("kind", &KindDebug(section_header.kind()))
+            .field("flags", &FlagsDebug(section_header.flags()))
+            .field("address", &section_header.address())
+            .field("offset", &section_header.offset())
+            .field("size", &section_header.size())
+            .field("link", &section_header.link())
+            .field("info", &section_header.info())
+            .field("address_align", &section_header.address_align())
+            .field("entry_size", &section_header.entry_size())
+            .finish()
+    }
+}
+
+/// Formats the section header table of an [`ElfFile`] like `readelf --wide --section-headers`.
+pub struct ElfSectionHeaderTableDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose section header table is formatted.
+    file: ElfFile<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSectionHeaderTableDisplay<'slice, C, E> {
+    /// Creates a new [`ElfSectionHeaderTableDisplay`] that formats the section header table of
+    /// `file`.
+    pub fn new(file: ElfFile<'slice, C, E>) -> Self {
+        Self {
+            file,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display
+    for ElfSectionHeaderTableDisplay<'slice, C, E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(table) = self.file.section_header_table() else {
+            return writeln!(f, "There are no sections in this file.");
+        };
+        let names = self.file.section_name_string_table();
+        let extensions = self.extensions.matching(self.file);
+
+        writeln!(f, "Section Headers:")?;
+        match self.file.class.into_class() {
+            Class::Class32 => writeln!(
+                f,
+                "  [Nr] Name              Type            Addr     Off    Size   ES Flg Lk Inf Al"
+            )?,
+            Class::Class64 => writeln!(
+                f,
+                "  [Nr] Name              Type            Address          Off    Size   ES Flg Lk Inf Al"
+            )?,
+        }
+        for (index, section_header) in table.iter().enumerate() {
+            let display = ElfSectionHeaderDisplay {
+                index,
+                section_header,
+                names,
+                extensions,
+            };
+            writeln!(f, "{display}")?;
+        }
+
+        writeln!(f, "Key to Flags:")?;
+        writeln!(
+            f,
+            "  W (write), A (alloc), X (execute), M (merge), S (strings), I (info),"
+        )?;
+        writeln!(
+            f,
+            "  L (link order), O (extra OS processing required), G (group), T (TLS),"
+        )?;
+        writeln!(
+            f,
+            "  C (compressed), x (unknown), o (OS specific), E (exclude),"
+        )?;
+        writeln!(f, "  D (mbind), l (large), p (processor specific)")
+    }
+}
+
+/// Formats the [`SectionType::DYNAMIC`] section of an [`ElfFile`] like `readelf --wide --dynamic`.
+pub struct ElfDynamicDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose dynamic section is formatted.
+    file: ElfFile<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfDynamicDisplay<'slice, C, E> {
+    /// Creates a new [`ElfDynamicDisplay`] that formats the dynamic section of `file`.
+    pub fn new(file: ElfFile<'slice, C, E>) -> Self {
+        Self {
+            file,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display for ElfDynamicDisplay<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file;
+        let extensions = self.extensions.matching(file);
+        let section_header_table = file.section_header_table();
+        let section_header = section_header_table.and_then(|table| {
+            table
+                .iter()
+                .find(|section_header| section_header.kind() == SectionType::DYNAMIC)
+        });
+        let Some((section_header, data)) = section_header
+            .and_then(|section_header| Some((section_header, file.section_bytes(section_header)?)))
+        else {
+            return writeln!(f, "There is no dynamic section in this file.");
+        };
+        let strings = section_header_table
+            .and_then(|table| table.get(section_header.link().try_into().ok()?))
+            .and_then(|string_table_header| file.section_bytes(string_table_header))
+            .map(ElfStringTable::new);
+
+        let dynamic_table = ElfDynamicTable::parse(data, file.class, file.encoding);
+        let entries = || (0..).map_while(|index| dynamic_table.get_entry(index));
+        let entry_count = entries()
+            .position(|entry| entry.raw_tag() == i64::from(ElfDynamicTag::NULL.0))
+            .map_or_else(|| entries().count(), |index| index.saturating_add(1));
+
+        writeln!(
+            f,
+            "Dynamic section at offset {:#x} contains {entry_count} entries:",
+            section_header.offset()
+        )?;
+        writeln!(f, "  Tag        Type                         Name/Value")?;
+        for entry in entries().take(entry_count) {
+            let (tag, value) = (entry.raw_tag(), entry.value());
+            let known_tag = i32::try_from(tag).ok().map(ElfDynamicTag);
+            match file.class.into_class() {
+                Class::Class32 => write!(f, " {:#010x}", tag as u32)?,
+                Class::Class64 => write!(f, " {:#018x}", tag as u64)?,
+            }
+            match known_tag.and_then(|tag| extensions.dynamic_tag_name(tag)) {
+                Some(name) => write!(f, " {:<20} ", Parenthesized(name))?,
+                None => write!(f, " {:<20} ", Parenthesized(HexValue(tag as u64)))?,
+            }
+
+            let string = |prefix: &str, f: &mut fmt::Formatter<'_>| match strings
+                .and_then(|strings| strings.get(value).ok())
+            {
+                Some(string) => writeln!(f, "{prefix}: [{}]", LossyName(string)),
+                None => writeln!(f, "<string table index: {value}>"),
+            };
+
+            match known_tag.unwrap_or(ElfDynamicTag(-1)) {
+                ElfDynamicTag::NEEDED => string("Shared library", f)?,
+                ElfDynamicTag::SO_NAME => string("Library soname", f)?,
+                ElfDynamicTag::RPATH => string("Library rpath", f)?,
+                ElfDynamicTag::RUNPATH => string("Library runpath", f)?,
+                ElfDynamicTag::PLT_REL_SIZE
+                | ElfDynamicTag::RELA_SIZE
+                | ElfDynamicTag::RELA_ENTRY_SIZE
+                | ElfDynamicTag::STRING_TABLE_SIZE
+                | ElfDynamicTag::SYMBOL_ENTRY_SIZE
+                | ElfDynamicTag::REL_SIZE
+                | ElfDynamicTag::REL_ENTRY_SIZE
+                | ElfDynamicTag::INIT_ARRAY_SIZE
+                | ElfDynamicTag::FINI_ARRAY_SIZE
+                | ElfDynamicTag::PREINIT_ARRAY_SIZE
+                | ElfDynamicTag::RELR_SIZE
+                | ElfDynamicTag::RELR_ENTRY_SIZE => writeln!(f, "{value} (bytes)")?,
+                ElfDynamicTag::PLT_REL => match i32::try_from(value).map(ElfDynamicTag) {
+                    Ok(ElfDynamicTag::RELA_TABLE) => writeln!(f, "RELA")?,
+                    Ok(ElfDynamicTag::REL_TABLE) => writeln!(f, "REL")?,
+                    _ => writeln!(f, "{value:#x}")?,
+                },
+                ElfDynamicTag::VERDEF_NUM
+                | ElfDynamicTag::VERNEED_NUM
+                | ElfDynamicTag::RELA_COUNT
+                | ElfDynamicTag::REL_COUNT => writeln!(f, "{value}")?,
+                ElfDynamicTag::FLAGS => {
+                    DynamicFlagsDisplay::new(value, DYNAMIC_FLAGS).fmt(f)?;
+                    writeln!(f)?;
+                }
+                ElfDynamicTag::FLAGS_1 => {
+                    write!(f, "Flags:")?;
+                    if value != 0 {
+                        write!(f, " ")?;
+                    }
+                    DynamicFlagsDisplay::new(value, DYNAMIC_FLAGS_1).fmt(f)?;
+                    writeln!(f)?;
+                }
+                _ => writeln!(f, "{value:#x}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The names of the flags stored in the [`ElfDynamicTag::FLAGS`] entry.
+const DYNAMIC_FLAGS: &[(u64, &str)] = &[
+    (0x1, "ORIGIN"),
+    (0x2, "SYMBOLIC"),
+    (0x4, "TEXTREL"),
+    (0x8, "BIND_NOW"),
+    (0x10, "STATIC_TLS"),
+];
+
+/// The names of the flags stored in the `DT_FLAGS_1` entry.
+const DYNAMIC_FLAGS_1: &[(u64, &str)] = &[
+    (0x1, "NOW"),
+    (0x2, "GLOBAL"),
+    (0x4, "GROUP"),
+    (0x8, "NODELETE"),
+    (0x10, "LOADFLTR"),
+    (0x20, "INITFIRST"),
+    (0x40, "NOOPEN"),
+    (0x80, "ORIGIN"),
+    (0x100, "DIRECT"),
+    (0x400, "INTERPOSE"),
+    (0x800, "NODEFLIB"),
+    (0x1000, "NODUMP"),
+    (0x2000, "CONFALT"),
+    (0x4000, "ENDFILTEE"),
+    (0x8000, "DISPRELDNE"),
+    (0x1_0000, "DISPRELPND"),
+    (0x2_0000, "NODIRECT"),
+    (0x4_0000, "IGNMULDEF"),
+    (0x8_0000, "NOKSYMS"),
+    (0x10_0000, "NOHDR"),
+    (0x20_0000, "EDITED"),
+    (0x40_0000, "NORELOC"),
+    (0x80_0000, "SYMINTPOSE"),
+    (0x100_0000, "GLOBAUDIT"),
+    (0x200_0000, "SINGLETON"),
+    (0x400_0000, "STUB"),
+    (0x800_0000, "PIE"),
+];
+
+/// Formats a set of dynamic flags as a space-separated list of names.
+struct DynamicFlagsDisplay {
+    /// The flags to format.
+    flags: u64,
+    /// The names of the known flags.
+    names: &'static [(u64, &'static str)],
+}
+
+impl DynamicFlagsDisplay {
+    /// Creates a new [`DynamicFlagsDisplay`] that formats `flags` using `names`.
+    fn new(flags: u64, names: &'static [(u64, &'static str)]) -> Self {
+        Self { flags, names }
+    }
+}
+
+impl fmt::Display for DynamicFlagsDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut remaining = self.flags;
+        let mut separator = "";
+        for &(flag, name) in self.names {
+            if remaining & flag != 0 {
+                write!(f, "{separator}{name}")?;
+                remaining &= !flag;
+                separator = " ";
+            }
+        }
+
+        if remaining != 0 || self.flags == 0 {
+            write!(f, "{separator}{remaining:#x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the relocation sections of an [`ElfFile`] like `readelf --wide --relocs`.
+pub struct ElfRelocationsDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose relocation sections are formatted.
+    file: ElfFile<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfRelocationsDisplay<'slice, C, E> {
+    /// Creates a new [`ElfRelocationsDisplay`] that formats the relocation sections of `file`.
+    pub fn new(file: ElfFile<'slice, C, E>) -> Self {
+        Self {
+            file,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display for ElfRelocationsDisplay<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file;
+        let extensions = self.extensions.matching(file);
+        let machine = file.header().machine();
+        let names = file.section_name_string_table();
+        let section_header_table = file.section_header_table();
+
+        let mut found = false;
+        for section_header in section_header_table.iter().flat_map(|table| table.iter()) {
+            let has_addend = match section_header.kind() {
+                SectionType::RELA => true,
+                SectionType::REL => false,
+                _ => continue,
+            };
+            let Some(data) = file.section_bytes(section_header) else {
+                continue;
+            };
+            let relocation_table = ElfRelocationTable {
+                slice: data,
+                has_addend,
+                machine,
+                class: file.class,
+                encoding: file.encoding,
+            };
+            let entry_count = relocation_table.len();
+            let symbol_table = section_header_table
+                .and_then(|table| table.get(section_header.link().try_into().ok()?))
+                .and_then(|symbol_table_header| file.symbol_table_in(symbol_table_header));
+
+            if found {
+                writeln!(f)?;
+            }
+            found = true;
+
+            write!(f, "Relocation section ")?;
+            match names.and_then(|names| section_header.name_str(names).ok()) {
+                Some(name) => write!(f, "'{}'", LossyName(name))?,
+                None => write!(f, "'<no-strings>'")?,
+            }
+            writeln!(
+                f,
+                " at offset {:#x} contains {entry_count} {}:",
+                section_header.offset(),
+                if entry_count == 1 { "entry" } else { "entries" }
+            )?;
+
+            let (address_width, type_width) = match file.class.into_class() {
+                Class::Class32 => {
+                    write!(
+                        f,
+                        " Offset     Info    Type                Sym. Value  Symbol's Name"
+                    )?;
+                    (8, 17)
+                }
+                Class::Class64 => {
+                    write!(
+                        f,
+                        "    Offset             Info             Type               Symbol's Value  Symbol's Name"
+                    )?;
+                    (16, 22)
+                }
+            };
+            if has_addend {
+                write!(f, " + Addend")?;
+            }
+            writeln!(f)?;
+
+            for relocation in relocation_table.iter() {
+                write!(
+                    f,
+                    "{:0address_width$x}  {:0address_width$x} ",
+                    relocation.offset(),
+                    relocation.info()
+                )?;
+                let kind = relocation.relocation_type();
+                match extensions.relocation_type_name(machine, kind) {
+                    Some(name) => write!(f, "{name:<type_width$}")?,
+                    None => write!(f, "{:<type_width$}", Unrecognized(kind))?,
+                }
+
+                let symbol = symbol_table
+                    .filter(|_| relocation.symbol_index() != 0)
+                    .and_then(|table| table.get(relocation.symbol_index().try_into().ok()?));
+                if let Some(symbol) = symbol {
+                    write!(f, " {:0address_width$x} ", symbol.value())?;
+                    let name = match symbol.name() {
+                        Ok(name) if !name.is_empty() => Some(name),
+                        _ if symbol.info().symbol_type() == SymbolType::SECTION => {
+                            section_header_table
+                                .and_then(|table| table.get(symbol.section_index().into()))
+                                .zip(names)
+                                .and_then(|(section, names)| section.name_str(names).ok())
+                        }
+                        _ => extensions
+                            .section_index_name(SectionIndex(symbol.section_index()))
+                            .map(str::as_bytes),
+                    };
+                    write!(f, "{}", LossyName(name.unwrap_or(&[])))?;
+                    if let Some(addend) = relocation.addend() {
+                        match addend {
+                            0.. => write!(f, " + {addend:x}")?,
+                            _ => write!(f, " - {:x}", addend.unsigned_abs())?,
+                        }
+                    }
+                } else if let Some(addend) = relocation.addend() {
+                    write!(f, "{:width$}", "", width = address_width.saturating_add(4))?;
+                    match addend {
+                        0.. => write!(f, "{addend:x}")?,
+                        _ => write!(f, "-{:x}", addend.unsigned_abs())?,
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
+        if !found {
+            writeln!(f, "There are no relocations in this file.")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the symbol tables of an [`ElfFile`] like `readelf --wide --syms`.
+pub struct ElfSymbolTablesDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose symbol tables are formatted.
+    file: ElfFile<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSymbolTablesDisplay<'slice, C, E> {
+    /// Creates a new [`ElfSymbolTablesDisplay`] that formats the symbol tables of `file`.
+    pub fn new(file: ElfFile<'slice, C, E>) -> Self {
+        Self {
+            file,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Consults `extension` for the names of processor-specific values.
+    pub fn with_machine_ext(mut self, extension: &'slice dyn MachineExt) -> Self {
+        self.extensions.machine = Some(extension);
+        self
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display
+    for ElfSymbolTablesDisplay<'slice, C, E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file;
+        let extensions = self.extensions.matching(file);
+        let names = file.section_name_string_table();
+
+        let mut found = false;
+        for section_header in file
+            .section_header_table()
+            .iter()
+            .flat_map(|table| table.iter())
+        {
+            if !matches!(
+                section_header.kind(),
+                SectionType::SYMTAB | SectionType::DYNSYM
+            ) {
+                continue;
+            }
+            let Some(symbol_table) = file.symbol_table_in(section_header) else {
+                continue;
+            };
+
+            if found {
+                writeln!(f)?;
+            }
+            found = true;
+
+            write!(f, "Symbol table ")?;
+            match names.and_then(|names| section_header.name_str(names).ok()) {
+                Some(name) => write!(f, "'{}'", LossyName(name))?,
+                None => write!(f, "'<no-strings>'")?,
+            }
+            writeln!(f, " contains {} entries:", symbol_table.len())?;
+
+            let value_width = match file.class.into_class() {
+                Class::Class32 => {
+                    writeln!(f, "   Num:    Value  Size Type    Bind   Vis      Ndx Name")?;
+                    8
+                }
+                Class::Class64 => {
+                    writeln!(
+                        f,
+                        "   Num:    Value          Size Type    Bind   Vis      Ndx Name"
+                    )?;
+                    16
+                }
+            };
+
+            for (index, symbol) in symbol_table.iter().enumerate() {
+                write!(
+                    f,
+                    "{index:>6}: {:0value_width$x} {:>5} ",
+                    symbol.value(),
+                    symbol.size()
+                )?;
+                let symbol_type = symbol.info().symbol_type();
+                match extensions.symbol_type_name(symbol_type) {
+                    Some(name) => write!(f, "{name:<7} ")?,
+                    None => write!(f, "{:<7} ", symbol_type.0)?,
+                }
+                let binding = symbol.info().binding();
+                match extensions.symbol_binding_name(binding) {
+                    Some(name) => write!(f, "{name:<6} ")?,
+                    None => write!(f, "{:<6} ", binding.0)?,
+                }
+                let visibility = SymbolVisibility::from(symbol.visibility());
+                match symbol_visibility_name(visibility) {
+                    Some(name) => write!(f, "{name:<8}")?,
+                    None => write!(f, "{:<8}", visibility.0)?,
+                }
+                match SectionIndex(symbol.section_index()) {
+                    SectionIndex::UNDEF => write!(f, " UND")?,
+                    SectionIndex::ABS => write!(f, " ABS")?,
+                    SectionIndex::COMMON => write!(f, " COM")?,
+                    index => match extensions.section_index_name(index) {
+                        Some(name) => write!(f, "{name:>4}")?,
+                        None => write!(f, "{:>4}", index.0)?,
+                    },
+                }
+                writeln!(f, " {}", LossyName(symbol.name().unwrap_or(&[])))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the notes of an [`ElfFile`] like `readelf --notes`.
+///
+/// The notes are read from the [`SectionType::NOTE`] sections if the [`ElfFile`] has any, and
+/// from its [`SegmentType::NOTE`] segments otherwise.
+pub struct ElfNotesDisplay<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose notes are formatted.
+    file: ElfFile<'slice, C, E>,
+    /// The extensions consulted while formatting.
+    extensions: Extensions<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfNotesDisplay<'slice, C, E> {
+    /// Creates a new [`ElfNotesDisplay`] that formats the notes of `file`.
+    pub fn new(file: ElfFile<'slice, C, E>) -> Self {
+        Self {
+            file,
+            extensions: Extensions::default(),
+        }
+    }
+
+    /// Consults `extension` for the names of operating system-specific values.
+    pub fn with_os_abi_ext(mut self, extension: &'slice dyn OsAbiExt) -> Self {
+        self.extensions.os_abi = Some(extension);
+        self
+    }
+
+    /// Formats the [`ElfNote`]s yielded by `notes` below the column headings, consulting
+    /// `extensions` for the names of their types.
+    fn fmt_notes(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        notes: elf_note::Iter<'slice, E>,
+        extensions: Extensions<'slice>,
+    ) -> fmt::Result {
+        writeln!(f, "  Owner                Data size \tDescription")?;
+        for note in notes {
+            write!(
+                f,
+                "  {:<20} {:#010x}\t",
+                LossyName(note.name),
+                note.descriptor.len()
+            )?;
+            match extensions.note_type_name(note.name, note.kind) {
+                Some(name) => writeln!(f, "{name}")?,
+                None => writeln!(f, "Unknown note type: ({:#010x})", note.kind)?,
+            }
+            self.fmt_descriptor(f, note)?;
+        }
+
+        Ok(())
+    }
+
+    /// Formats the decoded descriptor of `note`, if its type is one whose descriptor is decoded.
+    fn fmt_descriptor(&self, f: &mut fmt::Formatter<'_>, note: ElfNote<'slice>) -> fmt::Result {
+        if note.name == ELF_NOTE_GNU && note.kind == NT_GNU_BUILD_ID {
+            write!(f, "    Build ID: ")?;
+            for byte in note.descriptor {
+                write!(f, "{byte:02x}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Display for ElfNotesDisplay<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file;
+        let extensions = self.extensions.matching(file);
+        let names = file.section_name_string_table();
+
+        let mut found = false;
+        for section_header in file
+            .section_header_table()
+            .iter()
+            .flat_map(|table| table.iter())
+            .filter(|section_header| section_header.kind() == SectionType::NOTE)
+        {
+            let Some(data) = file.section_bytes(section_header) else {
+                continue;
+            };
+
+            if found {
+                writeln!(f)?;
+            }
+            found = true;
+
+            write!(f, "Displaying notes found in: ")?;
+            match names.and_then(|names| section_header.name_str(names).ok()) {
+                Some(name) => writeln!(f, "{}", LossyName(name))?,
+                None => writeln!(f, "<no-strings>")?,
+            }
+            let notes =
+                elf_note::Iter::with_alignment(data, section_header.address_align(), file.encoding);
+            self.fmt_notes(f, notes, extensions)?;
+        }
+        if found {
+            return Ok(());
+        }
+
+        for program_header in file.segments_of_type(SegmentType::NOTE) {
+            let Ok(data) = program_header.file_data(&file) else {
+                continue;
+            };
+
+            if found {
+                writeln!(f)?;
+            }
+            found = true;
+
+            writeln!(
+                f,
+                "Displaying notes found at file offset {:#010x} with length {:#010x}:",
+                program_header.file_offset(),
+                program_header.file_size()
+            )?;
+            let notes =
+                elf_note::Iter::with_alignment(data, program_header.alignment(), file.encoding);
+            self.fmt_notes(f, notes, extensions)?;
+        }
+
+        if !found {
+            writeln!(f, "There are no notes in this file.")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The extensions consulted by a formatter before the values known to this crate.
+#[derive(Clone, Copy, Default)]
+struct Extensions<'ext> {
+    /// The [`OsAbiExt`] interpreting operating system-specific values.
+    os_abi: Option<&'ext dyn OsAbiExt>,
+    /// The [`MachineExt`] interpreting processor-specific values.
+    machine: Option<&'ext dyn MachineExt>,
+}
+
+impl<'ext> Extensions<'ext> {
+    /// Returns these [`Extensions`] without any extension that does not interpret the values of
+    /// `file`, as determined by the [`OsAbi`] and [`Machine`] of its header.
+    fn matching<C: ClassParse, E: EncodingParse>(self, file: ElfFile<C, E>) -> Self {
+        let header = file.header();
+        Self {
+            os_abi: self
+                .os_abi
+                .filter(|extension| extension.os_abi() == header.elf_ident().os_abi()),
+            machine: self
+                .machine
+                .filter(|extension| extension.machine() == header.machine()),
+        }
+    }
+
+    /// Returns the name of `segment_type`.
+    fn segment_type_name(&self, segment_type: SegmentType) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.segment_type_name(segment_type))
+            .or_else(|| {
+                self.machine
+                    .and_then(|extension| extension.segment_type_name(segment_type))
+            })
+            .or_else(|| segment_type_name(segment_type))
+    }
+
+    /// Returns the name of `section_type`.
+    fn section_type_name(&self, section_type: SectionType) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.section_type_name(section_type))
+            .or_else(|| {
+                self.machine
+                    .and_then(|extension| extension.section_type_name(section_type))
+            })
+            .or_else(|| section_type_name(section_type))
+    }
+
+    /// Returns the name of the dynamic `tag`.
+    fn dynamic_tag_name(&self, tag: ElfDynamicTag) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.dynamic_tag_name(tag))
+            .or_else(|| dynamic_tag_name(tag))
+    }
+
+    /// Returns the name of the relocation type `relocation_type` on `machine`.
+    fn relocation_type_name(&self, machine: Machine, relocation_type: u32) -> Option<&'static str> {
+        self.machine
+            .and_then(|extension| extension.relocation_type_name(relocation_type))
+            .or_else(|| relocation_type_name(machine, relocation_type))
+    }
+
+    /// Returns the name of the operating system-specific or processor-specific reserved section
+    /// `index`.
+    fn section_index_name(&self, index: SectionIndex) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.section_index_name(index))
+            .or_else(|| {
+                self.machine
+                    .and_then(|extension| extension.section_index_name(index))
+            })
+    }
+
+    /// Returns the description of notes of type `kind` owned by the entity named `owner`.
+    fn note_type_name(&self, owner: &[u8], kind: u32) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.note_type_name(owner, kind))
+            .or_else(|| note_type_name(owner, kind))
+    }
+
+    /// Returns the name of `symbol_type`.
+    fn symbol_type_name(&self, symbol_type: SymbolType) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.symbol_type_name(symbol_type))
+            .or_else(|| symbol_type_name(symbol_type))
+    }
+
+    /// Returns the name of the symbol `binding`.
+    fn symbol_binding_name(&self, binding: SymbolBinding) -> Option<&'static str> {
+        self.os_abi
+            .and_then(|extension| extension.symbol_binding_name(binding))
+            .or_else(|| symbol_binding_name(binding))
+    }
+
+    /// Returns a formatter for `flags` that consults the [`MachineExt`].
+    fn section_flags(&self, flags: SectionFlags) -> ExtendedSectionFlagsDisplay<'ext> {
+        ExtendedSectionFlagsDisplay {
+            flags,
+            machine: self.machine,
+        }
+    }
+}
+
+/// Formats a value surrounded by parentheses, honoring the requested width.
+struct Parenthesized<T: fmt::Display>(T);
+
+impl<T: fmt::Display> fmt::Display for Parenthesized<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut counter = WidthCounter(0);
+        fmt::write(&mut counter, format_args!("({})", self.0))?;
+
+        write!(f, "({})", self.0)?;
+        for _ in counter.0..f.width().unwrap_or(0) {
+            f.write_str(" ")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a value as hexadecimal with a `0x` prefix.
+struct HexValue(u64);
+
+impl fmt::Display for HexValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+/// Formats a relocation type for which no name is known, honoring the requested width.
+struct Unrecognized(u32);
+
+impl fmt::Display for Unrecognized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut counter = WidthCounter(0);
+        fmt::write(&mut counter, format_args!("unrecognized: {:x}", self.0))?;
+
+        write!(f, "unrecognized: {:x}", self.0)?;
+        for _ in counter.0..f.width().unwrap_or(0) {
+            f.write_str(" ")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`fmt::Write`] implementation that counts the characters written to it.
+struct WidthCounter(usize);
+
+impl fmt::Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.saturating_add(s.chars().count());
+        Ok(())
+    }
+}
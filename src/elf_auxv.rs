@@ -0,0 +1,137 @@
+//! Definitions and interfaces for interacting with auxiliary vectors.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    encoding::EncodingParse,
+    raw::elf_auxv::{AuxvType, Elf32Auxv, Elf64Auxv},
+};
+
+/// An entry of an [`ElfAuxv`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfAuxvEntry<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfAuxvEntry`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the [`ElfAuxvEntry`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfAuxvEntry`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfAuxvEntry<'slice, C, E> {
+    /// Returns the [`AuxvType`] of the [`ElfAuxvEntry`].
+    pub fn kind(&self) -> AuxvType {
+        let kind = match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Auxv, kind), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Auxv, kind), self.slice),
+        };
+
+        AuxvType(kind)
+    }
+
+    /// Returns the value of the [`ElfAuxvEntry`], whose interpretation depends on
+    /// [`ElfAuxvEntry::kind`].
+    pub fn value(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Auxv, value), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Auxv, value), self.slice),
+        }
+    }
+}
+
+/// An auxiliary vector, as passed to a process by the kernel and recorded in the
+/// [`NT_AUXV`][nt] note of a core dump.
+///
+/// [nt]: crate::raw::elf_note::NT_AUXV
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfAuxv<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfAuxv`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the [`ElfAuxv`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfAuxv`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfAuxv<'slice, C, E> {
+    /// Parses an [`ElfAuxv`] from the provided `slice`.
+    ///
+    /// Any bytes following the last whole [`ElfAuxvEntry`] are ignored.
+    pub fn parse(slice: &'slice [u8], class: C, encoding: E) -> Self {
+        Self {
+            slice,
+            class,
+            encoding,
+        }
+    }
+
+    /// Returns the [`ElfAuxvEntry`] located at `index`, even if it follows an
+    /// [`AuxvType::NULL`] entry.
+    pub fn get_entry(&self, index: usize) -> Option<ElfAuxvEntry<'slice, C, E>> {
+        let offset = index.checked_mul(self.entry_size())?;
+        let end = offset.checked_add(self.entry_size())?;
+
+        Some(ElfAuxvEntry {
+            slice: self.slice.get(offset..end)?,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Returns the value of the first [`ElfAuxvEntry`] whose type is `kind`.
+    pub fn get(&self, kind: AuxvType) -> Option<u64> {
+        self.iter()
+            .find(|entry| entry.kind() == kind)
+            .map(|entry| entry.value())
+    }
+
+    /// Returns an iterator over the [`ElfAuxvEntry`]s of this [`ElfAuxv`], stopping at the first
+    /// [`AuxvType::NULL`] entry.
+    pub fn iter(&self) -> Iter<'slice, C, E> {
+        Iter {
+            auxv: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns the size of a single [`ElfAuxvEntry`].
+    fn entry_size(&self) -> usize {
+        match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Auxv>(),
+            Class::Class64 => mem::size_of::<Elf64Auxv>(),
+        }
+    }
+}
+
+/// An iterator over the [`ElfAuxvEntry`]s of an [`ElfAuxv`].
+pub struct Iter<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfAuxv`] being iterated over.
+    auxv: ElfAuxv<'slice, C, E>,
+    /// The index of the next [`ElfAuxvEntry`] to return.
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for Iter<'slice, C, E> {
+    type Item = ElfAuxvEntry<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.auxv.get_entry(self.index)?;
+        if next.kind() == AuxvType::NULL {
+            return None;
+        }
+
+        self.index = self.index.checked_add(1)?;
+        Some(next)
+    }
+}
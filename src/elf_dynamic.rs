@@ -0,0 +1,371 @@
+//! Definitions and interfaces for interacting with the ELF dynamic array.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_hash::{ElfGnuHashTable, ElfHashTable},
+    elf_string_table::{ElfStringTable, ElfStringTableError},
+    elf_symbol::ElfSymbolTable,
+    encoding::EncodingParse,
+    raw::{
+        elf_dynamic::{DynamicFlags, DynamicFlags1, Elf32Dynamic, Elf64Dynamic, ElfDynamicTag},
+        elf_header::ElfType,
+        elf_program_header::SegmentType,
+        elf_section_header::SectionType,
+        elf_symbol::{Elf32Symbol, Elf64Symbol},
+    },
+    ElfFile,
+};
+
+/// An entry of an [`ElfDynamicTable`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfDynamic<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfDynamic`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the [`ElfDynamic`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfDynamic`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfDynamic<'slice, C, E> {
+    /// Returns the raw tag of the [`ElfDynamic`], sign-extended to 64 bits.
+    pub fn raw_tag(&self) -> i64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_i32_at(mem::offset_of!(Elf32Dynamic, tag), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_i64_at(mem::offset_of!(Elf64Dynamic, tag), self.slice),
+        }
+    }
+
+    /// Returns the [`ElfDynamicTag`] of the [`ElfDynamic`], which determines how its value is
+    /// interpreted, or [`None`] if the tag does not fit in an [`ElfDynamicTag`].
+    pub fn tag(&self) -> Option<ElfDynamicTag> {
+        i32::try_from(self.raw_tag()).ok().map(ElfDynamicTag)
+    }
+
+    /// Returns the value of the [`ElfDynamic`], which is either an integer or a virtual address
+    /// depending on its [`ElfDynamicTag`].
+    pub fn value(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Dynamic, value), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Dynamic, value), self.slice),
+        }
+    }
+}
+
+/// The dynamic array of an ELF file, as stored in the [`SegmentType::DYNAMIC`] segment or the
+/// [`SectionType::DYNAMIC`] section.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfDynamicTable<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfDynamicTable`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the [`ElfDynamicTable`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfDynamicTable`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfDynamicTable<'slice, C, E> {
+    /// Parses an [`ElfDynamicTable`] from the provided `slice`.
+    ///
+    /// Any bytes following the last whole [`ElfDynamic`] are ignored.
+    pub fn parse(slice: &'slice [u8], class: C, encoding: E) -> Self {
+        Self {
+            slice,
+            class,
+            encoding,
+        }
+    }
+
+    /// Returns the [`ElfDynamic`] located at `index`, even if it follows an
+    /// [`ElfDynamicTag::NULL`] entry.
+    pub fn get_entry(&self, index: usize) -> Option<ElfDynamic<'slice, C, E>> {
+        let offset = index.checked_mul(self.entry_size())?;
+        let end = offset.checked_add(self.entry_size())?;
+
+        Some(ElfDynamic {
+            slice: self.slice.get(offset..end)?,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Returns the value of the first [`ElfDynamic`] whose tag is `tag`.
+    pub fn get(&self, tag: ElfDynamicTag) -> Option<u64> {
+        self.iter()
+            .find(|entry| entry.raw_tag() == i64::from(tag.0))
+            .map(|entry| entry.value())
+    }
+
+    /// Returns an iterator over the [`ElfDynamic`]s of this [`ElfDynamicTable`], stopping at the
+    /// first [`ElfDynamicTag::NULL`] entry.
+    pub fn iter(&self) -> Iter<'slice, C, E> {
+        Iter {
+            dynamic_table: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns the virtual address of the string table, as recorded by the
+    /// [`ElfDynamicTag::STRING_TABLE`] entry.
+    pub fn string_table_address(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::STRING_TABLE)
+    }
+
+    /// Returns the size of the string table, as recorded by the
+    /// [`ElfDynamicTag::STRING_TABLE_SIZE`] entry.
+    pub fn string_table_size(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::STRING_TABLE_SIZE)
+    }
+
+    /// Returns the virtual address of the symbol table, as recorded by the
+    /// [`ElfDynamicTag::SYMBOL_TABLE`] entry.
+    pub fn symbol_table_address(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::SYMBOL_TABLE)
+    }
+
+    /// Returns the size of an entry of the symbol table, as recorded by the
+    /// [`ElfDynamicTag::SYMBOL_ENTRY_SIZE`] entry.
+    pub fn symbol_entry_size(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::SYMBOL_ENTRY_SIZE)
+    }
+
+    /// Returns the virtual address of the symbol hash table, as recorded by the
+    /// [`ElfDynamicTag::HASH`] entry.
+    pub fn hash_address(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::HASH)
+    }
+
+    /// Returns the virtual address of the GNU symbol hash table, as recorded by the
+    /// [`ElfDynamicTag::GNU_HASH`] entry.
+    pub fn gnu_hash_address(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::GNU_HASH)
+    }
+
+    /// Returns the string table offset of the name of the shared object, as recorded by the
+    /// [`ElfDynamicTag::SO_NAME`] entry.
+    pub fn so_name_offset(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::SO_NAME)
+    }
+
+    /// Returns the address of the initialization function, as recorded by the
+    /// [`ElfDynamicTag::INIT`] entry.
+    pub fn init(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::INIT)
+    }
+
+    /// Returns the address of the termination function, as recorded by the
+    /// [`ElfDynamicTag::FINI`] entry.
+    pub fn fini(&self) -> Option<u64> {
+        self.get(ElfDynamicTag::FINI)
+    }
+
+    /// Returns the [`DynamicFlags`] recorded by the [`ElfDynamicTag::FLAGS`] entry, or no flags
+    /// if there is no such entry.
+    pub fn flags(&self) -> DynamicFlags {
+        DynamicFlags(self.get(ElfDynamicTag::FLAGS).unwrap_or(0))
+    }
+
+    /// Returns the [`DynamicFlags1`] recorded by the [`ElfDynamicTag::FLAGS_1`] entry, or no
+    /// flags if there is no such entry.
+    pub fn flags_1(&self) -> DynamicFlags1 {
+        DynamicFlags1(self.get(ElfDynamicTag::FLAGS_1).unwrap_or(0))
+    }
+
+    /// Returns the size of a single [`ElfDynamic`].
+    fn entry_size(&self) -> usize {
+        match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Dynamic>(),
+            Class::Class64 => mem::size_of::<Elf64Dynamic>(),
+        }
+    }
+}
+
+/// An iterator over the [`ElfDynamic`]s of an [`ElfDynamicTable`].
+pub struct Iter<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfDynamicTable`] being iterated over.
+    dynamic_table: ElfDynamicTable<'slice, C, E>,
+    /// The index of the next [`ElfDynamic`] to return.
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for Iter<'slice, C, E> {
+    type Item = ElfDynamic<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.dynamic_table.get_entry(self.index)?;
+        if next.raw_tag() == i64::from(ElfDynamicTag::NULL.0) {
+            return None;
+        }
+
+        self.index = self.index.checked_add(1)?;
+        Some(next)
+    }
+}
+
+/// An iterator over the names of the libraries required by an [`ElfFile`], as recorded by its
+/// [`ElfDynamicTag::NEEDED`] entries.
+pub struct NeededLibraries<'slice, C: ClassParse, E: EncodingParse> {
+    /// The iterator over the [`ElfDynamic`]s of the [`ElfDynamicTable`], or [`None`] if there is
+    /// no [`ElfDynamicTable`].
+    entries: Option<Iter<'slice, C, E>>,
+    /// The [`ElfStringTable`] holding the names of the libraries.
+    strings: ElfStringTable<'slice>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for NeededLibraries<'slice, C, E> {
+    type Item = Result<&'slice [u8], ElfStringTableError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self
+            .entries
+            .as_mut()?
+            .find(|entry| entry.tag() == Some(ElfDynamicTag::NEEDED))?;
+        Some(self.strings.get(entry.value()))
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`ElfDynamicTable`] of this [`ElfFile`], located using the
+    /// [`SegmentType::DYNAMIC`] segment or, if there is no such segment, the
+    /// [`SectionType::DYNAMIC`] section.
+    pub fn dynamic_table(&self) -> Option<ElfDynamicTable<'slice, C, E>> {
+        let slice = match self.segments_of_type(SegmentType::DYNAMIC).next() {
+            Some(program_header) => program_header.segment_data(self).ok()?.0,
+            None => {
+                let section_header = self
+                    .section_header_table()?
+                    .iter()
+                    .find(|section_header| section_header.kind() == SectionType::DYNAMIC)?;
+                self.section_bytes(section_header)?
+            }
+        };
+
+        Some(ElfDynamicTable::parse(slice, self.class, self.encoding))
+    }
+
+    /// Returns `true` if this [`ElfFile`] is a position-independent executable rather than a
+    /// shared library.
+    ///
+    /// Position-independent executables are [`ElfType::SHARED`] objects that either set
+    /// [`DynamicFlags1::PIE`] or request a program interpreter using a
+    /// [`SegmentType::INTERP`] segment.
+    pub fn is_pie(&self) -> bool {
+        if self.header().elf_type() != ElfType::SHARED {
+            return false;
+        }
+
+        self.dynamic_table()
+            .is_some_and(|dynamic_table| dynamic_table.flags_1().contains(DynamicFlags1::PIE))
+            || self.segments_of_type(SegmentType::INTERP).next().is_some()
+    }
+
+    /// Returns `true` if this [`ElfFile`] has neither a [`SegmentType::DYNAMIC`] segment nor a
+    /// [`SegmentType::INTERP`] segment, and so can be loaded without a dynamic linker.
+    pub fn is_statically_linked(&self) -> bool {
+        self.segments_of_type(SegmentType::DYNAMIC).next().is_none()
+            && self.segments_of_type(SegmentType::INTERP).next().is_none()
+    }
+
+    /// Returns `true` if this [`ElfFile`] has a [`SegmentType::DYNAMIC`] segment or a
+    /// [`SegmentType::INTERP`] segment, which is the opposite of
+    /// [`ElfFile::is_statically_linked`].
+    ///
+    /// Static position-independent executables are considered dynamically linked, as they carry
+    /// a [`SegmentType::DYNAMIC`] segment describing the relocations they apply to themselves.
+    pub fn is_dynamically_linked(&self) -> bool {
+        !self.is_statically_linked()
+    }
+
+    /// Returns an iterator over the names of the libraries this [`ElfFile`] depends on, in the
+    /// order of their [`ElfDynamicTag::NEEDED`] entries.
+    ///
+    /// The names are resolved using the [`ElfStringTable`] returned by
+    /// [`ElfFile::string_table_from_dynamic`]; if it is missing, every name fails to resolve.
+    pub fn needed_libraries(&self) -> NeededLibraries<'slice, C, E> {
+        NeededLibraries {
+            entries: self
+                .dynamic_table()
+                .map(|dynamic_table| dynamic_table.iter()),
+            strings: self
+                .string_table_from_dynamic()
+                .unwrap_or(ElfStringTable::new(&[])),
+        }
+    }
+
+    /// Returns the [`ElfStringTable`] referenced by the [`ElfDynamicTag::STRING_TABLE`] and
+    /// [`ElfDynamicTag::STRING_TABLE_SIZE`] entries of the [`ElfDynamicTable`], read from the
+    /// file image of the [`SegmentType::LOAD`] segment containing it.
+    pub fn string_table_from_dynamic(&self) -> Option<ElfStringTable<'slice>> {
+        let dynamic_table = self.dynamic_table()?;
+        let slice = self.vaddr_bytes(
+            dynamic_table.string_table_address()?,
+            dynamic_table.string_table_size()?,
+        )?;
+
+        Some(ElfStringTable::new(slice))
+    }
+
+    /// Returns the [`ElfHashTable`] referenced by the [`ElfDynamicTag::HASH`] entry of the
+    /// [`ElfDynamicTable`], read from the file image of the [`SegmentType::LOAD`] segment
+    /// containing it.
+    pub fn hash_table_from_dynamic(&self) -> Option<ElfHashTable<'slice, E>> {
+        let slice = self.vaddr_bytes_to_end(self.dynamic_table()?.hash_address()?)?;
+        ElfHashTable::parse(slice, self.encoding).ok()
+    }
+
+    /// Returns the [`ElfGnuHashTable`] referenced by the [`ElfDynamicTag::GNU_HASH`] entry of the
+    /// [`ElfDynamicTable`], read from the file image of the [`SegmentType::LOAD`] segment
+    /// containing it.
+    pub fn gnu_hash_table_from_dynamic(&self) -> Option<ElfGnuHashTable<'slice, C, E>> {
+        let slice = self.vaddr_bytes_to_end(self.dynamic_table()?.gnu_hash_address()?)?;
+        ElfGnuHashTable::parse(slice, self.class, self.encoding).ok()
+    }
+
+    /// Returns the [`ElfSymbolTable`] referenced by the [`ElfDynamicTag::SYMBOL_TABLE`] entry of
+    /// the [`ElfDynamicTable`], read from the file image of the [`SegmentType::LOAD`] segment
+    /// containing it.
+    ///
+    /// As the dynamic array does not record the size of the symbol table, the number of symbols
+    /// is taken from the chain count of the [`ElfHashTable`] returned by
+    /// [`ElfFile::hash_table_from_dynamic`], or, failing that, by walking the chains of the
+    /// [`ElfGnuHashTable`] returned by [`ElfFile::gnu_hash_table_from_dynamic`].
+    pub fn symbol_table_from_dynamic(&self) -> Option<ElfSymbolTable<'slice, C, E>> {
+        let dynamic_table = self.dynamic_table()?;
+        let entry_size = match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Symbol>() as u64,
+            Class::Class64 => mem::size_of::<Elf64Symbol>() as u64,
+        };
+        if dynamic_table
+            .symbol_entry_size()
+            .is_some_and(|size| size != entry_size)
+        {
+            return None;
+        }
+
+        let symbol_count = match self.hash_table_from_dynamic() {
+            Some(hash_table) => hash_table.chain_count(),
+            None => self.gnu_hash_table_from_dynamic()?.symbol_count()?,
+        };
+        let size = u64::from(symbol_count).checked_mul(entry_size)?;
+        let slice = self.vaddr_bytes(dynamic_table.symbol_table_address()?, size)?;
+        ElfSymbolTable::parse(
+            slice,
+            self.string_table_from_dynamic()?,
+            self.class,
+            self.encoding,
+        )
+        .ok()
+    }
+}
@@ -0,0 +1,434 @@
+//! Definitions and interfaces for interacting with ELF symbol hash tables.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_symbol::{ElfSymbol, ElfSymbolTable},
+    encoding::EncodingParse,
+    raw::{
+        elf_section_header::{SectionIndex, SectionType},
+        elf_symbol::{Elf32Symbol, Elf64Symbol},
+    },
+    ElfFile,
+};
+
+/// Computes the hash of `name` used by [`ElfHashTable`]s.
+pub fn sysv_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |hash, &byte| {
+        let hash = hash.wrapping_shl(4).wrapping_add(byte.into());
+        let high = hash & 0xF000_0000;
+        (hash ^ high.wrapping_shr(24)) & !high
+    })
+}
+
+/// Computes the hash of `name` used by [`ElfGnuHashTable`]s.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |hash, &byte| {
+        hash.wrapping_mul(33).wrapping_add(byte.into())
+    })
+}
+
+/// A System V symbol hash table, as referenced by [`SectionType::HASH`] sections.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfHashTable<'slice, E: EncodingParse> {
+    /// The bytes that make up the [`ElfHashTable`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`EncodingParse`] used to parse the [`ElfHashTable`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, E: EncodingParse> ElfHashTable<'slice, E> {
+    /// Parses an [`ElfHashTable`] from the provided `slice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfHashTableError::SliceTooSmall`] if `slice` is too small to contain the
+    /// header or the buckets and chains the header describes.
+    pub fn parse(slice: &'slice [u8], encoding: E) -> Result<Self, ParseElfHashTableError> {
+        if slice.len() < mem::size_of::<[u32; 2]>() {
+            return Err(ParseElfHashTableError::SliceTooSmall);
+        }
+
+        let hash_table = Self { slice, encoding };
+        let total_size = u64::from(hash_table.bucket_count())
+            .checked_add(u64::from(hash_table.chain_count()))
+            .and_then(|count| count.checked_add(2))
+            .and_then(|count| count.checked_mul(mem::size_of::<u32>() as u64))
+            .ok_or(ParseElfHashTableError::SliceTooSmall)?;
+        if (slice.len() as u64) < total_size {
+            return Err(ParseElfHashTableError::SliceTooSmall);
+        }
+
+        Ok(hash_table)
+    }
+
+    /// Returns the number of buckets in the [`ElfHashTable`].
+    pub fn bucket_count(&self) -> u32 {
+        self.encoding.parse_u32_at(0, self.slice)
+    }
+
+    /// Returns the number of chain entries in the [`ElfHashTable`], which is equal to the number of
+    /// entries in the associated symbol table.
+    pub fn chain_count(&self) -> u32 {
+        self.encoding
+            .parse_u32_at(mem::size_of::<u32>(), self.slice)
+    }
+
+    /// Returns the symbol table index stored in the bucket at `index`.
+    pub fn bucket(&self, index: u32) -> Option<u32> {
+        if index >= self.bucket_count() {
+            return None;
+        }
+
+        self.word(2u64.checked_add(index.into())?)
+    }
+
+    /// Returns the symbol table index stored in the chain entry at `index`.
+    pub fn chain(&self, index: u32) -> Option<u32> {
+        if index >= self.chain_count() {
+            return None;
+        }
+
+        let word_index = 2u64
+            .checked_add(self.bucket_count().into())?
+            .checked_add(index.into())?;
+        self.word(word_index)
+    }
+
+    /// Returns the defined [`ElfSymbol`] of `symbol_table` named `name`, found by walking the
+    /// chain of the bucket that `name` hashes to.
+    ///
+    /// `symbol_table` must be the symbol table that this [`ElfHashTable`] indexes.
+    pub fn lookup<C: ClassParse>(
+        &self,
+        name: &[u8],
+        symbol_table: ElfSymbolTable<'slice, C, E>,
+    ) -> Option<ElfSymbol<'slice, C, E>> {
+        let bucket_count = self.bucket_count();
+        if bucket_count == 0 {
+            return None;
+        }
+
+        let mut symbol_index = self.bucket(sysv_hash(name).checked_rem(bucket_count)?)?;
+        // Every chain entry can be visited at most once unless the chains form a cycle.
+        for _ in 0..self.chain_count() {
+            if symbol_index == 0 {
+                return None;
+            }
+
+            let symbol = symbol_table.get(symbol_index.try_into().ok()?)?;
+            if symbol.section_index() != SectionIndex::UNDEF.0
+                && symbol.name().is_ok_and(|symbol_name| symbol_name == name)
+            {
+                return Some(symbol);
+            }
+
+            symbol_index = self.chain(symbol_index)?;
+        }
+
+        None
+    }
+
+    /// Returns the [`u32`] at `index` words from the start of the [`ElfHashTable`].
+    fn word(&self, index: u64) -> Option<u32> {
+        let offset: usize = index
+            .checked_mul(mem::size_of::<u32>() as u64)?
+            .try_into()
+            .ok()?;
+        let end = offset.checked_add(mem::size_of::<u32>())?;
+        self.slice.get(offset..end)?;
+
+        Some(self.encoding.parse_u32_at(offset, self.slice))
+    }
+}
+
+/// Various errors that can occur while parsing an [`ElfHashTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfHashTableError {
+    /// The given slice was too small to contain the [`ElfHashTable`].
+    SliceTooSmall,
+}
+
+/// A GNU-style symbol hash table, as referenced by [`SectionType::GNU_HASH`] sections.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfGnuHashTable<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfGnuHashTable`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the [`ElfGnuHashTable`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfGnuHashTable`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfGnuHashTable<'slice, C, E> {
+    /// The size of the header of an [`ElfGnuHashTable`].
+    const HEADER_SIZE: usize = mem::size_of::<[u32; 4]>();
+
+    /// Parses an [`ElfGnuHashTable`] from the provided `slice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfGnuHashTableError::SliceTooSmall`] if `slice` is too small to contain the
+    /// header, bloom filter and buckets the header describes.
+    pub fn parse(
+        slice: &'slice [u8],
+        class: C,
+        encoding: E,
+    ) -> Result<Self, ParseElfGnuHashTableError> {
+        if slice.len() < Self::HEADER_SIZE {
+            return Err(ParseElfGnuHashTableError::SliceTooSmall);
+        }
+
+        let hash_table = Self {
+            slice,
+            class,
+            encoding,
+        };
+        if (slice.len() as u64) < hash_table.chains_offset() {
+            return Err(ParseElfGnuHashTableError::SliceTooSmall);
+        }
+
+        Ok(hash_table)
+    }
+
+    /// Returns the number of buckets in the [`ElfGnuHashTable`].
+    pub fn bucket_count(&self) -> u32 {
+        self.encoding.parse_u32_at(0, self.slice)
+    }
+
+    /// Returns the index of the first symbol of the associated symbol table that is accessible
+    /// through the [`ElfGnuHashTable`].
+    pub fn symbol_offset(&self) -> u32 {
+        self.encoding
+            .parse_u32_at(mem::size_of::<u32>(), self.slice)
+    }
+
+    /// Returns the number of words in the bloom filter.
+    pub fn bloom_size(&self) -> u32 {
+        self.encoding
+            .parse_u32_at(mem::size_of::<[u32; 2]>(), self.slice)
+    }
+
+    /// Returns the shift applied to the hash to obtain the second bloom filter bit.
+    pub fn bloom_shift(&self) -> u32 {
+        self.encoding
+            .parse_u32_at(mem::size_of::<[u32; 3]>(), self.slice)
+    }
+
+    /// Returns the bloom filter word at `index`.
+    ///
+    /// Bloom filter words are as wide as the class of the ELF file.
+    pub fn bloom_word(&self, index: u32) -> Option<u64> {
+        if index >= self.bloom_size() {
+            return None;
+        }
+
+        let offset = usize::try_from(index)
+            .ok()?
+            .checked_mul(self.bloom_word_size())?
+            .checked_add(Self::HEADER_SIZE)?;
+        let word = match self.class.into_class() {
+            Class::Class32 => self.encoding.parse_u32_at(offset, self.slice).into(),
+            Class::Class64 => self.encoding.parse_u64_at(offset, self.slice),
+        };
+
+        Some(word)
+    }
+
+    /// Returns the symbol table index stored in the bucket at `index`.
+    pub fn bucket(&self, index: u32) -> Option<u32> {
+        if index >= self.bucket_count() {
+            return None;
+        }
+
+        let offset = u64::from(index)
+            .checked_mul(mem::size_of::<u32>() as u64)?
+            .checked_add(self.buckets_offset())?
+            .try_into()
+            .ok()?;
+        Some(self.encoding.parse_u32_at(offset, self.slice))
+    }
+
+    /// Returns the chain value associated with the symbol at `symbol_index` of the associated
+    /// symbol table.
+    ///
+    /// The low bit of the value marks the end of a chain, and the remaining bits hold the hash of
+    /// the symbol's name.
+    pub fn chain(&self, symbol_index: u32) -> Option<u32> {
+        let chain_index = symbol_index.checked_sub(self.symbol_offset())?;
+        let offset: usize = u64::from(chain_index)
+            .checked_mul(mem::size_of::<u32>() as u64)?
+            .checked_add(self.chains_offset())?
+            .try_into()
+            .ok()?;
+        let end = offset.checked_add(mem::size_of::<u32>())?;
+        self.slice.get(offset..end)?;
+
+        Some(self.encoding.parse_u32_at(offset, self.slice))
+    }
+
+    /// Returns the number of entries in the associated symbol table, computed by walking the
+    /// chain that starts at the highest bucket value to its end.
+    ///
+    /// Returns [`None`] if the chain runs past the end of the [`ElfGnuHashTable`].
+    pub fn symbol_count(&self) -> Option<u32> {
+        let max_bucket = (0..self.bucket_count())
+            .filter_map(|index| self.bucket(index))
+            .max()
+            .unwrap_or(0);
+        if max_bucket < self.symbol_offset() {
+            return Some(self.symbol_offset());
+        }
+
+        let mut symbol_index = max_bucket;
+        loop {
+            if self.chain(symbol_index)? & 1 == 1 {
+                return symbol_index.checked_add(1);
+            }
+
+            symbol_index = symbol_index.checked_add(1)?;
+        }
+    }
+
+    /// Returns the defined [`ElfSymbol`] of `symbol_table` named `name`, found by checking the
+    /// bloom filter and then walking the chain of the bucket that `name` hashes to.
+    ///
+    /// `symbol_table` must be the symbol table that this [`ElfGnuHashTable`] indexes.
+    pub fn lookup(
+        &self,
+        name: &[u8],
+        symbol_table: ElfSymbolTable<'slice, C, E>,
+    ) -> Option<ElfSymbol<'slice, C, E>> {
+        let hash = gnu_hash(name);
+
+        let word_bits = u32::try_from(self.bloom_word_size()).ok()?.checked_mul(8)?;
+        let bloom_word = self.bloom_word(
+            hash.checked_div(word_bits)?
+                .checked_rem(self.bloom_size())?,
+        )?;
+        let first_bit = hash.checked_rem(word_bits)?;
+        let second_bit = hash
+            .checked_shr(self.bloom_shift())?
+            .checked_rem(word_bits)?;
+        let mask = 1u64.checked_shl(first_bit)? | 1u64.checked_shl(second_bit)?;
+        if bloom_word & mask != mask {
+            return None;
+        }
+
+        let mut symbol_index = self.bucket(hash.checked_rem(self.bucket_count())?)?;
+        if symbol_index < self.symbol_offset() {
+            return None;
+        }
+
+        loop {
+            let chain = self.chain(symbol_index)?;
+            if chain | 1 == hash | 1 {
+                let symbol = symbol_table.get(symbol_index.try_into().ok()?)?;
+                if symbol.section_index() != SectionIndex::UNDEF.0
+                    && symbol.name().is_ok_and(|symbol_name| symbol_name == name)
+                {
+                    return Some(symbol);
+                }
+            }
+
+            if chain & 1 == 1 {
+                return None;
+            }
+            symbol_index = symbol_index.checked_add(1)?;
+        }
+    }
+
+    /// Returns the size of a single bloom filter word.
+    fn bloom_word_size(&self) -> usize {
+        match self.class.into_class() {
+            Class::Class32 => mem::size_of::<u32>(),
+            Class::Class64 => mem::size_of::<u64>(),
+        }
+    }
+
+    /// Returns the offset of the buckets from the start of the [`ElfGnuHashTable`].
+    fn buckets_offset(&self) -> u64 {
+        u64::from(self.bloom_size())
+            .saturating_mul(self.bloom_word_size() as u64)
+            .saturating_add(Self::HEADER_SIZE as u64)
+    }
+
+    /// Returns the offset of the chains from the start of the [`ElfGnuHashTable`].
+    fn chains_offset(&self) -> u64 {
+        u64::from(self.bucket_count())
+            .saturating_mul(mem::size_of::<u32>() as u64)
+            .saturating_add(self.buckets_offset())
+    }
+}
+
+/// Various errors that can occur while parsing an [`ElfGnuHashTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfGnuHashTableError {
+    /// The given slice was too small to contain the [`ElfGnuHashTable`].
+    SliceTooSmall,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the defined dynamic [`ElfSymbol`] named `name`, looked up through the
+    /// [`ElfHashTable`] returned by [`ElfFile::hash_table_from_dynamic`].
+    pub fn lookup_symbol(&self, name: &[u8]) -> Option<ElfSymbol<'slice, C, E>> {
+        self.hash_table_from_dynamic()?
+            .lookup(name, self.symbol_table_from_dynamic()?)
+    }
+
+    /// Returns the defined dynamic [`ElfSymbol`] named `name`, looked up through the
+    /// [`ElfGnuHashTable`] returned by [`ElfFile::gnu_hash_table_from_dynamic`].
+    pub fn lookup_symbol_gnu(&self, name: &[u8]) -> Option<ElfSymbol<'slice, C, E>> {
+        self.gnu_hash_table_from_dynamic()?
+            .lookup(name, self.symbol_table_from_dynamic()?)
+    }
+
+    /// Returns the number of entries in the dynamic symbol table.
+    ///
+    /// The count is derived from the chain count of the [`ElfHashTable`] referenced by the dynamic
+    /// array if present, otherwise by walking the chains of the [`ElfGnuHashTable`] referenced by
+    /// the dynamic array, so that section-stripped objects are supported. Only if neither is
+    /// present are the hash sections and finally the size of the [`SectionType::DYNSYM`] section
+    /// consulted.
+    pub fn dynamic_symbol_count(&self) -> Option<u64> {
+        if let Some(hash_table) = self.hash_table_from_dynamic() {
+            return Some(hash_table.chain_count().into());
+        }
+        if let Some(hash_table) = self.gnu_hash_table_from_dynamic() {
+            return hash_table.symbol_count().map(u64::from);
+        }
+
+        let section_header_table = self.section_header_table()?;
+        let section_data = |kind: SectionType| {
+            let section_header = section_header_table
+                .iter()
+                .find(|section_header| section_header.kind() == kind)?;
+            self.section_bytes(section_header)
+        };
+
+        if let Some(data) = section_data(SectionType::HASH) {
+            if let Ok(hash_table) = ElfHashTable::parse(data, self.encoding) {
+                return Some(hash_table.chain_count().into());
+            }
+        }
+
+        if let Some(data) = section_data(SectionType::GNU_HASH) {
+            let count = ElfGnuHashTable::parse(data, self.class, self.encoding)
+                .ok()
+                .and_then(|hash_table| hash_table.symbol_count());
+            if let Some(count) = count {
+                return Some(count.into());
+            }
+        }
+
+        let dynamic_symbol_table = section_header_table
+            .iter()
+            .find(|section_header| section_header.kind() == SectionType::DYNSYM)?;
+        let entry_size = match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Symbol>() as u64,
+            Class::Class64 => mem::size_of::<Elf64Symbol>() as u64,
+        };
+        dynamic_symbol_table.size().checked_div(entry_size)
+    }
+}
@@ -5,11 +5,12 @@ use core::{fmt, mem};
 use crate::{
     class::{Class, ClassParse},
     elf_ident::{ElfIdent, ParseElfIdentError},
+    elf_section_header::ElfSectionHeader,
     encoding::EncodingParse,
     raw::{
         elf_header::{Elf32Header, Elf64Header, ElfType, Machine, CURRENT_OBJECT_FILE_VERSION},
-        elf_program_header::Elf64ProgramHeader,
-        elf_section_header::Elf64SectionHeader,
+        elf_program_header::{Elf32ProgramHeader, Elf64ProgramHeader},
+        elf_section_header::{Elf32SectionHeader, Elf64SectionHeader, SectionIndex},
     },
 };
 
@@ -25,44 +26,56 @@ pub struct ElfHeader<'slice, C: ClassParse, E: EncodingParse> {
 impl<'slice, C: ClassParse, E: EncodingParse> ElfHeader<'slice, C, E> {
     /// Parses an [`ElfHeader`] from the provided `file`, checking as many invariants
     /// as possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfHeaderError`] if the [`ElfIdent`] is invalid, if `file` is too small to
+    /// contain the [`ElfHeader`], or if any of the sizes it records are too small for its class.
     pub fn parse(file: &'slice [u8]) -> Result<Self, ParseElfHeaderError> {
         let elf_ident = ElfIdent::<C, E>::parse(file)?;
 
-        match elf_ident.class_parse().into_class() {
-            Class::Class32 => todo!(),
-            Class::Class64 => {
-                if file.len() < mem::size_of::<Elf64Header>() {
-                    return Err(ParseElfHeaderError::FileTooSmall);
-                }
-
-                let elf_header = Self {
-                    slice: file,
-                    class: elf_ident.class_parse(),
-                    encoding: elf_ident.encoding_parse(),
-                };
-
-                if elf_header.object_file_version() != CURRENT_OBJECT_FILE_VERSION {
-                    return Err(ParseElfHeaderError::UnsupportedElfFileVersion);
-                }
-
-                if (elf_header.elf_header_size() as usize) < mem::size_of::<Elf64Header>() {
-                    return Err(ParseElfHeaderError::InvalidElfHeaderSize);
-                }
-
-                if elf_header.program_header_count() != 0
-                    && (elf_header.program_header_entry_size() as usize)
-                        < mem::size_of::<Elf64ProgramHeader>()
-                {
-                    return Err(ParseElfHeaderError::InvalidProgramHeaderSize);
-                }
-
-                if elf_header.section_header_count() != 0
-                    && (elf_header.section_header_entry_size() as usize)
-                        < mem::size_of::<Elf64SectionHeader>()
-                {
-                    return Err(ParseElfHeaderError::InvalidSectionHeaderSize);
-                }
-            }
+        let (header_size, program_header_size, section_header_size) =
+            match elf_ident.class_parse().into_class() {
+                Class::Class32 => (
+                    mem::size_of::<Elf32Header>(),
+                    mem::size_of::<Elf32ProgramHeader>(),
+                    mem::size_of::<Elf32SectionHeader>(),
+                ),
+                Class::Class64 => (
+                    mem::size_of::<Elf64Header>(),
+                    mem::size_of::<Elf64ProgramHeader>(),
+                    mem::size_of::<Elf64SectionHeader>(),
+                ),
+            };
+
+        if file.len() < header_size {
+            return Err(ParseElfHeaderError::FileTooSmall);
+        }
+
+        let elf_header = Self {
+            slice: file,
+            class: elf_ident.class_parse(),
+            encoding: elf_ident.encoding_parse(),
+        };
+
+        if elf_header.object_file_version() != CURRENT_OBJECT_FILE_VERSION {
+            return Err(ParseElfHeaderError::UnsupportedElfFileVersion);
+        }
+
+        if usize::from(elf_header.elf_header_size()) < header_size {
+            return Err(ParseElfHeaderError::InvalidElfHeaderSize);
+        }
+
+        if elf_header.program_header_count() != 0
+            && usize::from(elf_header.program_header_entry_size()) < program_header_size
+        {
+            return Err(ParseElfHeaderError::InvalidProgramHeaderSize);
+        }
+
+        if (elf_header.section_header_count() != 0 || elf_header.section_header_offset() != 0)
+            && usize::from(elf_header.section_header_entry_size()) < section_header_size
+        {
+            return Err(ParseElfHeaderError::InvalidSectionHeaderSize);
         }
 
         Ok(Self {
@@ -259,6 +272,42 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfHeader<'slice, C, E> {
             ),
         }
     }
+
+    /// Returns the number of section headers this ELF file contains, resolving the extended
+    /// section numbering used by files with too many sections to fit into
+    /// [`ElfHeader::section_header_count`].
+    ///
+    /// Returns [`None`] if the count is stored in the first section header, but that header is not
+    /// located inside of the file.
+    pub fn real_section_header_count(&self) -> Option<u64> {
+        let count = self.section_header_count();
+        if count != 0 || self.section_header_offset() == 0 {
+            return Some(count.into());
+        }
+
+        Some(self.initial_section_header()?.size())
+    }
+
+    /// Returns the section header index of the string table for section names, resolving
+    /// [`SectionIndex::XINDEX`] to the index stored in the first section header.
+    ///
+    /// Returns [`None`] if the index is stored in the first section header, but that header is not
+    /// located inside of the file.
+    pub fn real_section_header_string_table_index(&self) -> Option<u32> {
+        let index = self.section_header_string_table_index();
+        if index != SectionIndex::XINDEX.0 {
+            return Some(index.into());
+        }
+
+        Some(self.initial_section_header()?.link())
+    }
+
+    /// Returns the first [`ElfSectionHeader`] of the file, whose `size` and `link` fields hold the
+    /// escaped values of extended section numbering.
+    fn initial_section_header(&self) -> Option<ElfSectionHeader<'slice, C, E>> {
+        let offset: usize = self.section_header_offset().try_into().ok()?;
+        ElfSectionHeader::parse(self.slice.get(offset..)?, self.class, self.encoding).ok()
+    }
 }
 
 impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfHeader<'slice, C, E> {
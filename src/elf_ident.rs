@@ -52,6 +52,13 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfIdent<'slice, C, E> {
         Ok(elf_ident)
     }
 
+    /// Returns the raw bytes that make up this [`ElfIdent`].
+    pub fn bytes(&self) -> [u8; mem::size_of::<RawElfIdent>()] {
+        let mut bytes = [0; mem::size_of::<RawElfIdent>()];
+        bytes.copy_from_slice(&self.slice[..mem::size_of::<RawElfIdent>()]);
+        bytes
+    }
+
     /// Returns the magic bytes that identify this file as an ELF file.
     pub fn magic(&self) -> [u8; 4] {
         let mut bytes = [0; 4];
@@ -0,0 +1,231 @@
+//! Definitions and interfaces for interacting with ELF notes.
+
+use core::mem;
+
+use crate::{
+    class::ClassParse,
+    elf_program_header::OfType,
+    encoding::EncodingParse,
+    raw::{
+        elf_note::{Elf32Nhdr, GnuAbiTagOs, ELF_NOTE_GNU, NT_GNU_ABI_TAG, NT_GNU_BUILD_ID},
+        elf_program_header::SegmentType,
+        elf_section_header::SectionType,
+    },
+    ElfFile,
+};
+
+/// A single note of a [`SegmentType::NOTE`][st] segment or [`SectionType::NOTE`][sht] section.
+///
+/// [st]: crate::raw::elf_program_header::SegmentType::NOTE
+/// [sht]: crate::raw::elf_section_header::SectionType::NOTE
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ElfNote<'slice> {
+    /// The name of the entity that owns the note, without its NUL terminator.
+    pub name: &'slice [u8],
+    /// The type of the note, whose interpretation depends on [`ElfNote::name`].
+    pub kind: u32,
+    /// The descriptor of the note.
+    pub descriptor: &'slice [u8],
+}
+
+/// The decoded descriptor of an [`NT_GNU_ABI_TAG`] note, which identifies the operating system
+/// the object file targets and the minimum version of its kernel.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct GnuAbiTag {
+    /// The operating system the object file targets.
+    pub os: GnuAbiTagOs,
+    /// The major, minor and subminor version of the oldest supported kernel.
+    pub kernel_version: [u32; 3],
+}
+
+impl GnuAbiTag {
+    /// Parses a [`GnuAbiTag`] from the `descriptor` of an [`NT_GNU_ABI_TAG`] note.
+    ///
+    /// Returns [`None`] if `descriptor` is too small to contain a [`GnuAbiTag`].
+    pub fn parse<E: EncodingParse>(descriptor: &[u8], encoding: E) -> Option<Self> {
+        let words = descriptor.get(..mem::size_of::<[u32; 4]>())?;
+        let word = |index: usize| {
+            encoding.parse_u32_at(index.saturating_mul(mem::size_of::<u32>()), words)
+        };
+
+        Some(Self {
+            os: GnuAbiTagOs(word(0)),
+            kernel_version: [word(1), word(2), word(3)],
+        })
+    }
+}
+
+/// An iterator over the [`ElfNote`]s stored in a slice.
+///
+/// Iteration stops at the first note that is malformed or does not fit inside of the slice.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Iter<'slice, E: EncodingParse> {
+    /// The bytes of the notes that have not been iterated over yet.
+    slice: &'slice [u8],
+    /// The alignment of the name and descriptor of each note.
+    alignment: usize,
+    /// The [`EncodingParse`] used to parse the notes.
+    encoding: E,
+}
+
+impl<'slice, E: EncodingParse> Iter<'slice, E> {
+    /// Creates a new [`Iter`] over the notes stored in `slice`, whose names and descriptors are
+    /// aligned to 4 bytes.
+    pub fn new(slice: &'slice [u8], encoding: E) -> Self {
+        Self {
+            slice,
+            alignment: mem::size_of::<u32>(),
+            encoding,
+        }
+    }
+
+    /// Creates a new [`Iter`] over the notes stored in a segment or section whose alignment is
+    /// `alignment`.
+    ///
+    /// Names and descriptors are aligned to 8 bytes if `alignment` is 8, as is done by some
+    /// producers of ELF64 files, and to 4 bytes otherwise.
+    pub fn with_alignment(slice: &'slice [u8], alignment: u64, encoding: E) -> Self {
+        let alignment = if alignment == 8 {
+            mem::size_of::<u64>()
+        } else {
+            mem::size_of::<u32>()
+        };
+
+        Self {
+            slice,
+            alignment,
+            encoding,
+        }
+    }
+}
+
+impl<'slice, E: EncodingParse> Iterator for Iter<'slice, E> {
+    type Item = ElfNote<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header_size = mem::size_of::<Elf32Nhdr>();
+        if self.slice.len() < header_size {
+            self.slice = &[];
+            return None;
+        }
+
+        let name_size: usize = self
+            .encoding
+            .parse_u32_at(mem::offset_of!(Elf32Nhdr, name_size), self.slice)
+            .try_into()
+            .ok()?;
+        let descriptor_size: usize = self
+            .encoding
+            .parse_u32_at(mem::offset_of!(Elf32Nhdr, descriptor_size), self.slice)
+            .try_into()
+            .ok()?;
+        let kind = self
+            .encoding
+            .parse_u32_at(mem::offset_of!(Elf32Nhdr, kind), self.slice);
+
+        let note = (|| {
+            let descriptor_offset = align_up(header_size.checked_add(name_size)?, self.alignment)?;
+            let descriptor_end = descriptor_offset.checked_add(descriptor_size)?;
+
+            let name = self
+                .slice
+                .get(header_size..header_size.checked_add(name_size)?)?;
+            let name = name.strip_suffix(&[0]).unwrap_or(name);
+            let descriptor = self.slice.get(descriptor_offset..descriptor_end)?;
+            let next = align_up(descriptor_end, self.alignment)?.min(self.slice.len());
+
+            Some((
+                ElfNote {
+                    name,
+                    kind,
+                    descriptor,
+                },
+                next,
+            ))
+        })();
+
+        let Some((note, next)) = note else {
+            self.slice = &[];
+            return None;
+        };
+
+        self.slice = self.slice.get(next..).unwrap_or(&[]);
+        Some(note)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the first [`ElfNote`] named `name` whose type is `kind`, searching the
+    /// [`SegmentType::NOTE`] segments and falling back to the [`SectionType::NOTE`] sections.
+    pub fn find_note(&self, name: &[u8], kind: u32) -> Option<ElfNote<'slice>> {
+        let matches = |note: &ElfNote<'slice>| note.name == name && note.kind == kind;
+
+        self.segment_notes().find(matches).or_else(|| {
+            self.section_header_table()?
+                .iter()
+                .filter(|section_header| section_header.kind() == SectionType::NOTE)
+                .find_map(|section_header| {
+                    let data = self.section_bytes(section_header)?;
+                    Iter::with_alignment(data, section_header.address_align(), self.encoding)
+                        .find(matches)
+                })
+        })
+    }
+
+    /// Returns an iterator over the [`ElfNote`]s of every [`SegmentType::NOTE`] segment of this
+    /// [`ElfFile`].
+    pub fn segment_notes(&self) -> SegmentNotes<'slice, C, E> {
+        SegmentNotes {
+            file: *self,
+            segments: self.segments_of_type(SegmentType::NOTE),
+            notes: Iter::new(&[], self.encoding),
+        }
+    }
+
+    /// Returns the descriptor of the [`NT_GNU_BUILD_ID`] note, which uniquely identifies the
+    /// build of this [`ElfFile`].
+    pub fn build_id(&self) -> Option<&'slice [u8]> {
+        self.find_note(ELF_NOTE_GNU, NT_GNU_BUILD_ID)
+            .map(|note| note.descriptor)
+    }
+
+    /// Returns the [`GnuAbiTag`] decoded from the [`NT_GNU_ABI_TAG`] note.
+    pub fn gnu_abi_tag(&self) -> Option<GnuAbiTag> {
+        let note = self.find_note(ELF_NOTE_GNU, NT_GNU_ABI_TAG)?;
+        GnuAbiTag::parse(note.descriptor, self.encoding)
+    }
+}
+
+/// An iterator over the [`ElfNote`]s of the [`SegmentType::NOTE`] segments of an [`ElfFile`].
+pub struct SegmentNotes<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose notes are being iterated over.
+    file: ElfFile<'slice, C, E>,
+    /// The [`SegmentType::NOTE`] segments that have not been iterated over yet.
+    segments: OfType<'slice, C, E>,
+    /// The notes of the current segment that have not been iterated over yet.
+    notes: Iter<'slice, E>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for SegmentNotes<'slice, C, E> {
+    type Item = ElfNote<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(note) = self.notes.next() {
+                return Some(note);
+            }
+
+            let program_header = self.segments.next()?;
+            if let Ok(data) = program_header.file_data(&self.file) {
+                self.notes =
+                    Iter::with_alignment(data, program_header.alignment(), self.file.encoding);
+            }
+        }
+    }
+}
+
+/// Rounds `value` up to the next multiple of `alignment`, which must be a power of two.
+fn align_up(value: usize, alignment: usize) -> Option<usize> {
+    let mask = alignment.checked_sub(1)?;
+    Some(value.checked_add(mask)? & !mask)
+}
@@ -1,12 +1,12 @@
 //! Definitions and interfaces for interacting with an ELF program header.
 
-use core::{fmt, mem};
+use core::{ffi::CStr, fmt, mem, ops::Range};
 
 use crate::{
     class::{Class, ClassParse},
     encoding::EncodingParse,
-    raw::elf_program_header::{Elf64ProgramHeader, SegmentFlags, SegmentType},
-    ElfFile,
+    raw::elf_program_header::{Elf32ProgramHeader, Elf64ProgramHeader, SegmentFlags, SegmentType},
+    ElfFile, TableIndexError,
 };
 
 /// Structure that describes how to locate and load data and configuration relevant to program
@@ -20,60 +20,147 @@ pub struct ElfProgramHeader<'slice, C: ClassParse, E: EncodingParse> {
 
 impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// Parses an [`ElfProgramHeader`] from the provided `slice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfProgramHeaderError`] if `slice` is too small to contain an
+    /// [`ElfProgramHeader`] or if the [`ElfProgramHeader`] is invalid.
     pub fn parse(
         slice: &'slice [u8],
         class: C,
         encoding: E,
     ) -> Result<Self, ParseElfProgramHeaderError> {
-        match class.into_class() {
-            Class::Class32 => todo!(),
-            Class::Class64 => {
-                if slice.len() < mem::size_of::<Elf64ProgramHeader>() {
-                    return Err(ParseElfProgramHeaderError::SliceTooSmall);
-                }
+        let size = match class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32ProgramHeader>(),
+            Class::Class64 => mem::size_of::<Elf64ProgramHeader>(),
+        };
+        if slice.len() < size {
+            return Err(ParseElfProgramHeaderError::SliceTooSmall);
+        }
 
-                let elf_program_header = Self {
-                    slice,
-                    class,
-                    encoding,
-                };
+        let elf_program_header = Self {
+            slice,
+            class,
+            encoding,
+        };
 
-                if elf_program_header.file_size() > elf_program_header.memory_size() {
-                    return Err(ParseElfProgramHeaderError::InvalidSizing);
-                }
+        elf_program_header.validate()?;
+        Ok(elf_program_header)
+    }
 
-                if !elf_program_header.alignment().is_power_of_two()
-                    && elf_program_header.alignment() != 0
-                {
-                    return Err(ParseElfProgramHeaderError::InvalidAlignment);
-                }
+    /// Checks the invariants of this [`ElfProgramHeader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfProgramHeaderError`] if the sizing or alignment of the segment described
+    /// by this [`ElfProgramHeader`] is invalid.
+    pub fn validate(&self) -> Result<(), ParseElfProgramHeaderError> {
+        if self.file_size() > self.memory_size() {
+            return Err(ParseElfProgramHeaderError::InvalidSizing);
+        }
 
-                if elf_program_header.alignment() != 0
-                    && elf_program_header.virtual_address() % elf_program_header.alignment()
-                        != elf_program_header.file_offset() % elf_program_header.alignment()
-                {
-                    return Err(ParseElfProgramHeaderError::UnalignedSegment);
-                }
+        if !self.alignment().is_power_of_two() && self.alignment() != 0 {
+            return Err(ParseElfProgramHeaderError::InvalidAlignment);
+        }
 
-                Ok(elf_program_header)
-            }
+        if self.alignment() != 0
+            && self.virtual_address().checked_rem(self.alignment())
+                != self.file_offset().checked_rem(self.alignment())
+        {
+            return Err(ParseElfProgramHeaderError::UnalignedSegment);
         }
+
+        Ok(())
     }
 
-    /// Returns the data associated with the [`ElfProgramHeader`].
-    pub fn segment_data(&self, file: ElfFile<'slice, C, E>) -> Option<&[u8]> {
-        let base: usize = self.file_offset().try_into().ok()?;
-        let size: usize = self.file_size().try_into().ok()?;
+    /// Returns the range of offsets into the file occupied by the segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SegmentRangeError`] if the end of the range overflows.
+    pub fn file_range(&self) -> Result<Range<u64>, SegmentRangeError> {
+        let start = self.file_offset();
+        let end = start
+            .checked_add(self.file_size())
+            .ok_or(SegmentRangeError)?;
+        Ok(start..end)
+    }
 
-        let max_offset = base.checked_add(size)?;
-        file.slice.get(base..max_offset)
+    /// Returns the range of virtual addresses occupied by the memory image of the segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SegmentRangeError`] if the end of the range overflows.
+    pub fn vaddr_range(&self) -> Result<Range<u64>, SegmentRangeError> {
+        let start = self.virtual_address();
+        let end = start
+            .checked_add(self.memory_size())
+            .ok_or(SegmentRangeError)?;
+        Ok(start..end)
+    }
+
+    /// Returns the bytes of `file` that make up the file image of the segment, along with the
+    /// number of zero bytes that follow them in the memory image of the segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SegmentDataError::OffsetOverflow`] if computing the end of the file image
+    /// overflows, [`SegmentDataError::SliceOutOfBounds`] if the file image is not located inside
+    /// of `file`, and [`SegmentDataError::InvalidSizing`] if the file image is larger than the
+    /// memory image.
+    pub fn segment_data(
+        &self,
+        file: &ElfFile<'slice, C, E>,
+    ) -> Result<(&'slice [u8], u64), SegmentDataError> {
+        let zero_fill = self
+            .memory_size()
+            .checked_sub(self.file_size())
+            .ok_or(SegmentDataError::InvalidSizing)?;
+
+        Ok((self.file_data(file)?, zero_fill))
+    }
+
+    /// Returns the bytes of `file` that make up the file image of the segment.
+    ///
+    /// Unlike [`ElfProgramHeader::segment_data`], this does not require the file image to fit
+    /// inside of the memory image, as is the case for the [`SegmentType::NOTE`] segments of core
+    /// dumps, which occupy no memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SegmentDataError::OffsetOverflow`] if computing the end of the file image
+    /// overflows and [`SegmentDataError::SliceOutOfBounds`] if the file image is not located
+    /// inside of `file`.
+    pub fn file_data(
+        &self,
+        file: &ElfFile<'slice, C, E>,
+    ) -> Result<&'slice [u8], SegmentDataError> {
+        let range = self
+            .file_range()
+            .map_err(|_| SegmentDataError::OffsetOverflow)?;
+        let start: usize = range
+            .start
+            .try_into()
+            .map_err(|_| SegmentDataError::OffsetOverflow)?;
+        let end: usize = range
+            .end
+            .try_into()
+            .map_err(|_| SegmentDataError::OffsetOverflow)?;
+
+        let data = file
+            .slice
+            .get(start..end)
+            .ok_or(SegmentDataError::SliceOutOfBounds)?;
+        Ok(data)
     }
 
     /// Returns the [`SegmentType`], which determines how to interpret the [`ElfProgramHeader`]'s
     /// information.
     pub fn segment_type(&self) -> SegmentType {
         let segment_type_value = match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32ProgramHeader, r#type), self.slice),
             Class::Class64 => self
                 .encoding
                 .parse_u32_at(mem::offset_of!(Elf64ProgramHeader, r#type), self.slice),
@@ -85,7 +172,9 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// Returns various flags relevant to the segment.
     pub fn flags(&self) -> SegmentFlags {
         let flags_value = match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32ProgramHeader, flags), self.slice),
             Class::Class64 => self
                 .encoding
                 .parse_u32_at(mem::offset_of!(Elf64ProgramHeader, flags), self.slice),
@@ -98,7 +187,10 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// exists.
     pub fn file_offset(&self) -> u64 {
         match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32ProgramHeader, file_offset), self.slice)
+                .into(),
             Class::Class64 => self
                 .encoding
                 .parse_u64_at(mem::offset_of!(Elf64ProgramHeader, file_offset), self.slice),
@@ -109,7 +201,13 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// loaded.
     pub fn virtual_address(&self) -> u64 {
         match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(
+                    mem::offset_of!(Elf32ProgramHeader, virtual_address),
+                    self.slice,
+                )
+                .into(),
             Class::Class64 => self.encoding.parse_u64_at(
                 mem::offset_of!(Elf64ProgramHeader, virtual_address),
                 self.slice,
@@ -121,7 +219,13 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// segment's physical address.
     pub fn physical_address(&self) -> u64 {
         match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(
+                    mem::offset_of!(Elf32ProgramHeader, physical_address),
+                    self.slice,
+                )
+                .into(),
             Class::Class64 => self.encoding.parse_u64_at(
                 mem::offset_of!(Elf64ProgramHeader, physical_address),
                 self.slice,
@@ -134,7 +238,10 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// This may be zero.
     pub fn file_size(&self) -> u64 {
         match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32ProgramHeader, file_size), self.slice)
+                .into(),
             Class::Class64 => self
                 .encoding
                 .parse_u64_at(mem::offset_of!(Elf64ProgramHeader, file_size), self.slice),
@@ -146,7 +253,10 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// This may be zero.
     pub fn memory_size(&self) -> u64 {
         match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32ProgramHeader, memory_size), self.slice)
+                .into(),
             Class::Class64 => self
                 .encoding
                 .parse_u64_at(mem::offset_of!(Elf64ProgramHeader, memory_size), self.slice),
@@ -158,7 +268,10 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
     /// This alignment is applicable both in the file and in memory.
     pub fn alignment(&self) -> u64 {
         match self.class.into_class() {
-            Class::Class32 => todo!(),
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32ProgramHeader, alignment), self.slice)
+                .into(),
             Class::Class64 => self
                 .encoding
                 .parse_u64_at(mem::offset_of!(Elf64ProgramHeader, alignment), self.slice),
@@ -196,6 +309,21 @@ pub enum ParseElfProgramHeaderError {
     InvalidSizing,
 }
 
+/// An error that occurs when the end of a range described by an [`ElfProgramHeader`] overflows.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct SegmentRangeError;
+
+/// Various errors that can occur while retrieving the contents of an [`ElfProgramHeader`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SegmentDataError {
+    /// The end of the file image overflowed while being computed.
+    OffsetOverflow,
+    /// The file image is not located inside of the file.
+    SliceOutOfBounds,
+    /// The file image is larger than the memory image.
+    InvalidSizing,
+}
+
 /// A table of [`ElfProgramHeader`]s.
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub struct ElfProgramHeaderTable<'slice, C: ClassParse, E: EncodingParse> {
@@ -208,6 +336,11 @@ pub struct ElfProgramHeaderTable<'slice, C: ClassParse, E: EncodingParse> {
 
 impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderTable<'slice, C, E> {
     /// Parses an [`ElfProgramHeaderTable`] from the provided `slice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfProgramHeaderTableError`] if `slice` is too small to contain the
+    /// [`ElfProgramHeaderTable`] or if any [`ElfProgramHeader`] is invalid.
     pub fn parse(
         slice: &'slice [u8],
         entry_count: usize,
@@ -215,6 +348,44 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderTable<'slice, C, E
         class: C,
         encoding: E,
     ) -> Result<Self, ParseElfProgramHeaderTableError> {
+        let elf_program_header_table =
+            Self::from_raw_parts(slice, entry_count, entry_size, class, encoding)?;
+
+        elf_program_header_table.validate()?;
+        Ok(elf_program_header_table)
+    }
+
+    /// Creates an [`ElfProgramHeaderTable`] from a `slice` that contains just the program header
+    /// array, without requiring an [`ElfHeader`][eh].
+    ///
+    /// This matches the information available at runtime through `dl_iterate_phdr` or the
+    /// `AT_PHDR`, `AT_PHENT` and `AT_PHNUM` auxiliary vector entries. Unlike
+    /// [`ElfProgramHeaderTable::parse`], the individual [`ElfProgramHeader`]s are not validated;
+    /// use [`ElfProgramHeaderTable::validate`] to check them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfProgramHeaderTableError::InvalidEntrySize`] if `entry_size` is smaller
+    /// than an [`ElfProgramHeader`] of `class`, and
+    /// [`ParseElfProgramHeaderTableError::SliceTooSmall`] if `slice` is too small to contain
+    /// `entry_count` entries.
+    ///
+    /// [eh]: crate::elf_header::ElfHeader
+    pub fn from_raw_parts(
+        slice: &'slice [u8],
+        entry_count: usize,
+        entry_size: usize,
+        class: C,
+        encoding: E,
+    ) -> Result<Self, ParseElfProgramHeaderTableError> {
+        let minimum_entry_size = match class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32ProgramHeader>(),
+            Class::Class64 => mem::size_of::<Elf64ProgramHeader>(),
+        };
+        if entry_count != 0 && entry_size < minimum_entry_size {
+            return Err(ParseElfProgramHeaderTableError::InvalidEntrySize);
+        }
+
         let total_size = entry_count
             .checked_mul(entry_size)
             .ok_or(ParseElfProgramHeaderTableError::SliceTooSmall)?;
@@ -222,34 +393,81 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderTable<'slice, C, E
             return Err(ParseElfProgramHeaderTableError::SliceTooSmall);
         }
 
-        let elf_program_header_table = Self {
+        Ok(Self {
             slice,
             entry_count,
             entry_size,
             class,
             encoding,
-        };
+        })
+    }
 
-        for index in 0..entry_count {
-            ElfProgramHeader::parse(&slice[index * entry_size..], class, encoding).map_err(
-                |error| ParseElfProgramHeaderTableError::ParseElfProgramHeaderError {
-                    index,
-                    error,
-                },
-            )?;
+    /// Checks the invariants of every [`ElfProgramHeader`] in this [`ElfProgramHeaderTable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfProgramHeaderTableError::ParseElfProgramHeaderError`] describing the
+    /// first [`ElfProgramHeader`] that is invalid.
+    pub fn validate(&self) -> Result<(), ParseElfProgramHeaderTableError> {
+        for index in 0..self.entry_count {
+            self.get_validated(index)?;
         }
 
-        Ok(elf_program_header_table)
+        Ok(())
+    }
+
+    /// Returns the [`ElfProgramHeader`] located at `index`, checking its invariants as
+    /// [`ElfProgramHeader::validate`] does.
+    ///
+    /// This defers the per-entry checks skipped by [`ElfFile::parse`][parse] to the first access
+    /// of each entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfProgramHeaderTableError::SliceTooSmall`] if the entry is not accessible,
+    /// and [`ParseElfProgramHeaderTableError::ParseElfProgramHeaderError`] if it is invalid.
+    ///
+    /// [parse]: crate::ElfFile::parse
+    pub fn get_validated(
+        &self,
+        index: usize,
+    ) -> Result<ElfProgramHeader<'slice, C, E>, ParseElfProgramHeaderTableError> {
+        let program_header = self
+            .try_get(index)
+            .map_err(|_| ParseElfProgramHeaderTableError::SliceTooSmall)?;
+        ElfProgramHeader::parse(program_header.slice, self.class, self.encoding).map_err(|error| {
+            ParseElfProgramHeaderTableError::ParseElfProgramHeaderError { index, error }
+        })
     }
 
     /// Returns the [`ElfProgramHeader`] located at `index`.
     pub fn get(&self, index: usize) -> Option<ElfProgramHeader<'slice, C, E>> {
+        self.try_get(index).ok()
+    }
+
+    /// Returns the [`ElfProgramHeader`] located at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableIndexError::IndexOutOfBounds`] if `index` is not less than the number of
+    /// entries, [`TableIndexError::OffsetOverflow`] if computing the offset of the entry
+    /// overflows, and [`TableIndexError::SliceOutOfBounds`] if the entry is not located inside of
+    /// the slice backing the [`ElfProgramHeaderTable`].
+    pub fn try_get(&self, index: usize) -> Result<ElfProgramHeader<'slice, C, E>, TableIndexError> {
         if index >= self.entry_count {
-            return None;
+            return Err(TableIndexError::IndexOutOfBounds);
         }
 
-        Some(ElfProgramHeader {
-            slice: &self.slice[index * self.entry_size..],
+        let offset = index
+            .checked_mul(self.entry_size)
+            .ok_or(TableIndexError::OffsetOverflow)?;
+        let slice = self
+            .slice
+            .get(offset..)
+            .ok_or(TableIndexError::SliceOutOfBounds)?;
+
+        Ok(ElfProgramHeader {
+            slice,
             class: self.class,
             encoding: self.encoding,
         })
@@ -260,6 +478,33 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderTable<'slice, C, E
         self.entry_count
     }
 
+    /// Returns `true` if the [`ElfProgramHeaderTable`] contains no [`ElfProgramHeader`]s.
+    pub fn is_empty(&self) -> bool {
+        self.entry_count == 0
+    }
+
+    /// Returns the [`ElfProgramHeader`] located at `index`.
+    ///
+    /// This is the equivalent of slice indexing; [`Index`][index] cannot be implemented since the
+    /// [`ElfProgramHeader`]s are decoded on access rather than stored in the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the number of entries or if the entry is not located
+    /// inside of the slice backing the [`ElfProgramHeaderTable`].
+    ///
+    /// [index]: core::ops::Index
+    pub fn entry(&self, index: usize) -> ElfProgramHeader<'slice, C, E> {
+        match self.try_get(index) {
+            Ok(program_header) => program_header,
+            Err(TableIndexError::IndexOutOfBounds) => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.entry_count, index
+            ),
+            Err(error) => panic!("program header {index} is not accessible: {error:?}"),
+        }
+    }
+
     /// Returns an iterator over the [`ElfProgramHeader`]s of this [`ElfProgramHeaderTable`].
     pub fn iter(&self) -> Iter<'slice, C, E> {
         Iter {
@@ -267,6 +512,155 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeaderTable<'slice, C, E
             index: 0,
         }
     }
+
+    /// Returns an iterator over the [`ElfProgramHeader`]s of this [`ElfProgramHeaderTable`] that
+    /// checks each entry with [`ElfProgramHeaderTable::get_validated`] as it is reached.
+    pub fn iter_validated(&self) -> ValidatedIter<'slice, C, E> {
+        ValidatedIter {
+            program_header_table: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator over the [`ElfProgramHeader`]s whose [`SegmentType`] is
+    /// `segment_type`.
+    pub fn segments_of_type(&self, segment_type: SegmentType) -> OfType<'slice, C, E> {
+        OfType {
+            iter: self.iter(),
+            segment_type,
+        }
+    }
+
+    /// Returns an iterator over the [`SegmentType::LOAD`] [`ElfProgramHeader`]s.
+    pub fn load_segments(&self) -> OfType<'slice, C, E> {
+        self.segments_of_type(SegmentType::LOAD)
+    }
+
+    /// Writes the [`ElfProgramHeader`]s of this [`ElfProgramHeaderTable`] to the start of
+    /// `buffer`, laid out for `class` and encoded using `encoding`, returning the number of bytes
+    /// written.
+    ///
+    /// Each [`ElfProgramHeader`] is written using the natural entry size of `class`, regardless
+    /// of the entry size of this [`ElfProgramHeaderTable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteElfProgramHeaderTableError::BufferTooSmall`] if `buffer` cannot hold the
+    /// table, and [`WriteElfProgramHeaderTableError::ValueOutOfRange`] if a field does not fit
+    /// into its representation in `class`.
+    pub fn write_to<C2: ClassParse, E2: EncodingParse>(
+        &self,
+        buffer: &mut [u8],
+        class: C2,
+        encoding: E2,
+    ) -> Result<usize, WriteElfProgramHeaderTableError> {
+        let entry_size = match class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32ProgramHeader>(),
+            Class::Class64 => mem::size_of::<Elf64ProgramHeader>(),
+        };
+        let total_size = self
+            .entry_count
+            .checked_mul(entry_size)
+            .ok_or(WriteElfProgramHeaderTableError::BufferTooSmall)?;
+        let buffer = buffer
+            .get_mut(..total_size)
+            .ok_or(WriteElfProgramHeaderTableError::BufferTooSmall)?;
+
+        for (index, (program_header, entry)) in self
+            .iter()
+            .zip(buffer.chunks_exact_mut(entry_size))
+            .enumerate()
+        {
+            match class.into_class() {
+                Class::Class32 => {
+                    let narrow = |value: u64| {
+                        u32::try_from(value)
+                            .map_err(|_| WriteElfProgramHeaderTableError::ValueOutOfRange { index })
+                    };
+
+                    for (offset, value) in [
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, r#type),
+                            program_header.segment_type().0,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, file_offset),
+                            narrow(program_header.file_offset())?,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, virtual_address),
+                            narrow(program_header.virtual_address())?,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, physical_address),
+                            narrow(program_header.physical_address())?,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, file_size),
+                            narrow(program_header.file_size())?,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, memory_size),
+                            narrow(program_header.memory_size())?,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, flags),
+                            program_header.flags().0,
+                        ),
+                        (
+                            mem::offset_of!(Elf32ProgramHeader, alignment),
+                            narrow(program_header.alignment())?,
+                        ),
+                    ] {
+                        encoding.write_u32_at(offset, value, entry);
+                    }
+                }
+                Class::Class64 => {
+                    encoding.write_u32_at(
+                        mem::offset_of!(Elf64ProgramHeader, r#type),
+                        program_header.segment_type().0,
+                        entry,
+                    );
+                    encoding.write_u32_at(
+                        mem::offset_of!(Elf64ProgramHeader, flags),
+                        program_header.flags().0,
+                        entry,
+                    );
+
+                    for (offset, value) in [
+                        (
+                            mem::offset_of!(Elf64ProgramHeader, file_offset),
+                            program_header.file_offset(),
+                        ),
+                        (
+                            mem::offset_of!(Elf64ProgramHeader, virtual_address),
+                            program_header.virtual_address(),
+                        ),
+                        (
+                            mem::offset_of!(Elf64ProgramHeader, physical_address),
+                            program_header.physical_address(),
+                        ),
+                        (
+                            mem::offset_of!(Elf64ProgramHeader, file_size),
+                            program_header.file_size(),
+                        ),
+                        (
+                            mem::offset_of!(Elf64ProgramHeader, memory_size),
+                            program_header.memory_size(),
+                        ),
+                        (
+                            mem::offset_of!(Elf64ProgramHeader, alignment),
+                            program_header.alignment(),
+                        ),
+                    ] {
+                        encoding.write_u64_at(offset, value, entry);
+                    }
+                }
+            }
+        }
+
+        Ok(total_size)
+    }
 }
 
 impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfProgramHeaderTable<'slice, C, E> {
@@ -274,7 +668,7 @@ impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfProgramHeaderTab
         let mut debug_list = f.debug_list();
 
         for i in 0..self.entry_count {
-            debug_list.entry(&self.get(i).unwrap());
+            debug_list.entry(&self.entry(i));
         }
 
         debug_list.finish()
@@ -286,6 +680,8 @@ impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfProgramHeaderTab
 pub enum ParseElfProgramHeaderTableError {
     /// The given slice was too small to contain the specified [`ElfProgramHeaderTable`].
     SliceTooSmall,
+    /// The given entry size is smaller than an [`ElfProgramHeader`].
+    InvalidEntrySize,
     /// An error occurred while parsing the [`ElfProgramHeader`] at `index`.
     ParseElfProgramHeaderError {
         /// The index of the [`ElfProgramHeader`] that parsing failed on.
@@ -295,6 +691,18 @@ pub enum ParseElfProgramHeaderTableError {
     },
 }
 
+/// Various errors that can occur while writing an [`ElfProgramHeaderTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum WriteElfProgramHeaderTableError {
+    /// The given buffer was too small to contain the [`ElfProgramHeaderTable`].
+    BufferTooSmall,
+    /// A field of the [`ElfProgramHeader`] at `index` does not fit into the requested class.
+    ValueOutOfRange {
+        /// The index of the [`ElfProgramHeader`] that could not be written.
+        index: usize,
+    },
+}
+
 /// An iterator over the [`ElfProgramHeader`]s of an [`ElfProgramHeaderTable`].
 pub struct Iter<'slice, C: ClassParse, E: EncodingParse> {
     program_header_table: ElfProgramHeaderTable<'slice, C, E>,
@@ -310,3 +718,186 @@ impl<'slice, C: ClassParse, E: EncodingParse> Iterator for Iter<'slice, C, E> {
         Some(next)
     }
 }
+
+/// An iterator over the [`ElfProgramHeader`]s of an [`ElfProgramHeaderTable`] that validates
+/// each entry before yielding it.
+///
+/// Iteration stops after the first invalid entry.
+pub struct ValidatedIter<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfProgramHeaderTable`] being iterated over.
+    program_header_table: ElfProgramHeaderTable<'slice, C, E>,
+    /// The index of the next [`ElfProgramHeader`] to validate.
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for ValidatedIter<'slice, C, E> {
+    type Item = Result<ElfProgramHeader<'slice, C, E>, ParseElfProgramHeaderTableError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.program_header_table.len() {
+            return None;
+        }
+
+        let next = self.program_header_table.get_validated(self.index);
+        self.index = match next {
+            Ok(_) => self.index.saturating_add(1),
+            Err(_) => self.program_header_table.len(),
+        };
+        Some(next)
+    }
+}
+
+/// An iterator over the [`ElfProgramHeader`]s of an [`ElfProgramHeaderTable`] that have the
+/// requested [`SegmentType`].
+pub struct OfType<'slice, C: ClassParse, E: EncodingParse> {
+    /// The underlying iterator over all [`ElfProgramHeader`]s.
+    iter: Iter<'slice, C, E>,
+    /// The [`SegmentType`] to yield.
+    segment_type: SegmentType,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for OfType<'slice, C, E> {
+    type Item = ElfProgramHeader<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let segment_type = self.segment_type;
+        self.iter
+            .find(|program_header| program_header.segment_type() == segment_type)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns an iterator over the [`ElfProgramHeader`]s of this [`ElfFile`] whose
+    /// [`SegmentType`] is `segment_type`, which is empty if this [`ElfFile`] has no
+    /// [`ElfProgramHeaderTable`].
+    pub fn segments_of_type(&self, segment_type: SegmentType) -> OfType<'slice, C, E> {
+        self.program_header_table()
+            .unwrap_or(ElfProgramHeaderTable {
+                slice: &[],
+                entry_count: 0,
+                entry_size: 0,
+                class: self.class,
+                encoding: self.encoding,
+            })
+            .segments_of_type(segment_type)
+    }
+
+    /// Returns an iterator over the [`SegmentType::LOAD`] [`ElfProgramHeader`]s of this
+    /// [`ElfFile`].
+    pub fn load_segments(&self) -> OfType<'slice, C, E> {
+        self.segments_of_type(SegmentType::LOAD)
+    }
+
+    /// Returns the file offset backing the virtual address `vaddr`, if `vaddr` lies within the
+    /// file image of a [`SegmentType::LOAD`] segment.
+    pub fn vaddr_to_file_offset(&self, vaddr: u64) -> Option<u64> {
+        self.load_segments().find_map(|program_header| {
+            let offset = vaddr.checked_sub(program_header.virtual_address())?;
+            if offset >= program_header.file_size() {
+                return None;
+            }
+
+            program_header.file_offset().checked_add(offset)
+        })
+    }
+
+    /// Returns the `size` bytes of this [`ElfFile`] backing the virtual addresses starting at
+    /// `vaddr`, if they all lie within the file image of a single [`SegmentType::LOAD`] segment.
+    pub fn vaddr_bytes(&self, vaddr: u64, size: u64) -> Option<&'slice [u8]> {
+        if size == 0 {
+            return self.vaddr_to_file_offset(vaddr).map(|_| &[][..]);
+        }
+
+        self.vaddr_bytes_to_end(vaddr)?.get(..size.try_into().ok()?)
+    }
+
+    /// Returns the bytes of this [`ElfFile`] backing the virtual addresses from `vaddr` to the
+    /// end of the file image of the [`SegmentType::LOAD`] segment containing `vaddr`.
+    ///
+    /// This is useful for structures whose size is only known once they have been parsed.
+    pub fn vaddr_bytes_to_end(&self, vaddr: u64) -> Option<&'slice [u8]> {
+        self.load_segments().find_map(|program_header| {
+            let offset = vaddr.checked_sub(program_header.virtual_address())?;
+            if offset >= program_header.file_size() {
+                return None;
+            }
+
+            let (data, _) = program_header.segment_data(self).ok()?;
+            data.get(offset.try_into().ok()?..)
+        })
+    }
+
+    /// Returns the path of the program interpreter named by the [`SegmentType::INTERP`] segment,
+    /// not including the NUL terminator, or [`None`] if this [`ElfFile`] has no such segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InterpreterError::SegmentDataError`] if the contents of the segment could not be
+    /// retrieved, and [`InterpreterError::MissingNulTerminator`] if the path is not terminated by
+    /// a NUL byte within the segment.
+    pub fn interpreter(&self) -> Result<Option<&'slice [u8]>, InterpreterError> {
+        let Some(program_header) = self.segments_of_type(SegmentType::INTERP).next() else {
+            return Ok(None);
+        };
+
+        let (data, _) = program_header.segment_data(self)?;
+        let path =
+            CStr::from_bytes_until_nul(data).map_err(|_| InterpreterError::MissingNulTerminator)?;
+        Ok(Some(path.to_bytes()))
+    }
+
+    /// Returns the stack requirements recorded by the [`SegmentType::GNU_STACK`] segment, or
+    /// [`None`] if this [`ElfFile`] has no such segment.
+    pub fn gnu_stack(&self) -> Option<GnuStack> {
+        let program_header = self.segments_of_type(SegmentType::GNU_STACK).next()?;
+        Some(GnuStack {
+            flags: program_header.flags(),
+            size: program_header.memory_size(),
+        })
+    }
+
+    /// Returns the range of virtual addresses described by the [`SegmentType::GNU_RELRO`]
+    /// segment, which should be made read-only once relocation processing is complete.
+    ///
+    /// Returns [`None`] if this [`ElfFile`] has no such segment, or if the end of the range
+    /// overflows.
+    pub fn relro_range(&self) -> Option<Range<u64>> {
+        self.segments_of_type(SegmentType::GNU_RELRO)
+            .next()?
+            .vaddr_range()
+            .ok()
+    }
+}
+
+/// The stack requirements recorded by a [`SegmentType::GNU_STACK`] segment.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct GnuStack {
+    /// The permissions requested for the stack.
+    pub flags: SegmentFlags,
+    /// The requested size of the stack, or zero if the default size should be used.
+    ///
+    /// Not every operating system honors this value.
+    pub size: u64,
+}
+
+impl GnuStack {
+    /// Returns `true` if an executable stack is requested.
+    pub fn is_executable(&self) -> bool {
+        self.flags.contains(SegmentFlags::EXECUTE)
+    }
+}
+
+/// Various errors that can occur while retrieving the program interpreter of an [`ElfFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum InterpreterError {
+    /// An error occurred while retrieving the contents of the [`SegmentType::INTERP`] segment.
+    SegmentDataError(SegmentDataError),
+    /// The path is not terminated by a NUL byte within the [`SegmentType::INTERP`] segment.
+    MissingNulTerminator,
+}
+
+impl From<SegmentDataError> for InterpreterError {
+    fn from(value: SegmentDataError) -> Self {
+        Self::SegmentDataError(value)
+    }
+}
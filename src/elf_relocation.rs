@@ -0,0 +1,518 @@
+//! Definitions and interfaces for interacting with ELF relocations.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    encoding::EncodingParse,
+    raw::{
+        elf_header::Machine,
+        elf_relocation::{Elf32Rela, Elf64Rela},
+    },
+    TableIndexError,
+};
+
+/// An entry of an [`ElfRelocationTable`], with or without an explicit addend.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfRelocation<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfRelocation`].
+    pub(crate) slice: &'slice [u8],
+    /// Whether the [`ElfRelocation`] has an explicit addend.
+    pub(crate) has_addend: bool,
+    /// The [`Machine`] that determines how the `info` field is packed.
+    pub(crate) machine: Machine,
+    /// The [`ClassParse`] used to parse the [`ElfRelocation`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfRelocation`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfRelocation<'slice, C, E> {
+    /// Returns the location at which to apply the [`ElfRelocation`].
+    ///
+    /// For [`ElfType::RELOCATABLE`][etr] files, this is an offset into the section being
+    /// relocated, while for other files, this is a virtual address.
+    ///
+    /// [etr]: crate::raw::elf_header::ElfType::RELOCATABLE
+    pub fn offset(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Rela, offset), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Rela, offset), self.slice),
+        }
+    }
+
+    /// Returns the raw `info` field of the [`ElfRelocation`], which holds both its symbol index
+    /// and its relocation type.
+    pub fn info(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Rela, info), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Rela, info), self.slice),
+        }
+    }
+
+    /// Returns the index of the symbol referenced by the [`ElfRelocation`], or zero if it
+    /// references no symbol.
+    pub fn symbol_index(&self) -> u32 {
+        if let Some(info) = self.mips64_info() {
+            return info.symbol_index;
+        }
+
+        let info = self.info();
+        match self.class.into_class() {
+            Class::Class32 => (info >> 8) as u32,
+            Class::Class64 => (info >> 32) as u32,
+        }
+    }
+
+    /// Returns the machine-specific type of the [`ElfRelocation`].
+    ///
+    /// For 64-bit [`Machine::MIPS`] objects, this is the first of the three types returned by
+    /// [`ElfRelocation::mips64_info`].
+    pub fn relocation_type(&self) -> u32 {
+        if let Some(info) = self.mips64_info() {
+            return info.types[0].into();
+        }
+
+        let info = self.info();
+        match self.class.into_class() {
+            Class::Class32 => (info & 0xFF) as u32,
+            Class::Class64 => info as u32,
+        }
+    }
+
+    /// Returns the fields of the `info` field of the [`ElfRelocation`] as packed by 64-bit
+    /// [`Machine::MIPS`] objects, or [`None`] if the [`ElfRelocation`] belongs to another kind of
+    /// object.
+    pub fn mips64_info(&self) -> Option<Mips64RelocationInfo> {
+        if self.machine != Machine::MIPS || self.class.into_class() != Class::Class64 {
+            return None;
+        }
+
+        let offset = mem::offset_of!(Elf64Rela, info);
+        let byte = |index: usize| {
+            self.encoding
+                .parse_u8_at(offset.wrapping_add(index), self.slice)
+        };
+        Some(Mips64RelocationInfo {
+            symbol_index: self.encoding.parse_u32_at(offset, self.slice),
+            special_symbol: byte(4),
+            types: [byte(7), byte(6), byte(5)],
+        })
+    }
+
+    /// Returns the explicit addend of the [`ElfRelocation`], or [`None`] if its addend is stored
+    /// in the field being relocated.
+    pub fn addend(&self) -> Option<i64> {
+        if !self.has_addend {
+            return None;
+        }
+
+        let addend = match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_i32_at(mem::offset_of!(Elf32Rela, addend), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_i64_at(mem::offset_of!(Elf64Rela, addend), self.slice),
+        };
+
+        Some(addend)
+    }
+}
+
+/// The fields of the `info` field of a relocation of a 64-bit [`Machine::MIPS`] object, which
+/// may combine up to three relocation types.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Mips64RelocationInfo {
+    /// The index of the symbol referenced by the relocation.
+    pub symbol_index: u32,
+    /// The special symbol used by the second relocation type.
+    pub special_symbol: u8,
+    /// The relocation types, in the order in which they are applied.
+    pub types: [u8; 3],
+}
+
+/// A table of [`ElfRelocation`]s, as stored in a [`SectionType::REL`][str] or
+/// [`SectionType::RELA`][stra] section.
+///
+/// [str]: crate::raw::elf_section_header::SectionType::REL
+/// [stra]: crate::raw::elf_section_header::SectionType::RELA
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfRelocationTable<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfRelocationTable`].
+    pub(crate) slice: &'slice [u8],
+    /// Whether the [`ElfRelocation`]s have explicit addends.
+    pub(crate) has_addend: bool,
+    /// The [`Machine`] that determines how the `info` fields are packed.
+    pub(crate) machine: Machine,
+    /// The [`ClassParse`] used to parse the [`ElfRelocationTable`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfRelocationTable`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfRelocationTable<'slice, C, E> {
+    /// Parses an [`ElfRelocationTable`] from the provided `slice`, whose [`ElfRelocation`]s have
+    /// explicit addends if `has_addend` is `true` and are decoded according to the conventions
+    /// of `machine`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfRelocationTableError::SizeNotMultipleOfEntrySize`] if `slice` does not
+    /// consist of a whole number of [`ElfRelocation`]s.
+    pub fn parse(
+        slice: &'slice [u8],
+        has_addend: bool,
+        machine: Machine,
+        class: C,
+        encoding: E,
+    ) -> Result<Self, ParseElfRelocationTableError> {
+        let relocation_table = Self {
+            slice,
+            has_addend,
+            machine,
+            class,
+            encoding,
+        };
+        if slice.len().checked_rem(relocation_table.entry_size()) != Some(0) {
+            return Err(ParseElfRelocationTableError::SizeNotMultipleOfEntrySize);
+        }
+
+        Ok(relocation_table)
+    }
+
+    /// Returns the [`ElfRelocation`] located at `index`.
+    pub fn get(&self, index: usize) -> Option<ElfRelocation<'slice, C, E>> {
+        self.try_get(index).ok()
+    }
+
+    /// Returns the [`ElfRelocation`] located at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableIndexError::IndexOutOfBounds`] if `index` is not less than the number of
+    /// entries and [`TableIndexError::OffsetOverflow`] if computing the offset of the entry
+    /// overflows.
+    pub fn try_get(&self, index: usize) -> Result<ElfRelocation<'slice, C, E>, TableIndexError> {
+        if index >= self.len() {
+            return Err(TableIndexError::IndexOutOfBounds);
+        }
+
+        let offset = index
+            .checked_mul(self.entry_size())
+            .ok_or(TableIndexError::OffsetOverflow)?;
+        let slice = self
+            .slice
+            .get(offset..)
+            .ok_or(TableIndexError::SliceOutOfBounds)?;
+
+        Ok(ElfRelocation {
+            slice,
+            has_addend: self.has_addend,
+            machine: self.machine,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Returns the number of [`ElfRelocation`]s in the [`ElfRelocationTable`].
+    pub fn len(&self) -> usize {
+        self.slice.len().checked_div(self.entry_size()).unwrap_or(0)
+    }
+
+    /// Returns `true` if the [`ElfRelocationTable`] contains no [`ElfRelocation`]s.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the [`ElfRelocation`]s of this [`ElfRelocationTable`] have explicit
+    /// addends.
+    pub fn has_addend(&self) -> bool {
+        self.has_addend
+    }
+
+    /// Returns an iterator over the [`ElfRelocation`]s of this [`ElfRelocationTable`].
+    pub fn iter(&self) -> Iter<'slice, C, E> {
+        Iter {
+            relocation_table: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns the size of a single [`ElfRelocation`].
+    pub fn entry_size(&self) -> usize {
+        match (self.class.into_class(), self.has_addend) {
+            (Class::Class32, false) => mem::offset_of!(Elf32Rela, addend),
+            (Class::Class32, true) => mem::size_of::<Elf32Rela>(),
+            (Class::Class64, false) => mem::offset_of!(Elf64Rela, addend),
+            (Class::Class64, true) => mem::size_of::<Elf64Rela>(),
+        }
+    }
+}
+
+/// Various errors that can occur while parsing an [`ElfRelocationTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfRelocationTableError {
+    /// The size of the given slice is not a multiple of the size of an [`ElfRelocation`].
+    SizeNotMultipleOfEntrySize,
+}
+
+/// An iterator over the [`ElfRelocation`]s of an [`ElfRelocationTable`].
+pub struct Iter<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfRelocationTable`] being iterated over.
+    relocation_table: ElfRelocationTable<'slice, C, E>,
+    /// The index of the next [`ElfRelocation`] to return.
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for Iter<'slice, C, E> {
+    type Item = ElfRelocation<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.relocation_table.get(self.index)?;
+        self.index = self.index.checked_add(1)?;
+        Some(next)
+    }
+}
+
+/// The field that relocations of a particular type modify, as returned by [`relocation_field`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct RelocationField {
+    /// The size, in bytes, of the field, or zero if the relocation does not modify a field.
+    pub size: usize,
+    /// Whether the value stored in the field is sign-extended when widened, as for PC-relative
+    /// relocations, rather than zero-extended.
+    pub signed: bool,
+    /// Whether the field is a TLS descriptor, which holds two words whose layout is defined by
+    /// the TLS descriptor ABI of the processor rather than a single value.
+    pub tls_descriptor: bool,
+}
+
+impl RelocationField {
+    /// Returns a [`RelocationField`] of `size` bytes whose value is zero-extended.
+    const fn new_unsigned(size: usize) -> Self {
+        Self {
+            size,
+            signed: false,
+            tls_descriptor: false,
+        }
+    }
+
+    /// Returns a [`RelocationField`] of `size` bytes whose value is sign-extended.
+    const fn new_signed(size: usize) -> Self {
+        Self {
+            size,
+            signed: true,
+            tls_descriptor: false,
+        }
+    }
+
+    /// Returns a [`RelocationField`] holding a TLS descriptor of `size` bytes.
+    const fn new_tls_descriptor(size: usize) -> Self {
+        Self {
+            size,
+            signed: false,
+            tls_descriptor: true,
+        }
+    }
+}
+
+/// Returns the [`RelocationField`] that relocations of type `relocation_type` modify on
+/// `machine`, whose size is zero if the relocation type does not modify a field.
+///
+/// Returns [`None`] if the relocation type is unknown, or if it modifies an instruction encoding
+/// rather than a plain data field.
+pub fn relocation_field(machine: Machine, relocation_type: u32) -> Option<RelocationField> {
+    let field = match machine {
+        Machine::I386 => match relocation_type {
+            // R_386_NONE, R_386_COPY and R_386_TLS_DESC_CALL.
+            0 | 5 | 40 => RelocationField::new_unsigned(0),
+            // R_386_32, R_386_GLOB_DAT, R_386_JMP_SLOT and R_386_RELATIVE.
+            1 | 6..=8 => RelocationField::new_unsigned(4),
+            // R_386_PC32, R_386_GOT32 and R_386_PLT32.
+            2..=4 => RelocationField::new_signed(4),
+            // R_386_GOTOFF and R_386_GOTPC.
+            9 | 10 => RelocationField::new_signed(4),
+            // R_386_TLS_TPOFF.
+            14 => RelocationField::new_signed(4),
+            // R_386_TLS_IE.
+            15 => RelocationField::new_unsigned(4),
+            // R_386_TLS_GOTIE through R_386_TLS_LDM.
+            16..=19 => RelocationField::new_signed(4),
+            // R_386_16 and R_386_8.
+            20 => RelocationField::new_unsigned(2),
+            22 => RelocationField::new_unsigned(1),
+            // R_386_PC16 and R_386_PC8.
+            21 => RelocationField::new_signed(2),
+            23 => RelocationField::new_signed(1),
+            // R_386_TLS_DTPMOD32, R_386_TLS_DTPOFF32 and R_386_TLS_TPOFF32.
+            35..=37 => RelocationField::new_unsigned(4),
+            // R_386_TLS_GOTDESC.
+            39 => RelocationField::new_signed(4),
+            // R_386_TLS_DESC.
+            41 => RelocationField::new_tls_descriptor(8),
+            // R_386_IRELATIVE.
+            42 => RelocationField::new_unsigned(4),
+            // R_386_GOT32X.
+            43 => RelocationField::new_signed(4),
+            _ => return None,
+        },
+        Machine::ARM => match relocation_type {
+            // R_ARM_NONE and R_ARM_COPY.
+            0 | 20 => RelocationField::new_unsigned(0),
+            // R_ARM_ABS32.
+            2 => RelocationField::new_unsigned(4),
+            // R_ARM_REL32.
+            3 => RelocationField::new_signed(4),
+            // R_ARM_ABS16 and R_ARM_ABS8.
+            5 => RelocationField::new_unsigned(2),
+            8 => RelocationField::new_unsigned(1),
+            // R_ARM_TLS_DESC.
+            13 => RelocationField::new_tls_descriptor(8),
+            // R_ARM_TLS_DTPMOD32, R_ARM_TLS_DTPOFF32 and R_ARM_TLS_TPOFF32.
+            17..=19 => RelocationField::new_unsigned(4),
+            // R_ARM_GLOB_DAT, R_ARM_JUMP_SLOT and R_ARM_RELATIVE.
+            21..=23 => RelocationField::new_unsigned(4),
+            // R_ARM_GOTOFF32, R_ARM_BASE_PREL and R_ARM_GOT_BREL.
+            24..=26 => RelocationField::new_signed(4),
+            // R_ARM_TARGET1 and R_ARM_IRELATIVE.
+            38 | 160 => RelocationField::new_unsigned(4),
+            _ => return None,
+        },
+        Machine::X86_64 => match relocation_type {
+            // R_X86_64_NONE, R_X86_64_COPY and R_X86_64_TLSDESC_CALL.
+            0 | 5 | 35 => RelocationField::new_unsigned(0),
+            // R_X86_64_64, R_X86_64_GLOB_DAT, R_X86_64_JUMP_SLOT and R_X86_64_RELATIVE.
+            1 | 6..=8 => RelocationField::new_unsigned(8),
+            // R_X86_64_PC32, R_X86_64_GOT32, R_X86_64_PLT32 and R_X86_64_GOTPCREL.
+            2..=4 | 9 => RelocationField::new_signed(4),
+            // R_X86_64_32.
+            10 => RelocationField::new_unsigned(4),
+            // R_X86_64_32S.
+            11 => RelocationField::new_signed(4),
+            // R_X86_64_16 and R_X86_64_8.
+            12 => RelocationField::new_unsigned(2),
+            14 => RelocationField::new_unsigned(1),
+            // R_X86_64_PC16 and R_X86_64_PC8.
+            13 => RelocationField::new_signed(2),
+            15 => RelocationField::new_signed(1),
+            // R_X86_64_DTPMOD64 and R_X86_64_DTPOFF64.
+            16 | 17 => RelocationField::new_unsigned(8),
+            // R_X86_64_TPOFF64.
+            18 => RelocationField::new_signed(8),
+            // R_X86_64_TLSGD through R_X86_64_TPOFF32.
+            19..=23 => RelocationField::new_signed(4),
+            // R_X86_64_PC64 and R_X86_64_GOTOFF64.
+            24 | 25 => RelocationField::new_signed(8),
+            // R_X86_64_GOTPC32 and R_X86_64_GOTPC32_TLSDESC.
+            26 | 34 => RelocationField::new_signed(4),
+            // R_X86_64_SIZE32.
+            32 => RelocationField::new_unsigned(4),
+            // R_X86_64_SIZE64.
+            33 => RelocationField::new_unsigned(8),
+            // R_X86_64_TLSDESC.
+            36 => RelocationField::new_tls_descriptor(16),
+            // R_X86_64_IRELATIVE and R_X86_64_RELATIVE64.
+            37 | 38 => RelocationField::new_unsigned(8),
+            // R_X86_64_GOTPCRELX and R_X86_64_REX_GOTPCRELX.
+            41 | 42 => RelocationField::new_signed(4),
+            _ => return None,
+        },
+        Machine::AARCH64 => match relocation_type {
+            // R_AARCH64_NONE and R_AARCH64_COPY.
+            0 | 1024 => RelocationField::new_unsigned(0),
+            // R_AARCH64_ABS64, R_AARCH64_ABS32 and R_AARCH64_ABS16.
+            257 => RelocationField::new_unsigned(8),
+            258 => RelocationField::new_unsigned(4),
+            259 => RelocationField::new_unsigned(2),
+            // R_AARCH64_PREL64, R_AARCH64_PREL32 and R_AARCH64_PREL16.
+            260 => RelocationField::new_signed(8),
+            261 => RelocationField::new_signed(4),
+            262 => RelocationField::new_signed(2),
+            // R_AARCH64_GLOB_DAT through R_AARCH64_TLS_TPREL.
+            1025..=1030 => RelocationField::new_unsigned(8),
+            // R_AARCH64_TLSDESC.
+            1031 => RelocationField::new_tls_descriptor(16),
+            // R_AARCH64_IRELATIVE.
+            1032 => RelocationField::new_unsigned(8),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(field)
+}
+
+/// Reads the implicit addend of a relocation of type `relocation_type` on `machine`, which is
+/// stored in the field located at `offset` bytes from the start of `image`.
+///
+/// `image` may either be the contents of the ELF file, in which case `offset` must be the file
+/// offset of the relocation target, or a loaded memory image, in which case `offset` must be the
+/// offset of the relocation target from the start of the image. The value is sign-extended or
+/// zero-extended from the width of the field as indicated by [`RelocationField::signed`].
+///
+/// # Errors
+///
+/// Returns [`ReadImplicitAddendError::UnsupportedRelocationType`] if the field modified by the
+/// relocation is unknown or is a TLS descriptor, and [`ReadImplicitAddendError::OutOfBounds`] if
+/// the field is not located inside of `image`.
+pub fn read_implicit_addend<E: EncodingParse>(
+    machine: Machine,
+    relocation_type: u32,
+    image: &[u8],
+    offset: u64,
+    encoding: E,
+) -> Result<i64, ReadImplicitAddendError> {
+    let RelocationField {
+        size,
+        signed,
+        tls_descriptor,
+    } = relocation_field(machine, relocation_type)
+        .ok_or(ReadImplicitAddendError::UnsupportedRelocationType)?;
+    if tls_descriptor {
+        return Err(ReadImplicitAddendError::UnsupportedRelocationType);
+    }
+
+    let offset: usize = offset
+        .try_into()
+        .map_err(|_| ReadImplicitAddendError::OutOfBounds)?;
+    let end = offset
+        .checked_add(size)
+        .ok_or(ReadImplicitAddendError::OutOfBounds)?;
+    let field = image
+        .get(offset..end)
+        .ok_or(ReadImplicitAddendError::OutOfBounds)?;
+
+    let addend = match (size, signed) {
+        (0, _) => 0,
+        (1, true) => i64::from(encoding.parse_u8_at(0, field) as i8),
+        (1, false) => i64::from(encoding.parse_u8_at(0, field)),
+        (2, true) => i64::from(encoding.parse_u16_at(0, field) as i16),
+        (2, false) => i64::from(encoding.parse_u16_at(0, field)),
+        (4, true) => i64::from(encoding.parse_i32_at(0, field)),
+        (4, false) => i64::from(encoding.parse_u32_at(0, field)),
+        _ => encoding.parse_i64_at(0, field),
+    };
+
+    Ok(addend)
+}
+
+/// Various errors that can occur while reading an implicit addend.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ReadImplicitAddendError {
+    /// The size of the field modified by the relocation type is unknown.
+    UnsupportedRelocationType,
+    /// The field modified by the relocation is not located inside of the image.
+    OutOfBounds,
+}
@@ -0,0 +1,708 @@
+//! Definitions and interfaces for interacting with an ELF section header.
+
+use core::{fmt, iter::FusedIterator, mem};
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_string_table::{self, ElfStringTable, ElfStringTableError},
+    encoding::EncodingParse,
+    raw::{
+        elf_dynamic::{Elf32Dynamic, Elf64Dynamic},
+        elf_relocation::{Elf32Rel, Elf32Rela, Elf64Rel, Elf64Rela},
+        elf_section_header::{
+            Elf32SectionHeader, Elf64SectionHeader, SectionFlags, SectionIndex, SectionType,
+        },
+        elf_symbol::{Elf32Symbol, Elf64Symbol},
+    },
+    ElfFile, TableIndexError,
+};
+
+/// Structure that describes the location, size and interpretation of a section of an ELF file.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfSectionHeader<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfSectionHeader`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the [`ElfSectionHeader`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfSectionHeader`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSectionHeader<'slice, C, E> {
+    /// Parses an [`ElfSectionHeader`] from the provided `slice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfSectionHeaderError::SliceTooSmall`] if `slice` is too small to contain an
+    /// [`ElfSectionHeader`].
+    pub fn parse(
+        slice: &'slice [u8],
+        class: C,
+        encoding: E,
+    ) -> Result<Self, ParseElfSectionHeaderError> {
+        let size = match class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32SectionHeader>(),
+            Class::Class64 => mem::size_of::<Elf64SectionHeader>(),
+        };
+        if slice.len() < size {
+            return Err(ParseElfSectionHeaderError::SliceTooSmall);
+        }
+
+        Ok(Self {
+            slice,
+            class,
+            encoding,
+        })
+    }
+
+    /// Returns the offset into the section name string table at which the name of the section is
+    /// located.
+    pub fn name(&self) -> u32 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, name), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64SectionHeader, name), self.slice),
+        }
+    }
+
+    /// Returns the name of the section, as stored in `names`.
+    ///
+    /// `names` should be the [`ElfStringTable`] returned by
+    /// [`ElfFile::section_name_string_table`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfStringTableError`] if the name could not be retrieved from `names`.
+    pub fn name_str(
+        &self,
+        names: ElfStringTable<'slice>,
+    ) -> Result<&'slice [u8], ElfStringTableError> {
+        names.get(self.name().into())
+    }
+
+    /// Returns the [`SectionType`], which determines how to interpret the contents of the
+    /// section.
+    pub fn kind(&self) -> SectionType {
+        let kind_value = match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, kind), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64SectionHeader, kind), self.slice),
+        };
+
+        SectionType(kind_value)
+    }
+
+    /// Returns various flags describing attributes of the section.
+    pub fn flags(&self) -> SectionFlags {
+        let flags_value = match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, flags), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64SectionHeader, flags), self.slice),
+        };
+
+        SectionFlags(flags_value)
+    }
+
+    /// Returns the virtual address at which the first byte of the section resides in memory when
+    /// loaded, or zero if the section does not appear in memory.
+    pub fn address(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, address), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64SectionHeader, address), self.slice),
+        }
+    }
+
+    /// Returns the offset from the beginning of the file at which the first byte of the section
+    /// exists.
+    pub fn offset(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, offset), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64SectionHeader, offset), self.slice),
+        }
+    }
+
+    /// Returns the size of the section in bytes.
+    pub fn size(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, size), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64SectionHeader, size), self.slice),
+        }
+    }
+
+    /// Returns the index of the [`ElfSectionHeader`] associated with this section, whose
+    /// interpretation depends on the [`SectionType`] of this section.
+    pub fn link(&self) -> u32 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, link), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64SectionHeader, link), self.slice),
+        }
+    }
+
+    /// Returns extra information about the section, whose interpretation depends on the
+    /// [`SectionType`] of this section.
+    pub fn info(&self) -> u32 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, info), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64SectionHeader, info), self.slice),
+        }
+    }
+
+    /// Returns the alignment constraint of the section, where zero and one both mean that the
+    /// section has no alignment constraint.
+    pub fn address_align(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(
+                    mem::offset_of!(Elf32SectionHeader, address_align),
+                    self.slice,
+                )
+                .into(),
+            Class::Class64 => self.encoding.parse_u64_at(
+                mem::offset_of!(Elf64SectionHeader, address_align),
+                self.slice,
+            ),
+        }
+    }
+
+    /// Returns the size of each entry of the section if the section holds a table of fixed-size
+    /// entries, or zero otherwise.
+    pub fn entry_size(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32SectionHeader, entry_size), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64SectionHeader, entry_size), self.slice),
+        }
+    }
+
+    /// Returns the bytes of `file` that make up the contents of the section.
+    ///
+    /// [`SectionType::NOBITS`] sections occupy no space in the file, and so their contents are
+    /// always empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SectionDataError::OffsetOverflow`] if computing the end of the section overflows,
+    /// and [`SectionDataError::SliceOutOfBounds`] if the section is not located inside of `file`.
+    pub fn data(&self, file: &ElfFile<'slice, C, E>) -> Result<&'slice [u8], SectionDataError> {
+        if self.kind() == SectionType::NOBITS {
+            return Ok(&[]);
+        }
+
+        let start: usize = self
+            .offset()
+            .try_into()
+            .map_err(|_| SectionDataError::OffsetOverflow)?;
+        let size: usize = self
+            .size()
+            .try_into()
+            .map_err(|_| SectionDataError::OffsetOverflow)?;
+        let end = start
+            .checked_add(size)
+            .ok_or(SectionDataError::OffsetOverflow)?;
+
+        file.slice
+            .get(start..end)
+            .ok_or(SectionDataError::SliceOutOfBounds)
+    }
+
+    /// Returns the entry size that the [`SectionType`] of this section requires for the class of
+    /// the ELF file, or [`None`] if the [`SectionType`] does not hold a table of fixed-size
+    /// entries known to this crate.
+    pub fn expected_entry_size(&self) -> Option<u64> {
+        let size = match (self.kind(), self.class.into_class()) {
+            (SectionType::SYMTAB | SectionType::DYNSYM, Class::Class32) => {
+                mem::size_of::<Elf32Symbol>()
+            }
+            (SectionType::SYMTAB | SectionType::DYNSYM, Class::Class64) => {
+                mem::size_of::<Elf64Symbol>()
+            }
+            (SectionType::RELA, Class::Class32) => mem::size_of::<Elf32Rela>(),
+            (SectionType::RELA, Class::Class64) => mem::size_of::<Elf64Rela>(),
+            (SectionType::REL, Class::Class32) => mem::size_of::<Elf32Rel>(),
+            (SectionType::REL, Class::Class64) => mem::size_of::<Elf64Rel>(),
+            (SectionType::DYNAMIC, Class::Class32) => mem::size_of::<Elf32Dynamic>(),
+            (SectionType::DYNAMIC, Class::Class64) => mem::size_of::<Elf64Dynamic>(),
+            _ => return None,
+        };
+
+        Some(size as u64)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfSectionHeader<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("ElfSectionHeader");
+
+        debug_struct.field("name", &self.name());
+        debug_struct.field("kind", &self.kind());
+        debug_struct.field("flags", &self.flags());
+        debug_struct.field("address", &self.address());
+        debug_struct.field("offset", &self.offset());
+        debug_struct.field("size", &self.size());
+        debug_struct.field("link", &self.link());
+        debug_struct.field("info", &self.info());
+        debug_struct.field("address_align", &self.address_align());
+        debug_struct.field("entry_size", &self.entry_size());
+
+        debug_struct.finish()
+    }
+}
+
+/// Various errors that can occur while parsing an [`ElfSectionHeader`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfSectionHeaderError {
+    /// The given slice was too small to contain an [`ElfSectionHeader`].
+    SliceTooSmall,
+}
+
+/// Various errors that can occur while retrieving the contents of an [`ElfSectionHeader`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SectionDataError {
+    /// The end of the section overflowed while being computed.
+    OffsetOverflow,
+    /// The section is not located inside of the file.
+    SliceOutOfBounds,
+}
+
+/// A table of [`ElfSectionHeader`]s.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfSectionHeaderTable<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfSectionHeaderTable`].
+    pub(crate) slice: &'slice [u8],
+    /// The number of [`ElfSectionHeader`]s in the [`ElfSectionHeaderTable`].
+    pub(crate) entry_count: usize,
+    /// The size, in bytes, of each [`ElfSectionHeader`].
+    pub(crate) entry_size: usize,
+    /// The [`ClassParse`] used to parse the [`ElfSectionHeaderTable`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfSectionHeaderTable`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSectionHeaderTable<'slice, C, E> {
+    /// Parses an [`ElfSectionHeaderTable`] from the provided `slice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfSectionHeaderTableError::SliceTooSmall`] if `slice` is too small to
+    /// contain `entry_count` entries of `entry_size` bytes, and
+    /// [`ParseElfSectionHeaderTableError::ParseElfSectionHeaderError`] if any of the
+    /// [`ElfSectionHeader`]s fails to parse.
+    pub fn parse(
+        slice: &'slice [u8],
+        entry_count: usize,
+        entry_size: usize,
+        class: C,
+        encoding: E,
+    ) -> Result<Self, ParseElfSectionHeaderTableError> {
+        let total_size = entry_count
+            .checked_mul(entry_size)
+            .ok_or(ParseElfSectionHeaderTableError::SliceTooSmall)?;
+        let slice = slice
+            .get(..total_size)
+            .ok_or(ParseElfSectionHeaderTableError::SliceTooSmall)?;
+
+        let elf_section_header_table = Self {
+            slice,
+            entry_count,
+            entry_size,
+            class,
+            encoding,
+        };
+
+        for index in 0..entry_count {
+            let entry = elf_section_header_table
+                .try_get(index)
+                .map_err(|_| ParseElfSectionHeaderTableError::SliceTooSmall)?;
+            ElfSectionHeader::parse(entry.slice, class, encoding).map_err(|error| {
+                ParseElfSectionHeaderTableError::ParseElfSectionHeaderError { index, error }
+            })?;
+        }
+
+        Ok(elf_section_header_table)
+    }
+
+    /// Returns the [`ElfSectionHeader`] located at `index`.
+    pub fn get(&self, index: usize) -> Option<ElfSectionHeader<'slice, C, E>> {
+        self.try_get(index).ok()
+    }
+
+    /// Returns the [`ElfSectionHeader`] located at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableIndexError::IndexOutOfBounds`] if `index` is not less than the number of
+    /// entries, [`TableIndexError::OffsetOverflow`] if computing the offset of the entry
+    /// overflows, and [`TableIndexError::SliceOutOfBounds`] if the entry is not located inside of
+    /// the slice backing the [`ElfSectionHeaderTable`].
+    pub fn try_get(&self, index: usize) -> Result<ElfSectionHeader<'slice, C, E>, TableIndexError> {
+        if index >= self.entry_count {
+            return Err(TableIndexError::IndexOutOfBounds);
+        }
+
+        let offset = index
+            .checked_mul(self.entry_size)
+            .ok_or(TableIndexError::OffsetOverflow)?;
+        let slice = self
+            .slice
+            .get(offset..)
+            .ok_or(TableIndexError::SliceOutOfBounds)?;
+
+        Ok(ElfSectionHeader {
+            slice,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Resolves `index`, as stored in a section link, section info or symbol section index, against
+    /// this [`ElfSectionHeaderTable`].
+    ///
+    /// Indices in the reserved range are never treated as references to an [`ElfSectionHeader`].
+    pub fn get_checked(&self, index: u32) -> SectionReference<'slice, C, E> {
+        if index == u32::from(SectionIndex::UNDEF.0) {
+            return SectionReference::Undefined;
+        }
+
+        if let Ok(index) = u16::try_from(index) {
+            match SectionIndex(index) {
+                SectionIndex::ABS => return SectionReference::Absolute,
+                SectionIndex::COMMON => return SectionReference::Common,
+                index if index.is_reserved() => return SectionReference::Reserved(index),
+                _ => {}
+            }
+        }
+
+        let section = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.get(index));
+        match section {
+            Some(section) => SectionReference::Section(section),
+            None => SectionReference::OutOfRange(index),
+        }
+    }
+
+    /// Returns the number of [`ElfSectionHeader`]s in the [`ElfSectionHeaderTable`].
+    pub fn len(&self) -> usize {
+        self.entry_count
+    }
+
+    /// Returns `true` if the [`ElfSectionHeaderTable`] contains no [`ElfSectionHeader`]s.
+    pub fn is_empty(&self) -> bool {
+        self.entry_count == 0
+    }
+
+    /// Returns an iterator over the [`ElfSectionHeader`]s of this [`ElfSectionHeaderTable`].
+    pub fn iter(&self) -> Iter<'slice, C, E> {
+        Iter {
+            section_header_table: *self,
+            index: 0,
+            end: self.entry_count,
+        }
+    }
+
+    /// Returns an iterator over the [`ElfSectionHeader`]s that have all of the flags in `mask`
+    /// set.
+    pub fn sections_with_flags(&self, mask: SectionFlags) -> WithFlags<'slice, C, E> {
+        WithFlags {
+            iter: self.iter(),
+            mask,
+        }
+    }
+
+    /// Returns an iterator over the [`ElfSectionHeader`]s that occupy memory during process
+    /// execution.
+    pub fn alloc_sections(&self) -> WithFlags<'slice, C, E> {
+        self.sections_with_flags(SectionFlags::ALLOC)
+    }
+
+    /// Returns an iterator over the [`ElfSectionHeader`]s that contain executable machine
+    /// instructions.
+    pub fn executable_sections(&self) -> WithFlags<'slice, C, E> {
+        self.sections_with_flags(SectionFlags::EXECINSTR)
+    }
+
+    /// Returns an iterator over the [`ElfSectionHeader`]s that are writable during process
+    /// execution.
+    pub fn writable_sections(&self) -> WithFlags<'slice, C, E> {
+        self.sections_with_flags(SectionFlags::WRITE)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfSectionHeaderTable<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// The result of resolving a section index with [`ElfSectionHeaderTable::get_checked`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SectionReference<'slice, C: ClassParse, E: EncodingParse> {
+    /// The index is [`SectionIndex::UNDEF`], referencing no section.
+    Undefined,
+    /// The index references an [`ElfSectionHeader`] of the table.
+    Section(ElfSectionHeader<'slice, C, E>),
+    /// The index is [`SectionIndex::ABS`], marking a value that is not affected by relocation.
+    Absolute,
+    /// The index is [`SectionIndex::COMMON`], marking an unallocated common symbol.
+    Common,
+    /// The index lies in the reserved range but has no meaning known to this crate.
+    Reserved(SectionIndex),
+    /// The index is neither reserved nor less than the number of entries in the table.
+    OutOfRange(u32),
+}
+
+/// Various errors that can occur while parsing an [`ElfSectionHeaderTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfSectionHeaderTableError {
+    /// The given slice was too small to contain the specified [`ElfSectionHeaderTable`].
+    SliceTooSmall,
+    /// An error occurred while parsing the [`ElfSectionHeader`] at `index`.
+    ParseElfSectionHeaderError {
+        /// The index of the [`ElfSectionHeader`] that parsing failed on.
+        index: usize,
+        /// The error that was returned.
+        error: ParseElfSectionHeaderError,
+    },
+}
+
+/// An iterator over the [`ElfSectionHeader`]s of an [`ElfSectionHeaderTable`].
+pub struct Iter<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfSectionHeaderTable`] being iterated over.
+    section_header_table: ElfSectionHeaderTable<'slice, C, E>,
+    /// The index of the next [`ElfSectionHeader`] to return from the front.
+    index: usize,
+    /// The index one past the next [`ElfSectionHeader`] to return from the back.
+    end: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for Iter<'slice, C, E> {
+    type Item = ElfSectionHeader<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+
+        let next = self.section_header_table.get(self.index)?;
+        self.index = self.index.checked_add(1)?;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.end);
+        self.next()
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> DoubleEndedIterator for Iter<'slice, C, E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+
+        self.end = self.end.checked_sub(1)?;
+        self.section_header_table.get(self.end)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ExactSizeIterator for Iter<'slice, C, E> {}
+
+impl<'slice, C: ClassParse, E: EncodingParse> FusedIterator for Iter<'slice, C, E> {}
+
+/// An iterator over the [`ElfSectionHeader`]s of an [`ElfSectionHeaderTable`] that have all of
+/// the requested [`SectionFlags`] set.
+pub struct WithFlags<'slice, C: ClassParse, E: EncodingParse> {
+    /// The underlying iterator over all [`ElfSectionHeader`]s.
+    iter: Iter<'slice, C, E>,
+    /// The [`SectionFlags`] that must be set.
+    mask: SectionFlags,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for WithFlags<'slice, C, E> {
+    type Item = ElfSectionHeader<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask = self.mask;
+        self.iter
+            .find(|section_header| section_header.flags().contains(mask))
+    }
+}
+
+/// An iterator over the distinct, non-empty producer strings of a `.comment` section.
+///
+/// A string is skipped if an identical string appears earlier in the section.
+#[derive(Clone, Debug)]
+pub struct Comments<'slice> {
+    /// The bytes of the `.comment` section.
+    slice: &'slice [u8],
+    /// The underlying iterator over all strings of the `.comment` section.
+    iter: elf_string_table::Iter<'slice>,
+}
+
+impl<'slice> Iterator for Comments<'slice> {
+    type Item = &'slice [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (offset, string) = self.iter.next()?;
+            let preceding = usize::try_from(offset)
+                .ok()
+                .and_then(|offset| self.slice.get(..offset))?;
+            if string.is_empty()
+                || preceding
+                    .split(|&byte| byte == 0)
+                    .any(|earlier| earlier == string)
+            {
+                continue;
+            }
+
+            return Some(string);
+        }
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns an iterator over the [`ElfSectionHeader`]s of this [`ElfFile`], which is empty if
+    /// this [`ElfFile`] has no [`ElfSectionHeaderTable`].
+    pub fn sections(&self) -> Iter<'slice, C, E> {
+        self.section_header_table()
+            .unwrap_or(ElfSectionHeaderTable {
+                slice: &[],
+                entry_count: 0,
+                entry_size: 0,
+                class: self.class,
+                encoding: self.encoding,
+            })
+            .iter()
+    }
+
+    /// Returns the first [`ElfSectionHeader`] whose name is `name`.
+    pub fn section_by_name(&self, name: &[u8]) -> Option<ElfSectionHeader<'slice, C, E>> {
+        let names = self.section_name_string_table()?;
+        self.section_header_table()?
+            .iter()
+            .find(|section_header| section_header.name_str(names) == Ok(name))
+    }
+
+    /// Returns the `.text` section, falling back to the first allocated
+    /// [`SectionType::PROGBITS`] section containing executable instructions.
+    pub fn text_section(&self) -> Option<ElfSectionHeader<'slice, C, E>> {
+        self.section_by_name(b".text").or_else(|| {
+            self.section_header_table()?
+                .sections_with_flags(SectionFlags(
+                    SectionFlags::ALLOC.0 | SectionFlags::EXECINSTR.0,
+                ))
+                .find(|section_header| section_header.kind() == SectionType::PROGBITS)
+        })
+    }
+
+    /// Returns the `.rodata` section.
+    pub fn rodata_section(&self) -> Option<ElfSectionHeader<'slice, C, E>> {
+        self.section_by_name(b".rodata")
+    }
+
+    /// Returns the `.bss` section, falling back to the first writable, allocated
+    /// [`SectionType::NOBITS`] section that does not hold thread-local storage.
+    pub fn bss_section(&self) -> Option<ElfSectionHeader<'slice, C, E>> {
+        self.section_by_name(b".bss").or_else(|| {
+            self.section_header_table()?
+                .sections_with_flags(SectionFlags(SectionFlags::ALLOC.0 | SectionFlags::WRITE.0))
+                .find(|section_header| {
+                    section_header.kind() == SectionType::NOBITS
+                        && !section_header.flags().contains(SectionFlags::TLS)
+                })
+        })
+    }
+
+    /// Returns the `.got` section, falling back to the `.got.plt` section.
+    pub fn got_section(&self) -> Option<ElfSectionHeader<'slice, C, E>> {
+        self.section_by_name(b".got")
+            .or_else(|| self.section_by_name(b".got.plt"))
+    }
+
+    /// Returns the `.plt` section, falling back to the `.plt.sec` section.
+    pub fn plt_section(&self) -> Option<ElfSectionHeader<'slice, C, E>> {
+        self.section_by_name(b".plt")
+            .or_else(|| self.section_by_name(b".plt.sec"))
+    }
+
+    /// Returns an iterator over the producer strings, such as compiler versions, recorded in the
+    /// `.comment` section.
+    ///
+    /// Each string is yielded once, in order of first appearance.
+    pub fn comments(&self) -> Option<Comments<'slice>> {
+        let slice = self.section_bytes(self.section_by_name(b".comment")?)?;
+        Some(Comments {
+            slice,
+            iter: ElfStringTable::new(slice).iter(),
+        })
+    }
+
+    /// Returns the [`ElfStringTable`] holding the names of the sections, as located by
+    /// [`ElfHeader::section_header_string_table_index`][index].
+    ///
+    /// [index]: crate::elf_header::ElfHeader::section_header_string_table_index
+    pub fn section_name_string_table(&self) -> Option<ElfStringTable<'slice>> {
+        let section_header = self.section_header_table()?.get(
+            self.header()
+                .real_section_header_string_table_index()?
+                .try_into()
+                .ok()?,
+        )?;
+
+        self.section_bytes(section_header).map(ElfStringTable::new)
+    }
+
+    /// Returns the bytes of the file described by `section_header`, or [`None`] if they are not
+    /// located inside of the file.
+    pub(crate) fn section_bytes(
+        &self,
+        section_header: ElfSectionHeader<'slice, C, E>,
+    ) -> Option<&'slice [u8]> {
+        section_header.data(self).ok()
+    }
+}
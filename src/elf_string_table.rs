@@ -0,0 +1,120 @@
+//! Definitions and interfaces for interacting with ELF string tables.
+
+use core::{ffi::CStr, fmt};
+
+/// A table of NUL-terminated strings, referenced by byte offsets from the start of the table.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfStringTable<'slice> {
+    /// The bytes that make up the [`ElfStringTable`].
+    pub(crate) slice: &'slice [u8],
+}
+
+impl<'slice> ElfStringTable<'slice> {
+    /// Creates a new [`ElfStringTable`] from the bytes in `slice`.
+    pub fn new(slice: &'slice [u8]) -> Self {
+        Self { slice }
+    }
+
+    /// Returns the bytes of the string located at `offset`, not including the NUL terminator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfStringTableError::OffsetOutOfBounds`] if `offset` is not located inside the
+    /// [`ElfStringTable`] and [`ElfStringTableError::MissingNulTerminator`] if the string is not
+    /// terminated before the end of the [`ElfStringTable`].
+    pub fn get(&self, offset: u64) -> Result<&'slice [u8], ElfStringTableError> {
+        let cstr = self.get_cstr(offset)?;
+        Ok(cstr.to_bytes())
+    }
+
+    /// Returns the string located at `offset` as a [`str`], not including the NUL terminator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfStringTableError::OffsetOutOfBounds`] if `offset` is not located inside the
+    /// [`ElfStringTable`], [`ElfStringTableError::MissingNulTerminator`] if the string is not
+    /// terminated before the end of the [`ElfStringTable`], and
+    /// [`ElfStringTableError::InvalidUtf8`] if the string is not valid UTF-8.
+    pub fn get_str(&self, offset: u64) -> Result<&'slice str, ElfStringTableError> {
+        let cstr = self.get_cstr(offset)?;
+        cstr.to_str().map_err(|_| ElfStringTableError::InvalidUtf8)
+    }
+
+    /// Returns the string located at `offset` as a [`CStr`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfStringTableError::OffsetOutOfBounds`] if `offset` is not located inside the
+    /// [`ElfStringTable`] and [`ElfStringTableError::MissingNulTerminator`] if the string is not
+    /// terminated before the end of the [`ElfStringTable`].
+    pub fn get_cstr(&self, offset: u64) -> Result<&'slice CStr, ElfStringTableError> {
+        let offset: usize = offset
+            .try_into()
+            .map_err(|_| ElfStringTableError::OffsetOutOfBounds)?;
+        let bytes = self
+            .slice
+            .get(offset..)
+            .ok_or(ElfStringTableError::OffsetOutOfBounds)?;
+
+        CStr::from_bytes_until_nul(bytes).map_err(|_| ElfStringTableError::MissingNulTerminator)
+    }
+
+    /// Returns an iterator over the offset and bytes of every NUL-terminated string in this
+    /// [`ElfStringTable`], in order of increasing offset.
+    ///
+    /// Trailing bytes that are not terminated by a NUL byte are not yielded.
+    pub fn iter(&self) -> Iter<'slice> {
+        Iter {
+            slice: self.slice,
+            offset: 0,
+        }
+    }
+
+    /// Returns the raw bytes that make up this [`ElfStringTable`].
+    pub fn as_bytes(&self) -> &'slice [u8] {
+        self.slice
+    }
+}
+
+impl<'slice> fmt::Debug for ElfStringTable<'slice> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElfStringTable")
+            .field("size", &self.slice.len())
+            .finish()
+    }
+}
+
+/// Various errors that can occur while retrieving a string from an [`ElfStringTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ElfStringTableError {
+    /// The requested offset is not located inside the [`ElfStringTable`].
+    OffsetOutOfBounds,
+    /// The requested string is not terminated by a NUL byte before the end of the
+    /// [`ElfStringTable`].
+    MissingNulTerminator,
+    /// The requested string is not valid UTF-8.
+    InvalidUtf8,
+}
+
+/// An iterator over the strings of an [`ElfStringTable`].
+#[derive(Clone, Debug)]
+pub struct Iter<'slice> {
+    /// The bytes of the [`ElfStringTable`] being iterated over.
+    slice: &'slice [u8],
+    /// The offset of the next string to return.
+    offset: usize,
+}
+
+impl<'slice> Iterator for Iter<'slice> {
+    type Item = (u64, &'slice [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.slice.get(self.offset..)?;
+        let length = remaining.iter().position(|&byte| byte == 0)?;
+        let string = remaining.get(..length)?;
+
+        let offset = self.offset;
+        self.offset = offset.checked_add(length)?.checked_add(1)?;
+        Some((offset as u64, string))
+    }
+}
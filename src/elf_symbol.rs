@@ -0,0 +1,815 @@
+//! Definitions and interfaces for interacting with ELF symbols and symbol tables.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, mem, ops::Range, str};
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_section_header::ElfSectionHeader,
+    elf_string_table::{ElfStringTable, ElfStringTableError},
+    encoding::EncodingParse,
+    raw::{
+        elf_section_header::{SectionIndex, SectionType},
+        elf_symbol::{
+            Elf32Symbol, Elf64Symbol, SymbolBinding as RawSymbolBinding, SymbolInfo,
+            SymbolType as RawSymbolType, SymbolVisibility as RawSymbolVisibility,
+        },
+    },
+    ElfFile, TableIndexError,
+};
+
+/// An entry of an [`ElfSymbolTable`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfSymbol<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfSymbol`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ElfStringTable`] holding the name of the [`ElfSymbol`].
+    pub(crate) strings: ElfStringTable<'slice>,
+    /// The [`ClassParse`] used to parse the [`ElfSymbol`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfSymbol`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSymbol<'slice, C, E> {
+    /// Returns the offset into the associated [`ElfStringTable`] at which the name of the
+    /// [`ElfSymbol`] is located, or zero if the [`ElfSymbol`] has no name.
+    pub fn name_offset(&self) -> u32 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Symbol, name), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64Symbol, name), self.slice),
+        }
+    }
+
+    /// Returns the name of the [`ElfSymbol`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfStringTableError`] if the name could not be retrieved from the associated
+    /// [`ElfStringTable`].
+    pub fn name(&self) -> Result<&'slice [u8], ElfStringTableError> {
+        self.strings.get(self.name_offset().into())
+    }
+
+    /// Returns the value of the [`ElfSymbol`], which is a virtual address in executable and
+    /// shared object files.
+    pub fn value(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Symbol, value), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Symbol, value), self.slice),
+        }
+    }
+
+    /// Returns the size of the [`ElfSymbol`], or zero if it has no size or an unknown size.
+    pub fn size(&self) -> u64 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf32Symbol, size), self.slice)
+                .into(),
+            Class::Class64 => self
+                .encoding
+                .parse_u64_at(mem::offset_of!(Elf64Symbol, size), self.slice),
+        }
+    }
+
+    /// Returns the [`SymbolInfo`] of the [`ElfSymbol`].
+    pub fn info(&self) -> SymbolInfo {
+        let info = match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u8_at(mem::offset_of!(Elf32Symbol, info), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u8_at(mem::offset_of!(Elf64Symbol, info), self.slice),
+        };
+
+        SymbolInfo(info)
+    }
+
+    /// Returns the [`SymbolType`] of the [`ElfSymbol`].
+    pub fn symbol_type(&self) -> SymbolType {
+        SymbolType::from(self.info().symbol_type())
+    }
+
+    /// Returns the [`SymbolBinding`] of the [`ElfSymbol`].
+    pub fn binding(&self) -> SymbolBinding {
+        SymbolBinding::from(self.info().binding())
+    }
+
+    /// Returns the `other` field of the [`ElfSymbol`], which currently holds its visibility.
+    pub fn other(&self) -> u8 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u8_at(mem::offset_of!(Elf32Symbol, other), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u8_at(mem::offset_of!(Elf64Symbol, other), self.slice),
+        }
+    }
+
+    /// Returns the [`SymbolVisibility`] of the [`ElfSymbol`], which is stored in the low bits of
+    /// its `other` field.
+    pub fn visibility(&self) -> SymbolVisibility {
+        SymbolVisibility::from(RawSymbolVisibility(self.other()))
+    }
+
+    /// Returns the index of the section in relation to which the [`ElfSymbol`] is defined.
+    pub fn section_index(&self) -> u16 {
+        match self.class.into_class() {
+            Class::Class32 => self
+                .encoding
+                .parse_u16_at(mem::offset_of!(Elf32Symbol, section_index), self.slice),
+            Class::Class64 => self
+                .encoding
+                .parse_u16_at(mem::offset_of!(Elf64Symbol, section_index), self.slice),
+        }
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> fmt::Debug for ElfSymbol<'slice, C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("ElfSymbol");
+
+        match self.name() {
+            Ok(name) => match str::from_utf8(name) {
+                Ok(name) => debug_struct.field("name", &name),
+                Err(_) => debug_struct.field("name", &name),
+            },
+            Err(error) => debug_struct.field("name", &error),
+        };
+        debug_struct.field("value", &self.value());
+        debug_struct.field("size", &self.size());
+        debug_struct.field("symbol_type", &self.symbol_type());
+        debug_struct.field("binding", &self.binding());
+        debug_struct.field("visibility", &self.visibility());
+        debug_struct.field("section_index", &self.section_index());
+
+        debug_struct.finish()
+    }
+}
+
+/// The linkage visibility and behavior of an [`ElfSymbol`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SymbolBinding {
+    /// The symbol is not visible outside of the ELF file that defines it.
+    Local,
+    /// The symbol is visible to all ELF files being combined.
+    Global,
+    /// The symbol is a lower priority [`SymbolBinding::Global`] symbol.
+    Weak,
+    /// A binding in the range reserved for operating system-specific semantics.
+    OsSpecific(u8),
+    /// A binding in the range reserved for processor-specific semantics.
+    ProcessorSpecific(u8),
+    /// A binding that is not defined by the ELF specification.
+    Unknown(u8),
+}
+
+impl From<RawSymbolBinding> for SymbolBinding {
+    fn from(value: RawSymbolBinding) -> Self {
+        match value {
+            RawSymbolBinding::LOCAL => Self::Local,
+            RawSymbolBinding::GLOBAL => Self::Global,
+            RawSymbolBinding::WEAK => Self::Weak,
+            RawSymbolBinding(binding)
+                if (RawSymbolBinding::OS_SPECIFIC_START.0
+                    ..=RawSymbolBinding::OS_SPECIFIC_END.0)
+                    .contains(&binding) =>
+            {
+                Self::OsSpecific(binding)
+            }
+            RawSymbolBinding(binding)
+                if (RawSymbolBinding::PROCESSOR_SPECIFIC_START.0
+                    ..=RawSymbolBinding::PROCESSOR_SPECIFIC_END.0)
+                    .contains(&binding) =>
+            {
+                Self::ProcessorSpecific(binding)
+            }
+            RawSymbolBinding(binding) => Self::Unknown(binding),
+        }
+    }
+}
+
+impl From<SymbolBinding> for RawSymbolBinding {
+    fn from(value: SymbolBinding) -> Self {
+        match value {
+            SymbolBinding::Local => Self::LOCAL,
+            SymbolBinding::Global => Self::GLOBAL,
+            SymbolBinding::Weak => Self::WEAK,
+            SymbolBinding::OsSpecific(binding)
+            | SymbolBinding::ProcessorSpecific(binding)
+            | SymbolBinding::Unknown(binding) => Self(binding),
+        }
+    }
+}
+
+/// The type of an [`ElfSymbol`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SymbolType {
+    /// The symbol's type is not specified.
+    NoType,
+    /// The symbol is associated with a data object.
+    Object,
+    /// The symbol is associated with a function or other code.
+    Function,
+    /// The symbol is associated with a section.
+    Section,
+    /// The symbol's name gives the name of the source file associated with the object file.
+    File,
+    /// The symbol labels an uninitialized common block.
+    Common,
+    /// The symbol specifies a thread-local storage entity.
+    Tls,
+    /// A type in the range reserved for operating system-specific semantics.
+    OsSpecific(u8),
+    /// A type in the range reserved for processor-specific semantics.
+    ProcessorSpecific(u8),
+    /// A type that is not defined by the ELF specification.
+    Unknown(u8),
+}
+
+impl From<RawSymbolType> for SymbolType {
+    fn from(value: RawSymbolType) -> Self {
+        match value {
+            RawSymbolType::NO_TYPE => Self::NoType,
+            RawSymbolType::OBJECT => Self::Object,
+            RawSymbolType::FUNCTION => Self::Function,
+            RawSymbolType::SECTION => Self::Section,
+            RawSymbolType::FILE => Self::File,
+            RawSymbolType::COMMON => Self::Common,
+            RawSymbolType::TLS => Self::Tls,
+            RawSymbolType(symbol_type)
+                if (RawSymbolType::OS_SPECIFIC_START.0..=RawSymbolType::OS_SPECIFIC_END.0)
+                    .contains(&symbol_type) =>
+            {
+                Self::OsSpecific(symbol_type)
+            }
+            RawSymbolType(symbol_type)
+                if (RawSymbolType::PROCESSOR_SPECIFIC_START.0
+                    ..=RawSymbolType::PROCESSOR_SPECIFIC_END.0)
+                    .contains(&symbol_type) =>
+            {
+                Self::ProcessorSpecific(symbol_type)
+            }
+            RawSymbolType(symbol_type) => Self::Unknown(symbol_type),
+        }
+    }
+}
+
+impl From<SymbolType> for RawSymbolType {
+    fn from(value: SymbolType) -> Self {
+        match value {
+            SymbolType::NoType => Self::NO_TYPE,
+            SymbolType::Object => Self::OBJECT,
+            SymbolType::Function => Self::FUNCTION,
+            SymbolType::Section => Self::SECTION,
+            SymbolType::File => Self::FILE,
+            SymbolType::Common => Self::COMMON,
+            SymbolType::Tls => Self::TLS,
+            SymbolType::OsSpecific(symbol_type)
+            | SymbolType::ProcessorSpecific(symbol_type)
+            | SymbolType::Unknown(symbol_type) => Self(symbol_type),
+        }
+    }
+}
+
+/// The visibility of an [`ElfSymbol`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SymbolVisibility {
+    /// The visibility is as specified by the [`SymbolBinding`] of the symbol.
+    Default,
+    /// Defined by processor supplements to further constrain hidden symbols.
+    Internal,
+    /// The symbol's name is not visible to other ELF files.
+    Hidden,
+    /// The symbol is not preemptable within the defining ELF file, but is still visible outside
+    /// of the defining ELF file.
+    Protected,
+}
+
+impl From<RawSymbolVisibility> for SymbolVisibility {
+    /// Converts the low two bits of `value`, which are the only bits that encode visibility.
+    fn from(value: RawSymbolVisibility) -> Self {
+        match RawSymbolVisibility(value.0 & 0x3) {
+            RawSymbolVisibility::DEFAULT => Self::Default,
+            RawSymbolVisibility::INTERNAL => Self::Internal,
+            RawSymbolVisibility::HIDDEN => Self::Hidden,
+            _ => Self::Protected,
+        }
+    }
+}
+
+impl From<SymbolVisibility> for RawSymbolVisibility {
+    fn from(value: SymbolVisibility) -> Self {
+        match value {
+            SymbolVisibility::Default => Self::DEFAULT,
+            SymbolVisibility::Internal => Self::INTERNAL,
+            SymbolVisibility::Hidden => Self::HIDDEN,
+            SymbolVisibility::Protected => Self::PROTECTED,
+        }
+    }
+}
+
+/// A table of [`ElfSymbol`]s.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfSymbolTable<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes that make up the [`ElfSymbolTable`].
+    pub(crate) slice: &'slice [u8],
+    /// The [`ElfStringTable`] holding the names of the [`ElfSymbol`]s.
+    pub(crate) strings: ElfStringTable<'slice>,
+    /// The [`ClassParse`] used to parse the [`ElfSymbolTable`].
+    pub(crate) class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfSymbolTable`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfSymbolTable<'slice, C, E> {
+    /// Parses an [`ElfSymbolTable`] from the provided `slice`, whose [`ElfSymbol`]s are named by
+    /// the strings in `strings`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfSymbolTableError::SizeNotMultipleOfEntrySize`] if `slice` does not
+    /// consist of a whole number of [`ElfSymbol`]s.
+    pub fn parse(
+        slice: &'slice [u8],
+        strings: ElfStringTable<'slice>,
+        class: C,
+        encoding: E,
+    ) -> Result<Self, ParseElfSymbolTableError> {
+        let symbol_table = Self {
+            slice,
+            strings,
+            class,
+            encoding,
+        };
+        if slice.len().checked_rem(symbol_table.entry_size()) != Some(0) {
+            return Err(ParseElfSymbolTableError::SizeNotMultipleOfEntrySize);
+        }
+
+        Ok(symbol_table)
+    }
+
+    /// Returns the [`ElfSymbol`] located at `index`.
+    pub fn get(&self, index: usize) -> Option<ElfSymbol<'slice, C, E>> {
+        self.try_get(index).ok()
+    }
+
+    /// Returns the [`ElfSymbol`] located at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableIndexError::IndexOutOfBounds`] if `index` is not less than the number of
+    /// entries and [`TableIndexError::OffsetOverflow`] if computing the offset of the entry
+    /// overflows.
+    pub fn try_get(&self, index: usize) -> Result<ElfSymbol<'slice, C, E>, TableIndexError> {
+        if index >= self.len() {
+            return Err(TableIndexError::IndexOutOfBounds);
+        }
+
+        let offset = index
+            .checked_mul(self.entry_size())
+            .ok_or(TableIndexError::OffsetOverflow)?;
+        let slice = self
+            .slice
+            .get(offset..)
+            .ok_or(TableIndexError::SliceOutOfBounds)?;
+
+        Ok(ElfSymbol {
+            slice,
+            strings: self.strings,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Returns the number of [`ElfSymbol`]s in the [`ElfSymbolTable`].
+    pub fn len(&self) -> usize {
+        self.slice.len().checked_div(self.entry_size()).unwrap_or(0)
+    }
+
+    /// Returns `true` if the [`ElfSymbolTable`] contains no [`ElfSymbol`]s.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the [`ElfSymbol`]s of this [`ElfSymbolTable`].
+    pub fn iter(&self) -> Iter<'slice, C, E> {
+        Iter {
+            symbol_table: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns the size of a single [`ElfSymbol`].
+    fn entry_size(&self) -> usize {
+        match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Symbol>(),
+            Class::Class64 => mem::size_of::<Elf64Symbol>(),
+        }
+    }
+}
+
+/// Various errors that can occur while parsing an [`ElfSymbolTable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfSymbolTableError {
+    /// The size of the given slice is not a multiple of the size of an [`ElfSymbol`].
+    SizeNotMultipleOfEntrySize,
+}
+
+/// An iterator over the [`ElfSymbol`]s of an [`ElfSymbolTable`].
+#[derive(Clone)]
+pub struct Iter<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfSymbolTable`] being iterated over.
+    symbol_table: ElfSymbolTable<'slice, C, E>,
+    /// The index of the next [`ElfSymbol`] to return.
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for Iter<'slice, C, E> {
+    type Item = ElfSymbol<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.symbol_table.get(self.index)?;
+        self.index = self.index.checked_add(1)?;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.symbol_table.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ExactSizeIterator for Iter<'slice, C, E> {}
+
+/// An iterator over the [`ElfSymbol`]s of the [`SectionType::SYMTAB`] and
+/// [`SectionType::DYNSYM`] sections of an [`ElfFile`], returned by [`ElfFile::merged_symbols`].
+///
+/// All [`ElfSymbol`]s of the [`SectionType::SYMTAB`] section are yielded first, followed by the
+/// [`ElfSymbol`]s of the [`SectionType::DYNSYM`] section that do not share both their name and
+/// their value with an [`ElfSymbol`] of the [`SectionType::SYMTAB`] section.
+///
+/// Each [`ElfSymbol`] of the [`SectionType::DYNSYM`] section is checked against every
+/// [`ElfSymbol`] of the [`SectionType::SYMTAB`] section, so a full iteration takes time
+/// proportional to the product of the sizes of both sections. [`ElfFile::merged_symbol`] looks up
+/// a single [`ElfSymbol`] by name without iterating.
+pub struct MergedSymbols<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfSymbolTable`] described by the [`SectionType::SYMTAB`] section.
+    symbol_table: Option<ElfSymbolTable<'slice, C, E>>,
+    /// The iterator over the [`ElfSymbol`]s of the [`SectionType::SYMTAB`] section.
+    symbols: Option<Iter<'slice, C, E>>,
+    /// The iterator over the [`ElfSymbol`]s of the [`SectionType::DYNSYM`] section.
+    dynamic_symbols: Option<Iter<'slice, C, E>>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for MergedSymbols<'slice, C, E> {
+    type Item = ElfSymbol<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(symbol) = self.symbols.as_mut().and_then(Iterator::next) {
+            return Some(symbol);
+        }
+
+        let symbol_table = self.symbol_table;
+        self.dynamic_symbols.as_mut()?.find(|dynamic_symbol| {
+            let Some(symbol_table) = symbol_table else {
+                return true;
+            };
+
+            !symbol_table.iter().any(|symbol| {
+                symbol.value() == dynamic_symbol.value() && symbol.name() == dynamic_symbol.name()
+            })
+        })
+    }
+}
+
+/// An index of [`ElfSymbol`]s sorted by value, used to find the symbol nearest to an address in
+/// logarithmic time.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct SymbolAddressIndex<'slice, C: ClassParse, E: EncodingParse> {
+    /// The indexed [`ElfSymbol`]s, sorted by value.
+    symbols: Vec<ElfSymbol<'slice, C, E>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'slice, C: ClassParse, E: EncodingParse> SymbolAddressIndex<'slice, C, E> {
+    /// Builds a [`SymbolAddressIndex`] over the [`SymbolType::Function`] and
+    /// [`SymbolType::Object`] symbols of `symbols` that are defined relative to a section.
+    pub fn new<I: IntoIterator<Item = ElfSymbol<'slice, C, E>>>(symbols: I) -> Self {
+        let mut symbols: Vec<_> = symbols
+            .into_iter()
+            .filter(|symbol| {
+                matches!(
+                    symbol.symbol_type(),
+                    SymbolType::Function | SymbolType::Object
+                ) && !matches!(
+                    SectionIndex(symbol.section_index()),
+                    SectionIndex::UNDEF | SectionIndex::ABS
+                )
+            })
+            .collect();
+        symbols.sort_by_key(ElfSymbol::value);
+
+        Self { symbols }
+    }
+
+    /// Returns the [`ElfSymbol`] with the greatest value that is less than or equal to `address`.
+    ///
+    /// If several [`ElfSymbol`]s share that value, the one indexed last is returned.
+    pub fn nearest_symbol(&self, address: u64) -> Option<ElfSymbol<'slice, C, E>> {
+        let index = self
+            .symbols
+            .partition_point(|symbol| symbol.value() <= address);
+        self.symbols.get(index.checked_sub(1)?).copied()
+    }
+
+    /// Returns the number of [`ElfSymbol`]s in the [`SymbolAddressIndex`].
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns `true` if the [`SymbolAddressIndex`] contains no [`ElfSymbol`]s.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+/// An index of [`ElfSymbol`]s grouped by the section in which they are defined, each group sorted
+/// by value.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct SectionSymbolIndex<'slice, C: ClassParse, E: EncodingParse> {
+    /// The indexed [`ElfSymbol`]s, sorted by section index and then by value.
+    symbols: Vec<ElfSymbol<'slice, C, E>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'slice, C: ClassParse, E: EncodingParse> SectionSymbolIndex<'slice, C, E> {
+    /// Builds a [`SectionSymbolIndex`] over the symbols of `symbols` that are defined relative to
+    /// a section.
+    pub fn new<I: IntoIterator<Item = ElfSymbol<'slice, C, E>>>(symbols: I) -> Self {
+        let mut symbols: Vec<_> = symbols
+            .into_iter()
+            .filter(|symbol| {
+                let index = SectionIndex(symbol.section_index());
+                index != SectionIndex::UNDEF && !index.is_reserved()
+            })
+            .collect();
+        symbols.sort_by_key(|symbol| (symbol.section_index(), symbol.value()));
+
+        Self { symbols }
+    }
+
+    /// Returns the [`ElfSymbol`]s defined in the section located at `section_index`, sorted by
+    /// value.
+    pub fn symbols_in(&self, section_index: u16) -> &[ElfSymbol<'slice, C, E>] {
+        let start = self
+            .symbols
+            .partition_point(|symbol| symbol.section_index() < section_index);
+        let end = self
+            .symbols
+            .partition_point(|symbol| symbol.section_index() <= section_index);
+
+        self.symbols.get(start..end).unwrap_or(&[])
+    }
+
+    /// Returns the number of [`ElfSymbol`]s in the [`SectionSymbolIndex`].
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns `true` if the [`SectionSymbolIndex`] contains no [`ElfSymbol`]s.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+/// A function symbol together with the range of virtual addresses its code occupies.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct FunctionExtent<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfSymbol`] describing the function.
+    pub symbol: ElfSymbol<'slice, C, E>,
+    /// The range of virtual addresses occupied by the function.
+    pub range: Range<u64>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`ElfSymbolTable`] described by the [`SectionType::SYMTAB`] section, or
+    /// [`None`] if there is no such section or its entry size is invalid.
+    pub fn symbol_table(&self) -> Option<ElfSymbolTable<'slice, C, E>> {
+        self.symbol_table_of_type(SectionType::SYMTAB)
+    }
+
+    /// Returns the [`ElfSymbolTable`] described by the [`SectionType::DYNSYM`] section, or
+    /// [`None`] if there is no such section or its entry size is invalid.
+    pub fn dynamic_symbol_table(&self) -> Option<ElfSymbolTable<'slice, C, E>> {
+        self.symbol_table_of_type(SectionType::DYNSYM)
+    }
+
+    /// Returns an iterator over the [`ElfSymbol`]s of both the [`SectionType::SYMTAB`] and
+    /// [`SectionType::DYNSYM`] sections, deduplicated by name and value.
+    ///
+    /// When the same symbol appears in both sections, the entry of the [`SectionType::SYMTAB`]
+    /// section is preferred. See [`MergedSymbols`] for the cost of deduplication.
+    pub fn merged_symbols(&self) -> MergedSymbols<'slice, C, E> {
+        let symbol_table = self.symbol_table();
+        MergedSymbols {
+            symbol_table,
+            symbols: symbol_table.map(|symbol_table| symbol_table.iter()),
+            dynamic_symbols: self
+                .dynamic_symbol_table()
+                .map(|dynamic_symbol_table| dynamic_symbol_table.iter()),
+        }
+    }
+
+    /// Returns the defined [`ElfSymbol`] named `name` from either the [`SectionType::DYNSYM`] or
+    /// the [`SectionType::SYMTAB`] section.
+    ///
+    /// Dynamic symbols are looked up through the [`ElfGnuHashTable`][gnu] or, failing that, the
+    /// [`ElfHashTable`][sysv] referenced by the dynamic array, so only names that are not
+    /// exported dynamically require a linear scan of the [`SectionType::SYMTAB`] section.
+    ///
+    /// [gnu]: crate::elf_hash::ElfGnuHashTable
+    /// [sysv]: crate::elf_hash::ElfHashTable
+    pub fn merged_symbol(&self, name: &[u8]) -> Option<ElfSymbol<'slice, C, E>> {
+        self.lookup_symbol_gnu(name)
+            .or_else(|| self.lookup_symbol(name))
+            .or_else(|| {
+                self.symbol_table()?.iter().find(|symbol| {
+                    SectionIndex(symbol.section_index()) != SectionIndex::UNDEF
+                        && symbol.name() == Ok(name)
+                })
+            })
+    }
+
+    /// Builds a [`SymbolAddressIndex`] over the [`ElfSymbol`]s returned by
+    /// [`ElfFile::merged_symbols`].
+    #[cfg(feature = "alloc")]
+    pub fn symbol_address_index(&self) -> SymbolAddressIndex<'slice, C, E> {
+        SymbolAddressIndex::new(self.merged_symbols())
+    }
+
+    /// Builds a [`SectionSymbolIndex`] over the [`ElfSymbol`]s returned by
+    /// [`ElfFile::merged_symbols`].
+    #[cfg(feature = "alloc")]
+    pub fn section_symbol_index(&self) -> SectionSymbolIndex<'slice, C, E> {
+        SectionSymbolIndex::new(self.merged_symbols())
+    }
+
+    /// Returns the range of virtual addresses occupied by the function named `name`.
+    ///
+    /// Functions with a size of zero are assumed to extend up to the next symbol defined in the
+    /// same section, or to the end of that section if no such symbol exists.
+    pub fn function_range(&self, name: &[u8]) -> Option<Range<u64>> {
+        let symbol_table = self.function_symbol_table()?;
+        let symbol = function_symbols(symbol_table).find(|symbol| symbol.name() == Ok(name))?;
+
+        Some(self.function_extent(symbol_table, symbol))
+    }
+
+    /// Returns the [`FunctionExtent`] of the function whose code contains the virtual address
+    /// `address`.
+    ///
+    /// Functions with a size of zero are assumed to extend up to the next symbol defined in the
+    /// same section, or to the end of that section if no such symbol exists.
+    pub fn function_containing(&self, address: u64) -> Option<FunctionExtent<'slice, C, E>> {
+        let symbol_table = self.function_symbol_table()?;
+        function_symbols(symbol_table)
+            .filter(|symbol| symbol.value() <= address)
+            .map(|symbol| FunctionExtent {
+                symbol,
+                range: self.function_extent(symbol_table, symbol),
+            })
+            .find(|extent| extent.range.contains(&address))
+    }
+
+    /// Returns the [`SymbolType::Function`] or [`SymbolType::Object`] [`ElfSymbol`] whose
+    /// `[value, value + size)` range contains the virtual address `address`.
+    ///
+    /// If no such [`ElfSymbol`] exists, the one with the greatest value that is less than or
+    /// equal to `address` is returned instead.
+    pub fn symbol_for_address(&self, address: u64) -> Option<ElfSymbol<'slice, C, E>> {
+        let symbol_table = self.function_symbol_table()?;
+        let candidates = || {
+            symbol_table.iter().filter(|symbol| {
+                matches!(
+                    symbol.symbol_type(),
+                    SymbolType::Function | SymbolType::Object
+                ) && !matches!(
+                    SectionIndex(symbol.section_index()),
+                    SectionIndex::UNDEF | SectionIndex::ABS
+                ) && symbol.value() <= address
+            })
+        };
+
+        candidates()
+            .find(|symbol| address < symbol.value().saturating_add(symbol.size()))
+            .or_else(|| candidates().max_by_key(ElfSymbol::value))
+    }
+
+    /// Returns the [`ElfSymbolTable`] used to look up functions, preferring the
+    /// [`SectionType::SYMTAB`] section as it usually holds more symbols.
+    fn function_symbol_table(&self) -> Option<ElfSymbolTable<'slice, C, E>> {
+        self.symbol_table().or_else(|| self.dynamic_symbol_table())
+    }
+
+    /// Returns the range of virtual addresses occupied by the function described by `symbol`.
+    fn function_extent(
+        &self,
+        symbol_table: ElfSymbolTable<'slice, C, E>,
+        symbol: ElfSymbol<'slice, C, E>,
+    ) -> Range<u64> {
+        let start = symbol.value();
+        if symbol.size() != 0 {
+            return start..start.saturating_add(symbol.size());
+        }
+
+        let next_symbol = symbol_table
+            .iter()
+            .filter(|other| {
+                other.section_index() == symbol.section_index()
+                    && other.symbol_type() != SymbolType::Section
+                    && other.value() > start
+            })
+            .map(|other| other.value())
+            .min();
+        let section_end = || {
+            if SectionIndex(symbol.section_index()).is_reserved() {
+                return None;
+            }
+
+            let section_header = self
+                .section_header_table()?
+                .get(symbol.section_index().into())?;
+            section_header
+                .address()
+                .checked_add(section_header.size())
+                .filter(|&end| end > start)
+        };
+
+        start..next_symbol.or_else(section_end).unwrap_or(start)
+    }
+
+    /// Returns the [`ElfSymbolTable`] described by the first section of type `kind`.
+    fn symbol_table_of_type(&self, kind: SectionType) -> Option<ElfSymbolTable<'slice, C, E>> {
+        let section_header_table = self.section_header_table()?;
+        let section_header = section_header_table
+            .iter()
+            .find(|section_header| section_header.kind() == kind)?;
+
+        self.symbol_table_in(section_header)
+    }
+
+    /// Returns the [`ElfSymbolTable`] stored in `section_header`, whose names are located in the
+    /// string table referenced by its link.
+    ///
+    /// Returns [`None`] if the entry size recorded by `section_header` does not match the size of
+    /// an [`ElfSymbol`].
+    pub(crate) fn symbol_table_in(
+        &self,
+        section_header: ElfSectionHeader<'slice, C, E>,
+    ) -> Option<ElfSymbolTable<'slice, C, E>> {
+        if section_header
+            .expected_entry_size()
+            .is_some_and(|expected| section_header.entry_size() != expected)
+        {
+            return None;
+        }
+
+        let string_table_header = self
+            .section_header_table()?
+            .get(section_header.link().try_into().ok()?)?;
+
+        ElfSymbolTable::parse(
+            self.section_bytes(section_header)?,
+            ElfStringTable::new(self.section_bytes(string_table_header)?),
+            self.class,
+            self.encoding,
+        )
+        .ok()
+    }
+}
+
+/// Returns an iterator over the defined [`SymbolType::Function`] symbols of `symbol_table`.
+fn function_symbols<'slice, C: ClassParse, E: EncodingParse>(
+    symbol_table: ElfSymbolTable<'slice, C, E>,
+) -> impl Iterator<Item = ElfSymbol<'slice, C, E>> {
+    symbol_table.iter().filter(|symbol| {
+        symbol.symbol_type() == SymbolType::Function
+            && symbol.section_index() != SectionIndex::UNDEF.0
+    })
+}
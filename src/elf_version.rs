@@ -0,0 +1,285 @@
+//! Definitions and interfaces for interacting with GNU symbol versioning.
+
+use core::mem;
+
+use crate::{
+    class::ClassParse,
+    elf_string_table::ElfStringTable,
+    elf_symbol::{self, ElfSymbol},
+    encoding::EncodingParse,
+    raw::{
+        elf_section_header::SectionType,
+        elf_version::{Elf64Verdaux, Elf64Verdef, Elf64Vernaux, Elf64Verneed, Versym},
+    },
+    ElfFile,
+};
+
+/// The version of an [`ElfSymbol`], resolved through the [`SectionType::GNU_VERDEF`] or
+/// [`SectionType::GNU_VERNEED`] sections.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct SymbolVersion<'slice> {
+    /// The version index of the [`SymbolVersion`], without the [`Versym::HIDDEN`] bit.
+    pub index: u16,
+    /// The name of the version.
+    pub name: &'slice [u8],
+    /// The name of the file the version is required from, or [`None`] if the version is defined
+    /// by the ELF file itself.
+    pub file: Option<&'slice [u8]>,
+    /// Whether the symbol is hidden, which means that it is not the default version of the
+    /// symbol.
+    pub hidden: bool,
+}
+
+/// The version definitions and version requirements of an [`ElfFile`].
+///
+/// The layout of the entries does not depend on the class of the [`ElfFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ElfVersionTables<'slice, E: EncodingParse> {
+    /// The bytes of the [`SectionType::GNU_VERDEF`] section.
+    pub(crate) definitions: &'slice [u8],
+    /// The bytes of the [`SectionType::GNU_VERNEED`] section.
+    pub(crate) requirements: &'slice [u8],
+    /// The [`ElfStringTable`] holding the names of the versions and files.
+    pub(crate) strings: ElfStringTable<'slice>,
+    /// The [`EncodingParse`] used to parse the [`ElfVersionTables`].
+    pub(crate) encoding: E,
+}
+
+impl<'slice, E: EncodingParse> ElfVersionTables<'slice, E> {
+    /// Creates a new [`ElfVersionTables`] from the bytes of the version definition and version
+    /// requirement sections, whose names are located in `strings`.
+    pub fn new(
+        definitions: &'slice [u8],
+        requirements: &'slice [u8],
+        strings: ElfStringTable<'slice>,
+        encoding: E,
+    ) -> Self {
+        Self {
+            definitions,
+            requirements,
+            strings,
+            encoding,
+        }
+    }
+
+    /// Returns the [`SymbolVersion`] that `versym` refers to.
+    ///
+    /// Returns [`None`] if `versym` is [`Versym::LOCAL`] or [`Versym::GLOBAL`], or if no version
+    /// with its index could be found.
+    pub fn resolve(&self, versym: Versym) -> Option<SymbolVersion<'slice>> {
+        let index = versym.index();
+        if index == Versym::LOCAL.0 || index == Versym::GLOBAL.0 {
+            return None;
+        }
+
+        let (name, file) = self
+            .definition_name(index)
+            .map(|name| (name, None))
+            .or_else(|| {
+                self.requirement_name(index)
+                    .map(|(name, file)| (name, Some(file)))
+            })?;
+
+        Some(SymbolVersion {
+            index,
+            name,
+            file,
+            hidden: versym.is_hidden(),
+        })
+    }
+
+    /// Returns the name of the version defined with `index`.
+    fn definition_name(&self, index: u16) -> Option<&'slice [u8]> {
+        let mut offset = 0usize;
+        loop {
+            let entry = self
+                .definitions
+                .get(offset..offset.checked_add(mem::size_of::<Elf64Verdef>())?)?;
+            let entry_index = self
+                .encoding
+                .parse_u16_at(mem::offset_of!(Elf64Verdef, index), entry);
+            if entry_index == index {
+                let auxiliary_offset = offset.checked_add(
+                    self.encoding
+                        .parse_u32_at(mem::offset_of!(Elf64Verdef, auxiliary_offset), entry)
+                        .try_into()
+                        .ok()?,
+                )?;
+                let auxiliary = self.definitions.get(
+                    auxiliary_offset
+                        ..auxiliary_offset.checked_add(mem::size_of::<Elf64Verdaux>())?,
+                )?;
+                let name = self
+                    .encoding
+                    .parse_u32_at(mem::offset_of!(Elf64Verdaux, name), auxiliary);
+                return self.strings.get(name.into()).ok();
+            }
+
+            let next_offset = self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64Verdef, next_offset), entry);
+            if next_offset == 0 {
+                return None;
+            }
+            offset = offset.checked_add(next_offset.try_into().ok()?)?;
+        }
+    }
+
+    /// Returns the name of the version required with `index` and the name of the file it is
+    /// required from.
+    fn requirement_name(&self, index: u16) -> Option<(&'slice [u8], &'slice [u8])> {
+        let mut offset = 0usize;
+        loop {
+            let entry = self
+                .requirements
+                .get(offset..offset.checked_add(mem::size_of::<Elf64Verneed>())?)?;
+            let auxiliary_count = self
+                .encoding
+                .parse_u16_at(mem::offset_of!(Elf64Verneed, auxiliary_count), entry);
+
+            let mut auxiliary_offset = offset.checked_add(
+                self.encoding
+                    .parse_u32_at(mem::offset_of!(Elf64Verneed, auxiliary_offset), entry)
+                    .try_into()
+                    .ok()?,
+            )?;
+            for _ in 0..auxiliary_count {
+                let auxiliary = self.requirements.get(
+                    auxiliary_offset
+                        ..auxiliary_offset.checked_add(mem::size_of::<Elf64Vernaux>())?,
+                )?;
+                let other = self
+                    .encoding
+                    .parse_u16_at(mem::offset_of!(Elf64Vernaux, other), auxiliary);
+                if other == index {
+                    let name = self
+                        .encoding
+                        .parse_u32_at(mem::offset_of!(Elf64Vernaux, name), auxiliary);
+                    let file = self
+                        .encoding
+                        .parse_u32_at(mem::offset_of!(Elf64Verneed, file), entry);
+                    return Some((
+                        self.strings.get(name.into()).ok()?,
+                        self.strings.get(file.into()).ok()?,
+                    ));
+                }
+
+                let next_offset = self
+                    .encoding
+                    .parse_u32_at(mem::offset_of!(Elf64Vernaux, next_offset), auxiliary);
+                if next_offset == 0 {
+                    break;
+                }
+                auxiliary_offset = auxiliary_offset.checked_add(next_offset.try_into().ok()?)?;
+            }
+
+            let next_offset = self
+                .encoding
+                .parse_u32_at(mem::offset_of!(Elf64Verneed, next_offset), entry);
+            if next_offset == 0 {
+                return None;
+            }
+            offset = offset.checked_add(next_offset.try_into().ok()?)?;
+        }
+    }
+}
+
+/// An [`ElfSymbol`] together with its resolved [`SymbolVersion`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct VersionedSymbol<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfSymbol`].
+    pub symbol: ElfSymbol<'slice, C, E>,
+    /// The [`SymbolVersion`] of the [`ElfSymbol`], or [`None`] if the symbol is unversioned.
+    pub version: Option<SymbolVersion<'slice>>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`ElfVersionTables`] described by the [`SectionType::GNU_VERDEF`] and
+    /// [`SectionType::GNU_VERNEED`] sections, or [`None`] if there are no such sections.
+    pub fn version_tables(&self) -> Option<ElfVersionTables<'slice, E>> {
+        let section_header_table = self.section_header_table()?;
+        let section = |kind: SectionType| {
+            section_header_table
+                .iter()
+                .find(|section_header| section_header.kind() == kind)
+        };
+
+        let definitions = section(SectionType::GNU_VERDEF);
+        let requirements = section(SectionType::GNU_VERNEED);
+        let string_table_header =
+            section_header_table.get(definitions.or(requirements)?.link().try_into().ok()?)?;
+        let bytes = |section_header: Option<_>| {
+            section_header
+                .and_then(|section_header| self.section_bytes(section_header))
+                .unwrap_or(&[])
+        };
+
+        Some(ElfVersionTables::new(
+            bytes(definitions),
+            bytes(requirements),
+            ElfStringTable::new(self.section_bytes(string_table_header)?),
+            self.encoding,
+        ))
+    }
+
+    /// Returns an iterator over the [`ElfSymbol`]s of the [`SectionType::DYNSYM`] section, each
+    /// joined with the [`SymbolVersion`] recorded for it by the [`SectionType::GNU_VERSYM`]
+    /// section.
+    pub fn versioned_dynamic_symbols(&self) -> Option<VersionedSymbols<'slice, C, E>> {
+        let symbols = self.dynamic_symbol_table()?.iter();
+        let versions = self
+            .section_header_table()?
+            .iter()
+            .find(|section_header| section_header.kind() == SectionType::GNU_VERSYM)
+            .and_then(|section_header| self.section_bytes(section_header))
+            .unwrap_or(&[]);
+
+        Some(VersionedSymbols {
+            symbols,
+            versions,
+            tables: self.version_tables(),
+            index: 0,
+        })
+    }
+}
+
+/// An iterator over the [`VersionedSymbol`]s of an [`ElfFile`].
+#[derive(Clone)]
+pub struct VersionedSymbols<'slice, C: ClassParse, E: EncodingParse> {
+    /// The iterator over the [`ElfSymbol`]s being versioned.
+    symbols: elf_symbol::Iter<'slice, C, E>,
+    /// The bytes of the [`SectionType::GNU_VERSYM`] section.
+    versions: &'slice [u8],
+    /// The [`ElfVersionTables`] used to resolve the [`SymbolVersion`]s.
+    tables: Option<ElfVersionTables<'slice, E>>,
+    /// The index of the next [`ElfSymbol`].
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for VersionedSymbols<'slice, C, E> {
+    type Item = VersionedSymbol<'slice, C, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = self.symbols.next()?;
+        let offset = self.index.checked_mul(mem::size_of::<Versym>());
+        self.index = self.index.saturating_add(1);
+
+        let version = offset
+            .and_then(|offset| {
+                self.versions
+                    .get(offset..offset.checked_add(mem::size_of::<Versym>())?)
+            })
+            .zip(self.tables)
+            .and_then(|(versym, tables)| {
+                tables.resolve(Versym(symbol.encoding.parse_u16_at(0, versym)))
+            });
+
+        Some(VersionedSymbol { symbol, version })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.symbols.size_hint()
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ExactSizeIterator for VersionedSymbols<'slice, C, E> {}
@@ -54,6 +54,76 @@ pub trait EncodingParse: Clone + Copy + PartialEq + Eq {
     ///
     /// Panics if an arithmetic or bounds overflow error occurs.
     fn parse_i64_at(self, offset: usize, data: &[u8]) -> i64;
+
+    /// Stores `value` at `offset` bytes from the start of `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an arithmetic or bounds overflow error occurs.
+    fn write_u8_at(self, offset: usize, value: u8, data: &mut [u8]) {
+        write_bytes_at(offset, &[value], data);
+    }
+
+    /// Stores `value` at `offset` bytes from the start of `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an arithmetic or bounds overflow error occurs.
+    fn write_u16_at(self, offset: usize, value: u16, data: &mut [u8]) {
+        let bytes = match self.into_encoding() {
+            Encoding::TwosComplementLittleEndian => value.to_le_bytes(),
+            Encoding::TwosComplementBigEndian => value.to_be_bytes(),
+        };
+        write_bytes_at(offset, &bytes, data);
+    }
+
+    /// Stores `value` at `offset` bytes from the start of `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an arithmetic or bounds overflow error occurs.
+    fn write_u32_at(self, offset: usize, value: u32, data: &mut [u8]) {
+        let bytes = match self.into_encoding() {
+            Encoding::TwosComplementLittleEndian => value.to_le_bytes(),
+            Encoding::TwosComplementBigEndian => value.to_be_bytes(),
+        };
+        write_bytes_at(offset, &bytes, data);
+    }
+
+    /// Stores `value` at `offset` bytes from the start of `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an arithmetic or bounds overflow error occurs.
+    fn write_u64_at(self, offset: usize, value: u64, data: &mut [u8]) {
+        let bytes = match self.into_encoding() {
+            Encoding::TwosComplementLittleEndian => value.to_le_bytes(),
+            Encoding::TwosComplementBigEndian => value.to_be_bytes(),
+        };
+        write_bytes_at(offset, &bytes, data);
+    }
+}
+
+/// Copies `bytes` to `offset` bytes from the start of `data`.
+///
+/// # Panics
+///
+/// Panics if an arithmetic or bounds overflow error occurs.
+fn write_bytes_at(offset: usize, bytes: &[u8], data: &mut [u8]) {
+    let data_len = data.len();
+    let byte_after = offset
+        .checked_add(bytes.len())
+        .expect("`offset + size` overflowed");
+    let Some(destination) = data.get_mut(offset..byte_after) else {
+        panic!(
+            "attempted write of {} bytes at an offset of {} bytes to {} byte buffer",
+            bytes.len(),
+            offset,
+            data_len,
+        )
+    };
+
+    destination.copy_from_slice(bytes);
 }
 
 /// Indicates how the ELF file should be parsed with respect to differences in the encoding of
@@ -95,7 +165,7 @@ macro_rules! setup_func {
             let byte_after = offset
                 .checked_add(mem::size_of::<$kind>())
                 .expect("`offset + size` overflowed");
-            if byte_after >= data.len() {
+            if byte_after > data.len() {
                 if mem::size_of::<$kind>() != 1 {
                     panic!(
                         "attempted read of {} bytes at an offset of {} bytes from {} byte buffer",
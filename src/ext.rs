@@ -0,0 +1,133 @@
+//! Extension points for interpreting operating system-specific and processor-specific values
+//! outside of this crate.
+//!
+//! The generic formatters of [`display`][crate::display] consult these extensions before falling
+//! back to the values known to this crate, which allows support for niche operating systems and
+//! architectures to live in downstream crates. Formatters of an entire
+//! [`ElfFile`][crate::ElfFile] only consult an extension whose [`OsAbiExt::os_abi`] or
+//! [`MachineExt::machine`] matches the header of the file.
+
+use crate::raw::{
+    elf_dynamic::ElfDynamicTag,
+    elf_header::Machine,
+    elf_ident::OsAbi,
+    elf_program_header::SegmentType,
+    elf_section_header::{SectionFlags, SectionIndex, SectionType},
+    elf_symbol::{SymbolBinding, SymbolType},
+};
+
+/// Interprets the values an ELF file reserves for the operating system identified by
+/// [`OsAbiExt::os_abi`].
+///
+/// Every method has a default implementation that interprets nothing, so implementors only need
+/// to provide the values they know about.
+pub trait OsAbiExt {
+    /// Returns the [`OsAbi`] whose values this extension interprets.
+    fn os_abi(&self) -> OsAbi;
+
+    /// Returns the name of the operating system-specific `segment_type`.
+    fn segment_type_name(&self, segment_type: SegmentType) -> Option<&'static str> {
+        let _ = segment_type;
+        None
+    }
+
+    /// Returns the name of the operating system-specific `section_type`.
+    fn section_type_name(&self, section_type: SectionType) -> Option<&'static str> {
+        let _ = section_type;
+        None
+    }
+
+    /// Returns the name of the operating system-specific dynamic `tag`.
+    fn dynamic_tag_name(&self, tag: ElfDynamicTag) -> Option<&'static str> {
+        let _ = tag;
+        None
+    }
+
+    /// Returns the name of notes of type `kind` owned by the entity named `owner`.
+    fn note_type_name(&self, owner: &[u8], kind: u32) -> Option<&'static str> {
+        let _ = (owner, kind);
+        None
+    }
+
+    /// Returns the name of the operating system-specific `symbol_type`.
+    fn symbol_type_name(&self, symbol_type: SymbolType) -> Option<&'static str> {
+        let _ = symbol_type;
+        None
+    }
+
+    /// Returns the name of the operating system-specific symbol `binding`.
+    fn symbol_binding_name(&self, binding: SymbolBinding) -> Option<&'static str> {
+        let _ = binding;
+        None
+    }
+
+    /// Returns the name of the operating system-specific reserved section `index`.
+    fn section_index_name(&self, index: SectionIndex) -> Option<&'static str> {
+        let _ = index;
+        None
+    }
+}
+
+/// Returns the first of `extensions` that interprets values for `os_abi`.
+pub fn select_os_abi_ext<'ext>(
+    os_abi: OsAbi,
+    extensions: &[&'ext dyn OsAbiExt],
+) -> Option<&'ext dyn OsAbiExt> {
+    extensions
+        .iter()
+        .copied()
+        .find(|extension| extension.os_abi() == os_abi)
+}
+
+/// Interprets the values an ELF file reserves for the processor identified by
+/// [`MachineExt::machine`].
+///
+/// Every method has a default implementation that interprets nothing, so implementors only need
+/// to provide the values they know about.
+pub trait MachineExt {
+    /// Returns the [`Machine`] whose values this extension interprets.
+    fn machine(&self) -> Machine;
+
+    /// Returns the name of the processor-specific `segment_type`.
+    fn segment_type_name(&self, segment_type: SegmentType) -> Option<&'static str> {
+        let _ = segment_type;
+        None
+    }
+
+    /// Returns the name of the processor-specific `section_type`.
+    fn section_type_name(&self, section_type: SectionType) -> Option<&'static str> {
+        let _ = section_type;
+        None
+    }
+
+    /// Returns the letter used to display the processor-specific section `flag`, which has
+    /// exactly one bit set.
+    fn section_flag_letter(&self, flag: SectionFlags) -> Option<char> {
+        let _ = flag;
+        None
+    }
+
+    /// Returns the name of the relocation type `relocation_type`.
+    fn relocation_type_name(&self, relocation_type: u32) -> Option<&'static str> {
+        let _ = relocation_type;
+        None
+    }
+
+    /// Returns the name of the processor-specific reserved section `index`, such as a special
+    /// common section.
+    fn section_index_name(&self, index: SectionIndex) -> Option<&'static str> {
+        let _ = index;
+        None
+    }
+}
+
+/// Returns the first of `extensions` that interprets values for `machine`.
+pub fn select_machine_ext<'ext>(
+    machine: Machine,
+    extensions: &[&'ext dyn MachineExt],
+) -> Option<&'ext dyn MachineExt> {
+    extensions
+        .iter()
+        .copied()
+        .find(|extension| extension.machine() == machine)
+}
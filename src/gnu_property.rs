@@ -0,0 +1,182 @@
+//! Definitions and interfaces for interacting with GNU program properties.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_note,
+    encoding::EncodingParse,
+    raw::{
+        elf_note::{ELF_NOTE_GNU, NT_GNU_PROPERTY_TYPE_0},
+        elf_program_header::SegmentType,
+        gnu_property::{Aarch64Feature1, GnuPropertyType, X86Feature1},
+    },
+    ElfFile,
+};
+
+/// A single program property of an [`NT_GNU_PROPERTY_TYPE_0`] note.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct GnuProperty<'slice, E: EncodingParse> {
+    /// The type of the property.
+    pub kind: GnuPropertyType,
+    /// The data of the property, whose interpretation depends on [`GnuProperty::kind`].
+    pub data: &'slice [u8],
+    /// The [`EncodingParse`] used to parse the data of the property.
+    encoding: E,
+}
+
+impl<'slice, E: EncodingParse> GnuProperty<'slice, E> {
+    /// Returns the [`X86Feature1`] flags of a [`GnuPropertyType::X86_FEATURE_1_AND`] property.
+    pub fn x86_feature_1_and(&self) -> Option<X86Feature1> {
+        if self.kind != GnuPropertyType::X86_FEATURE_1_AND {
+            return None;
+        }
+
+        self.u32_data().map(X86Feature1)
+    }
+
+    /// Returns the [`Aarch64Feature1`] flags of a [`GnuPropertyType::AARCH64_FEATURE_1_AND`]
+    /// property.
+    pub fn aarch64_feature_1_and(&self) -> Option<Aarch64Feature1> {
+        if self.kind != GnuPropertyType::AARCH64_FEATURE_1_AND {
+            return None;
+        }
+
+        self.u32_data().map(Aarch64Feature1)
+    }
+
+    /// Returns the data of the property interpreted as a single [`u32`].
+    fn u32_data(&self) -> Option<u32> {
+        if self.data.len() != mem::size_of::<u32>() {
+            return None;
+        }
+
+        Some(self.encoding.parse_u32_at(0, self.data))
+    }
+}
+
+/// The program properties stored in the descriptor of an [`NT_GNU_PROPERTY_TYPE_0`] note.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct GnuProperties<'slice, C: ClassParse, E: EncodingParse> {
+    /// The descriptor of the note.
+    slice: &'slice [u8],
+    /// The [`ClassParse`] that determines the alignment of each property.
+    class: C,
+    /// The [`EncodingParse`] used to parse the properties.
+    encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> GnuProperties<'slice, C, E> {
+    /// Creates a new [`GnuProperties`] from the descriptor of an [`NT_GNU_PROPERTY_TYPE_0`]
+    /// note.
+    pub fn new(descriptor: &'slice [u8], class: C, encoding: E) -> Self {
+        Self {
+            slice: descriptor,
+            class,
+            encoding,
+        }
+    }
+
+    /// Returns an iterator over the [`GnuProperty`]s of this [`GnuProperties`].
+    ///
+    /// Iteration stops at the first property that does not fit inside of the descriptor.
+    pub fn iter(&self) -> GnuPropertyIter<'slice, E> {
+        let alignment = match self.class.into_class() {
+            Class::Class32 => mem::size_of::<u32>(),
+            Class::Class64 => mem::size_of::<u64>(),
+        };
+
+        GnuPropertyIter {
+            slice: self.slice,
+            alignment,
+            encoding: self.encoding,
+        }
+    }
+
+    /// Returns the [`X86Feature1`] flags recorded by the
+    /// [`GnuPropertyType::X86_FEATURE_1_AND`] property.
+    pub fn x86_feature_1_and(&self) -> Option<X86Feature1> {
+        self.iter()
+            .find_map(|property| property.x86_feature_1_and())
+    }
+
+    /// Returns the [`Aarch64Feature1`] flags recorded by the
+    /// [`GnuPropertyType::AARCH64_FEATURE_1_AND`] property.
+    pub fn aarch64_feature_1_and(&self) -> Option<Aarch64Feature1> {
+        self.iter()
+            .find_map(|property| property.aarch64_feature_1_and())
+    }
+}
+
+/// An iterator over the [`GnuProperty`]s of a [`GnuProperties`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct GnuPropertyIter<'slice, E: EncodingParse> {
+    /// The bytes of the properties that have not been iterated over yet.
+    slice: &'slice [u8],
+    /// The alignment of each property.
+    alignment: usize,
+    /// The [`EncodingParse`] used to parse the properties.
+    encoding: E,
+}
+
+impl<'slice, E: EncodingParse> Iterator for GnuPropertyIter<'slice, E> {
+    type Item = GnuProperty<'slice, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header_size = mem::size_of::<[u32; 2]>();
+        let property = (|| {
+            let header = self.slice.get(..header_size)?;
+            let kind = GnuPropertyType(self.encoding.parse_u32_at(0, header));
+            let data_size: usize = self
+                .encoding
+                .parse_u32_at(mem::size_of::<u32>(), header)
+                .try_into()
+                .ok()?;
+
+            let data_end = header_size.checked_add(data_size)?;
+            let data = self.slice.get(header_size..data_end)?;
+            let mask = self.alignment.checked_sub(1)?;
+            let next = (data_end.checked_add(mask)? & !mask).min(self.slice.len());
+
+            Some((
+                GnuProperty {
+                    kind,
+                    data,
+                    encoding: self.encoding,
+                },
+                next,
+            ))
+        })();
+
+        let Some((property, next)) = property else {
+            self.slice = &[];
+            return None;
+        };
+
+        self.slice = self.slice.get(next..).unwrap_or(&[]);
+        Some(property)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`GnuProperties`] recorded by the [`NT_GNU_PROPERTY_TYPE_0`] note of the
+    /// [`SegmentType::GNU_PROPERTY`] segment, falling back to the [`SegmentType::NOTE`]
+    /// segments.
+    pub fn gnu_properties(&self) -> Option<GnuProperties<'slice, C, E>> {
+        let mut segments = self
+            .segments_of_type(SegmentType::GNU_PROPERTY)
+            .chain(self.segments_of_type(SegmentType::NOTE));
+
+        let note = segments.find_map(|program_header| {
+            let data = program_header.file_data(self).ok()?;
+            elf_note::Iter::with_alignment(data, program_header.alignment(), self.encoding)
+                .find(|note| note.name == ELF_NOTE_GNU && note.kind == NT_GNU_PROPERTY_TYPE_0)
+        })?;
+
+        Some(GnuProperties::new(
+            note.descriptor,
+            self.class,
+            self.encoding,
+        ))
+    }
+}
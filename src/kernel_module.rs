@@ -0,0 +1,203 @@
+//! Definitions and interfaces for interacting with Linux kernel modules.
+
+use core::mem;
+
+use crate::{
+    class::ClassParse,
+    encoding::{BigEndian, EncodingParse},
+    raw::kernel_module::{ModuleSignatureIdType, ModuleSignatureInfo, MODULE_SIGNATURE_MAGIC},
+    ElfFile,
+};
+
+/// The signature appended to a Linux kernel module.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ModuleSignature<'slice> {
+    /// The contents of the kernel module that the signature covers, which is the module with the
+    /// signature stripped.
+    pub module: &'slice [u8],
+    /// The public-key crypto algorithm.
+    pub algorithm: u8,
+    /// The digest algorithm.
+    pub hash: u8,
+    /// The kind of key identifier.
+    pub id_type: ModuleSignatureIdType,
+    /// The name of the signer, which is empty for [`ModuleSignatureIdType::PKCS7`].
+    pub signer: &'slice [u8],
+    /// The key identifier, which is empty for [`ModuleSignatureIdType::PKCS7`].
+    pub key_id: &'slice [u8],
+    /// The signature.
+    pub signature: &'slice [u8],
+}
+
+impl<'slice> ModuleSignature<'slice> {
+    /// Parses the [`ModuleSignature`] appended to the kernel module stored in `file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseModuleSignatureError::NotSigned`] if `file` does not end with
+    /// [`MODULE_SIGNATURE_MAGIC`], and [`ParseModuleSignatureError::Truncated`] if the
+    /// [`ModuleSignatureInfo`] describes more data than precedes it.
+    pub fn parse(file: &'slice [u8]) -> Result<Self, ParseModuleSignatureError> {
+        let rest = file
+            .strip_suffix(MODULE_SIGNATURE_MAGIC)
+            .ok_or(ParseModuleSignatureError::NotSigned)?;
+
+        let info_offset = rest
+            .len()
+            .checked_sub(mem::size_of::<ModuleSignatureInfo>())
+            .ok_or(ParseModuleSignatureError::Truncated)?;
+        let (rest, info) = rest.split_at(info_offset);
+
+        let signature_length: usize = BigEndian
+            .parse_u32_at(mem::offset_of!(ModuleSignatureInfo, signature_length), info)
+            .try_into()
+            .map_err(|_| ParseModuleSignatureError::Truncated)?;
+        let signer_length: usize = BigEndian
+            .parse_u8_at(mem::offset_of!(ModuleSignatureInfo, signer_length), info)
+            .into();
+        let key_id_length: usize = BigEndian
+            .parse_u8_at(mem::offset_of!(ModuleSignatureInfo, key_id_length), info)
+            .into();
+
+        let (rest, signature) = split_tail(rest, signature_length)?;
+        let (rest, key_id) = split_tail(rest, key_id_length)?;
+        let (module, signer) = split_tail(rest, signer_length)?;
+
+        Ok(Self {
+            module,
+            algorithm: BigEndian.parse_u8_at(mem::offset_of!(ModuleSignatureInfo, algorithm), info),
+            hash: BigEndian.parse_u8_at(mem::offset_of!(ModuleSignatureInfo, hash), info),
+            id_type: ModuleSignatureIdType(
+                BigEndian.parse_u8_at(mem::offset_of!(ModuleSignatureInfo, id_type), info),
+            ),
+            signer,
+            key_id,
+            signature,
+        })
+    }
+
+    /// Returns the combined length of the signer's name and key identifier.
+    pub fn signer_info_length(&self) -> usize {
+        self.signer.len().saturating_add(self.key_id.len())
+    }
+}
+
+/// The contents of the `.modinfo` section of a kernel module, which holds NUL-separated
+/// `key=value` pairs.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ModInfo<'slice> {
+    /// The bytes that make up the [`ModInfo`].
+    slice: &'slice [u8],
+}
+
+impl<'slice> ModInfo<'slice> {
+    /// Creates a new [`ModInfo`] from the bytes of a `.modinfo` section.
+    pub fn new(slice: &'slice [u8]) -> Self {
+        Self { slice }
+    }
+
+    /// Returns an iterator over the [`ModInfoEntry`]s of this [`ModInfo`].
+    ///
+    /// Empty entries, such as those produced by alignment padding, and entries without a `=` are
+    /// skipped.
+    pub fn iter(&self) -> ModInfoIter<'slice> {
+        ModInfoIter { slice: self.slice }
+    }
+
+    /// Returns the value of the first entry whose key is `key`.
+    pub fn get(&self, key: &[u8]) -> Option<&'slice [u8]> {
+        self.iter()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.value)
+    }
+
+    /// Returns the version magic string the kernel module was built against.
+    pub fn vermagic(&self) -> Option<&'slice [u8]> {
+        self.get(b"vermagic")
+    }
+
+    /// Returns the license of the kernel module.
+    pub fn license(&self) -> Option<&'slice [u8]> {
+        self.get(b"license")
+    }
+
+    /// Returns an iterator over the names of the kernel modules this kernel module depends on.
+    pub fn depends(&self) -> impl Iterator<Item = &'slice [u8]> {
+        self.get(b"depends")
+            .unwrap_or_default()
+            .split(|&byte| byte == b',')
+            .filter(|name| !name.is_empty())
+    }
+}
+
+/// A single `key=value` pair of a [`ModInfo`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ModInfoEntry<'slice> {
+    /// The bytes preceding the first `=`.
+    pub key: &'slice [u8],
+    /// The bytes following the first `=`.
+    pub value: &'slice [u8],
+}
+
+/// An iterator over the [`ModInfoEntry`]s of a [`ModInfo`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ModInfoIter<'slice> {
+    /// The bytes that have not been iterated over yet.
+    slice: &'slice [u8],
+}
+
+impl<'slice> Iterator for ModInfoIter<'slice> {
+    type Item = ModInfoEntry<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.slice.is_empty() {
+            let end = self
+                .slice
+                .iter()
+                .position(|&byte| byte == 0)
+                .unwrap_or(self.slice.len());
+            let (entry, rest) = self.slice.split_at(end);
+            let rest = rest.get(1..).unwrap_or_default();
+            self.slice = rest;
+
+            let Some(separator) = entry.iter().position(|&byte| byte == b'=') else {
+                continue;
+            };
+            let (key, value) = entry.split_at(separator);
+            return Some(ModInfoEntry {
+                key,
+                value: value.get(1..).unwrap_or_default(),
+            });
+        }
+
+        None
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`ModInfo`] stored in the `.modinfo` section of this kernel module.
+    pub fn modinfo(&self) -> Option<ModInfo<'slice>> {
+        let section_header = self.section_by_name(b".modinfo")?;
+        self.section_bytes(section_header).map(ModInfo::new)
+    }
+}
+
+/// Various errors that can occur while parsing a [`ModuleSignature`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseModuleSignatureError {
+    /// The kernel module does not end with [`MODULE_SIGNATURE_MAGIC`].
+    NotSigned,
+    /// The kernel module is too small to hold the signature described by its
+    /// [`ModuleSignatureInfo`].
+    Truncated,
+}
+
+/// Splits the last `length` bytes off of `slice`.
+fn split_tail(slice: &[u8], length: usize) -> Result<(&[u8], &[u8]), ParseModuleSignatureError> {
+    let offset = slice
+        .len()
+        .checked_sub(length)
+        .ok_or(ParseModuleSignatureError::Truncated)?;
+
+    Ok(slice.split_at(offset))
+}
@@ -3,19 +3,50 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use crate::{
     class::ClassParse,
     elf_header::{ElfHeader, ParseElfHeaderError},
     elf_program_header::{ElfProgramHeaderTable, ParseElfProgramHeaderTableError},
+    elf_section_header::{ElfSectionHeaderTable, ParseElfSectionHeaderTableError},
     encoding::EncodingParse,
 };
 
+pub mod abi;
+#[cfg(feature = "alloc")]
+pub mod buf;
 pub mod class;
+pub mod core_dump;
+pub mod display;
+pub mod elf_auxv;
+pub mod elf_dynamic;
+pub mod elf_hash;
 pub mod elf_header;
 pub mod elf_ident;
+pub mod elf_note;
 pub mod elf_program_header;
+pub mod elf_relocation;
+pub mod elf_section_header;
+pub mod elf_string_table;
+pub mod elf_symbol;
+pub mod elf_version;
 pub mod encoding;
+pub mod ext;
+pub mod gnu_property;
+pub mod kernel_module;
+pub mod lint;
+pub mod loaded;
+pub mod loader;
+pub mod multiboot2;
+pub mod parser;
+pub mod prefix;
 pub mod raw;
+pub mod relocate;
+pub mod source;
+pub mod write;
+pub mod xen;
 
 /// An ELF file.
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
@@ -26,21 +57,62 @@ pub struct ElfFile<'slice, C: ClassParse, E: EncodingParse> {
 }
 
 impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
-    /// Parses an [`ElfFile`] from the provided `file`, checking various invariants
-    /// before returning.
+    /// Parses an [`ElfFile`] from the provided `file`, checking that the [`ElfHeader`] is valid
+    /// and that the [`ElfProgramHeaderTable`] and [`ElfSectionHeaderTable`] lie within `file`.
+    ///
+    /// The individual [`ElfProgramHeader`][ph]s are not validated, which allows tools that only
+    /// inspect the [`ElfHeader`] to skip that work. Use [`ElfFile::parse_and_validate`] or
+    /// [`ElfProgramHeaderTable::validate`] to check them up front, or
+    /// [`ElfProgramHeaderTable::get_validated`] and [`ElfProgramHeaderTable::iter_validated`] to
+    /// check each entry on access. The plain accessors stay unchecked, since segments such as
+    /// the [`SegmentType::NOTE`][note] segments of core dumps legitimately break the invariants
+    /// checked for loadable segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfFileError`] if the [`ElfHeader`] is invalid or if either header table
+    /// does not fit within `file`.
+    ///
+    /// [ph]: elf_program_header::ElfProgramHeader
+    /// [note]: raw::elf_program_header::SegmentType::NOTE
     pub fn parse(file: &'slice [u8]) -> Result<Self, ParseElfFileError> {
         let elf_header = ElfHeader::<C, E>::parse(file)?;
         if elf_header.program_header_count() != 0 {
-            if (file.len() as u64) < elf_header.program_header_offset() {
-                return Err(ParseElfFileError::ParseElfProgramHeaderTableError(
-                    ParseElfProgramHeaderTableError::SliceTooSmall,
-                ));
-            }
+            let program_header_offset: usize = elf_header
+                .program_header_offset()
+                .try_into()
+                .map_err(|_| ParseElfProgramHeaderTableError::SliceTooSmall)?;
+            let program_header_slice = file
+                .get(program_header_offset..)
+                .ok_or(ParseElfProgramHeaderTableError::SliceTooSmall)?;
 
-            ElfProgramHeaderTable::parse(
-                &file[elf_header.program_header_offset() as usize..],
-                elf_header.program_header_count() as usize,
-                elf_header.program_header_entry_size() as usize,
+            ElfProgramHeaderTable::from_raw_parts(
+                program_header_slice,
+                elf_header.program_header_count().into(),
+                elf_header.program_header_entry_size().into(),
+                elf_header.elf_ident().class_parse(),
+                elf_header.elf_ident().encoding_parse(),
+            )?;
+        }
+
+        let section_header_count = elf_header
+            .real_section_header_count()
+            .ok_or(ParseElfSectionHeaderTableError::SliceTooSmall)?;
+        if section_header_count != 0 {
+            let section_header_offset: usize = elf_header
+                .section_header_offset()
+                .try_into()
+                .map_err(|_| ParseElfSectionHeaderTableError::SliceTooSmall)?;
+            let section_header_slice = file
+                .get(section_header_offset..)
+                .ok_or(ParseElfSectionHeaderTableError::SliceTooSmall)?;
+
+            ElfSectionHeaderTable::parse(
+                section_header_slice,
+                section_header_count
+                    .try_into()
+                    .map_err(|_| ParseElfSectionHeaderTableError::SliceTooSmall)?,
+                elf_header.section_header_entry_size().into(),
                 elf_header.elf_ident().class_parse(),
                 elf_header.elf_ident().encoding_parse(),
             )?;
@@ -53,6 +125,24 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
         })
     }
 
+    /// Parses an [`ElfFile`] from the provided `file` like [`ElfFile::parse`], and additionally
+    /// validates every [`ElfProgramHeader`][ph] of the [`ElfProgramHeaderTable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfFileError`] if the [`ElfHeader`] is invalid, if either header table does
+    /// not fit within `file`, or if any [`ElfProgramHeader`][ph] is invalid.
+    ///
+    /// [ph]: elf_program_header::ElfProgramHeader
+    pub fn parse_and_validate(file: &'slice [u8]) -> Result<Self, ParseElfFileError> {
+        let elf_file = Self::parse(file)?;
+        if let Some(program_header_table) = elf_file.program_header_table() {
+            program_header_table.validate()?;
+        }
+
+        Ok(elf_file)
+    }
+
     /// Returns the [`ElfHeader`] of this [`ElfFile`].
     pub fn header(&self) -> ElfHeader<'slice, C, E> {
         ElfHeader {
@@ -62,16 +152,55 @@ impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
         }
     }
 
+    /// Returns the virtual address to which the system first transfers control, or [`None`] if
+    /// this [`ElfFile`] has no associated entry point.
+    pub fn entry_point(&self) -> Option<u64> {
+        let entry = self.header().entry();
+        if entry == 0 {
+            return None;
+        }
+
+        Some(entry)
+    }
+
+    /// Returns `true` if this [`ElfFile`] has an associated entry point.
+    pub fn has_entry(&self) -> bool {
+        self.entry_point().is_some()
+    }
+
     /// Returns the [`ElfProgramHeaderTable`] of this [`ElfFile`].
     pub fn program_header_table(&self) -> Option<ElfProgramHeaderTable<'slice, C, E>> {
         if self.header().program_header_count() == 0 {
             return None;
         }
 
+        let offset: usize = self.header().program_header_offset().try_into().ok()?;
         Some(ElfProgramHeaderTable {
-            slice: &self.slice[self.header().program_header_offset() as usize..],
-            entry_count: self.header().program_header_count() as usize,
-            entry_size: self.header().program_header_entry_size() as usize,
+            slice: self.slice.get(offset..)?,
+            entry_count: self.header().program_header_count().into(),
+            entry_size: self.header().program_header_entry_size().into(),
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Returns the [`ElfSectionHeaderTable`] of this [`ElfFile`].
+    pub fn section_header_table(&self) -> Option<ElfSectionHeaderTable<'slice, C, E>> {
+        let header = self.header();
+        let entry_count: usize = header.real_section_header_count()?.try_into().ok()?;
+        if entry_count == 0 {
+            return None;
+        }
+
+        let entry_size: usize = header.section_header_entry_size().into();
+        let offset: usize = header.section_header_offset().try_into().ok()?;
+        let size = entry_count.checked_mul(entry_size)?;
+        let end = offset.checked_add(size)?;
+
+        Some(ElfSectionHeaderTable {
+            slice: self.slice.get(offset..end)?,
+            entry_count,
+            entry_size,
             class: self.class,
             encoding: self.encoding,
         })
@@ -85,6 +214,8 @@ pub enum ParseElfFileError {
     ParseElfHeaderError(ParseElfHeaderError),
     /// An error ocurred while parsing the [`ElfProgramHeaderTable`].
     ParseElfProgramHeaderTableError(ParseElfProgramHeaderTableError),
+    /// An error ocurred while parsing the [`ElfSectionHeaderTable`].
+    ParseElfSectionHeaderTableError(ParseElfSectionHeaderTableError),
 }
 
 impl From<ParseElfHeaderError> for ParseElfFileError {
@@ -99,6 +230,23 @@ impl From<ParseElfProgramHeaderTableError> for ParseElfFileError {
     }
 }
 
+impl From<ParseElfSectionHeaderTableError> for ParseElfFileError {
+    fn from(value: ParseElfSectionHeaderTableError) -> Self {
+        Self::ParseElfSectionHeaderTableError(value)
+    }
+}
+
+/// Various errors that can occur while retrieving an entry from a table stored in an ELF file.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum TableIndexError {
+    /// The requested index is not less than the number of entries in the table.
+    IndexOutOfBounds,
+    /// The offset of the requested entry overflowed while being computed.
+    OffsetOverflow,
+    /// The requested entry is not located inside of the slice backing the table.
+    SliceOutOfBounds,
+}
+
 /// Obtains the size of the specfied filed, evaluated at const time.
 ///
 /// This only works for [`Sized`] types.
@@ -0,0 +1,689 @@
+//! Consistency checks that flag ELF files which parse successfully, but whose structures
+//! contradict each other, which usually indicates a broken linker or a tampered file.
+
+use core::{mem, ops::Range};
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_hash::{gnu_hash, sysv_hash, ElfGnuHashTable, ElfHashTable},
+    elf_symbol::ElfSymbolTable,
+    encoding::EncodingParse,
+    raw::{
+        elf_dynamic::{Elf32Dynamic, Elf64Dynamic, ElfDynamicTag},
+        elf_program_header::SegmentType,
+        elf_section_header::SectionType,
+        elf_version::{Elf64Verdaux, Elf64Verdef, Elf64Vernaux, Elf64Verneed},
+    },
+    ElfFile,
+};
+
+/// Verifies that the `.dynamic` section and the [`SegmentType::DYNAMIC`] segment of `file`
+/// describe the same bytes, and that both are sized in whole dynamic entries of the class of
+/// `file`.
+///
+/// Files without a section header table are only checked for the size of the
+/// [`SegmentType::DYNAMIC`] segment.
+///
+/// # Errors
+///
+/// Returns the first [`DynamicLintError`] found.
+pub fn check_dynamic<C: ClassParse, E: EncodingParse>(
+    file: ElfFile<C, E>,
+) -> Result<(), DynamicLintError> {
+    let expected_entry_size = match file.class.into_class() {
+        Class::Class32 => mem::size_of::<Elf32Dynamic>() as u64,
+        Class::Class64 => mem::size_of::<Elf64Dynamic>() as u64,
+    };
+
+    let segment = file.program_header_table().and_then(|table| {
+        table
+            .iter()
+            .find(|program_header| program_header.segment_type() == SegmentType::DYNAMIC)
+    });
+    let section = file.section_header_table().and_then(|table| {
+        table
+            .iter()
+            .find(|section_header| section_header.kind() == SectionType::DYNAMIC)
+    });
+
+    if let Some(segment) = segment {
+        if segment.file_size().checked_rem(expected_entry_size) != Some(0) {
+            return Err(DynamicLintError::SegmentSizeNotMultipleOfEntrySize {
+                size: segment.file_size(),
+                entry_size: expected_entry_size,
+            });
+        }
+    }
+
+    let Some(section) = section else {
+        if segment.is_some() && file.section_header_table().is_some() {
+            return Err(DynamicLintError::MissingSection);
+        }
+
+        return Ok(());
+    };
+
+    if section.entry_size() != expected_entry_size {
+        return Err(DynamicLintError::InvalidSectionEntrySize {
+            entry_size: section.entry_size(),
+            expected: expected_entry_size,
+        });
+    }
+
+    if section.size().checked_rem(expected_entry_size) != Some(0) {
+        return Err(DynamicLintError::SectionSizeNotMultipleOfEntrySize {
+            size: section.size(),
+            entry_size: expected_entry_size,
+        });
+    }
+
+    let Some(segment) = segment else {
+        return Err(DynamicLintError::MissingSegment);
+    };
+
+    let section_file_range = section.offset()..section.offset().saturating_add(section.size());
+    let segment_file_range =
+        segment.file_offset()..segment.file_offset().saturating_add(segment.file_size());
+    if section_file_range != segment_file_range {
+        return Err(DynamicLintError::FileRangeMismatch {
+            section: section_file_range,
+            segment: segment_file_range,
+        });
+    }
+
+    if section.address() != segment.virtual_address() {
+        return Err(DynamicLintError::AddressMismatch {
+            section: section.address(),
+            segment: segment.virtual_address(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Various inconsistencies between the `.dynamic` section and the [`SegmentType::DYNAMIC`]
+/// segment reported by [`check_dynamic`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum DynamicLintError {
+    /// The file has a [`SegmentType::DYNAMIC`] segment and a section header table, but no
+    /// [`SectionType::DYNAMIC`] section.
+    MissingSection,
+    /// The file has a [`SectionType::DYNAMIC`] section, but no [`SegmentType::DYNAMIC`] segment.
+    MissingSegment,
+    /// The entry size of the [`SectionType::DYNAMIC`] section does not match the size of a dynamic
+    /// entry of the file's class.
+    InvalidSectionEntrySize {
+        /// The entry size specified by the section.
+        entry_size: u64,
+        /// The size of a dynamic entry of the file's class.
+        expected: u64,
+    },
+    /// The size of the [`SectionType::DYNAMIC`] section is not a multiple of the size of a dynamic
+    /// entry.
+    SectionSizeNotMultipleOfEntrySize {
+        /// The size of the section.
+        size: u64,
+        /// The size of a dynamic entry of the file's class.
+        entry_size: u64,
+    },
+    /// The file size of the [`SegmentType::DYNAMIC`] segment is not a multiple of the size of a
+    /// dynamic entry.
+    SegmentSizeNotMultipleOfEntrySize {
+        /// The file size of the segment.
+        size: u64,
+        /// The size of a dynamic entry of the file's class.
+        entry_size: u64,
+    },
+    /// The section and the segment cover different bytes of the file.
+    FileRangeMismatch {
+        /// The bytes of the file covered by the section.
+        section: Range<u64>,
+        /// The bytes of the file covered by the segment.
+        segment: Range<u64>,
+    },
+    /// The section and the segment are located at different virtual addresses.
+    AddressMismatch {
+        /// The virtual address of the section.
+        section: u64,
+        /// The virtual address of the segment.
+        segment: u64,
+    },
+}
+
+/// Verifies that every [`SectionType::SYMTAB`], [`SectionType::DYNSYM`],
+/// [`SectionType::RELA`], [`SectionType::REL`] and [`SectionType::DYNAMIC`] section of `file`
+/// specifies the entry size of its table for the class of `file`, and that its size is a multiple
+/// of that entry size.
+///
+/// # Errors
+///
+/// Returns the first [`EntrySizeLintError`] found.
+pub fn check_entry_sizes<C: ClassParse, E: EncodingParse>(
+    file: ElfFile<C, E>,
+) -> Result<(), EntrySizeLintError> {
+    let Some(section_header_table) = file.section_header_table() else {
+        return Ok(());
+    };
+
+    for (index, section_header) in section_header_table.iter().enumerate() {
+        let Some(expected) = section_header.expected_entry_size() else {
+            continue;
+        };
+
+        if section_header.entry_size() != expected {
+            return Err(EntrySizeLintError::InvalidEntrySize {
+                index,
+                kind: section_header.kind(),
+                entry_size: section_header.entry_size(),
+                expected,
+            });
+        }
+
+        if section_header.size().checked_rem(expected) != Some(0) {
+            return Err(EntrySizeLintError::SizeNotMultipleOfEntrySize {
+                index,
+                kind: section_header.kind(),
+                size: section_header.size(),
+                entry_size: expected,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Various inconsistencies between the kind of a section and the sizing of its entries reported
+/// by [`check_entry_sizes`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum EntrySizeLintError {
+    /// The entry size specified by the section does not match the size of the entries its kind
+    /// requires.
+    InvalidEntrySize {
+        /// The index of the offending section.
+        index: usize,
+        /// The kind of the offending section.
+        kind: SectionType,
+        /// The entry size specified by the section.
+        entry_size: u64,
+        /// The entry size required by the kind of the section.
+        expected: u64,
+    },
+    /// The size of the section is not a multiple of the size of its entries.
+    SizeNotMultipleOfEntrySize {
+        /// The index of the offending section.
+        index: usize,
+        /// The kind of the offending section.
+        kind: SectionType,
+        /// The size of the section.
+        size: u64,
+        /// The entry size required by the kind of the section.
+        entry_size: u64,
+    },
+}
+
+/// Verifies that the chains of the [`SectionType::HASH`] and [`SectionType::GNU_HASH`] sections of
+/// `file` terminate within the bounds of the dynamic symbol table, and that every hashed dynamic
+/// symbol is reachable by looking up its name.
+///
+/// # Errors
+///
+/// Returns the first [`HashTableLintError`] found.
+pub fn check_hash_tables<C: ClassParse, E: EncodingParse>(
+    file: ElfFile<C, E>,
+) -> Result<(), HashTableLintError> {
+    let Some(section_header_table) = file.section_header_table() else {
+        return Ok(());
+    };
+    let section_data = |kind: SectionType| {
+        section_header_table
+            .iter()
+            .find(|section_header| section_header.kind() == kind)
+            .map(|section_header| (kind, file.section_bytes(section_header)))
+    };
+
+    if let Some((kind, data)) = section_data(SectionType::HASH) {
+        let hash_table = data
+            .and_then(|data| ElfHashTable::parse(data, file.encoding).ok())
+            .ok_or(HashTableLintError::Malformed { kind })?;
+        let symbol_table = file
+            .dynamic_symbol_table()
+            .ok_or(HashTableLintError::MissingDynamicSymbolTable { kind })?;
+        check_sysv_hash_table(hash_table, symbol_table)?;
+    }
+
+    if let Some((kind, data)) = section_data(SectionType::GNU_HASH) {
+        let hash_table = data
+            .and_then(|data| ElfGnuHashTable::parse(data, file.class, file.encoding).ok())
+            .ok_or(HashTableLintError::Malformed { kind })?;
+        let symbol_table = file
+            .dynamic_symbol_table()
+            .ok_or(HashTableLintError::MissingDynamicSymbolTable { kind })?;
+        check_gnu_hash_table(hash_table, symbol_table)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies the consistency of a [`SectionType::HASH`] section with the dynamic symbol table.
+fn check_sysv_hash_table<C: ClassParse, E: EncodingParse>(
+    hash_table: ElfHashTable<E>,
+    symbol_table: ElfSymbolTable<C, E>,
+) -> Result<(), HashTableLintError> {
+    let kind = SectionType::HASH;
+    let symbol_count = symbol_table.len() as u64;
+    if u64::from(hash_table.chain_count()) != symbol_count {
+        return Err(HashTableLintError::ChainCountMismatch {
+            chain_count: hash_table.chain_count(),
+            symbol_count,
+        });
+    }
+    if hash_table.bucket_count() == 0 {
+        return if symbol_count > 1 {
+            Err(HashTableLintError::NoBuckets { kind })
+        } else {
+            Ok(())
+        };
+    }
+
+    // Walks the chain starting at `bucket`, returning whether `target` was reached.
+    let walk = |bucket: u32, target: Option<u32>| {
+        let mut index = hash_table
+            .bucket(bucket)
+            .ok_or(HashTableLintError::Malformed { kind })?;
+        let mut steps = 0u32;
+        while index != 0 {
+            if Some(index) == target {
+                return Ok(true);
+            }
+
+            steps = steps.saturating_add(1);
+            if steps > hash_table.chain_count() {
+                return Err(HashTableLintError::ChainCycle { kind, bucket });
+            }
+
+            index = hash_table
+                .chain(index)
+                .ok_or(HashTableLintError::ChainOutOfBounds { kind, bucket })?;
+        }
+
+        Ok(false)
+    };
+
+    for bucket in 0..hash_table.bucket_count() {
+        walk(bucket, None)?;
+    }
+
+    for (index, symbol) in symbol_table.iter().enumerate().skip(1) {
+        let name = symbol
+            .name()
+            .map_err(|_| HashTableLintError::UnreachableSymbol { kind, index })?;
+        let bucket = sysv_hash(name)
+            .checked_rem(hash_table.bucket_count())
+            .ok_or(HashTableLintError::NoBuckets { kind })?;
+        let target = u32::try_from(index).ok();
+        if target.is_none() || !walk(bucket, target)? {
+            return Err(HashTableLintError::UnreachableSymbol { kind, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies the consistency of a [`SectionType::GNU_HASH`] section with the dynamic symbol table.
+fn check_gnu_hash_table<C: ClassParse, E: EncodingParse>(
+    hash_table: ElfGnuHashTable<C, E>,
+    symbol_table: ElfSymbolTable<C, E>,
+) -> Result<(), HashTableLintError> {
+    let kind = SectionType::GNU_HASH;
+    let symbol_count = symbol_table.len();
+    let symbol_offset = hash_table.symbol_offset();
+    if usize::try_from(symbol_offset).map_or(true, |offset| offset > symbol_count) {
+        return Err(HashTableLintError::SymbolOffsetOutOfBounds {
+            symbol_offset,
+            symbol_count,
+        });
+    }
+    if hash_table.bucket_count() == 0 {
+        return if usize::try_from(symbol_offset) == Ok(symbol_count) {
+            Ok(())
+        } else {
+            Err(HashTableLintError::NoBuckets { kind })
+        };
+    }
+
+    // Walks the chain starting at `bucket`, returning whether a symbol matching `target`, given
+    // as an index and a hash, was reached.
+    let walk = |bucket: u32, target: Option<(u32, u32)>| {
+        let mut index = hash_table
+            .bucket(bucket)
+            .ok_or(HashTableLintError::Malformed { kind })?;
+        if index == 0 {
+            return Ok(false);
+        }
+        if index < symbol_offset {
+            return Err(HashTableLintError::ChainOutOfBounds { kind, bucket });
+        }
+
+        loop {
+            if usize::try_from(index).map_or(true, |index| index >= symbol_count) {
+                return Err(HashTableLintError::ChainOutOfBounds { kind, bucket });
+            }
+
+            let chain = hash_table
+                .chain(index)
+                .ok_or(HashTableLintError::ChainOutOfBounds { kind, bucket })?;
+            if let Some((target_index, target_hash)) = target {
+                if index == target_index && (chain | 1) == (target_hash | 1) {
+                    return Ok(true);
+                }
+            }
+            if chain & 1 == 1 {
+                return Ok(false);
+            }
+
+            index = index
+                .checked_add(1)
+                .ok_or(HashTableLintError::ChainOutOfBounds { kind, bucket })?;
+        }
+    };
+
+    for bucket in 0..hash_table.bucket_count() {
+        walk(bucket, None)?;
+    }
+
+    for (index, symbol) in symbol_table.iter().enumerate().skip(symbol_offset as usize) {
+        let name = symbol
+            .name()
+            .map_err(|_| HashTableLintError::UnreachableSymbol { kind, index })?;
+        let hash = gnu_hash(name);
+        let bucket = hash
+            .checked_rem(hash_table.bucket_count())
+            .ok_or(HashTableLintError::NoBuckets { kind })?;
+        let target = u32::try_from(index).ok().map(|index| (index, hash));
+        if target.is_none() || !walk(bucket, target)? {
+            return Err(HashTableLintError::UnreachableSymbol { kind, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Various inconsistencies between the symbol hash tables and the dynamic symbol table reported
+/// by [`check_hash_tables`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum HashTableLintError {
+    /// The hash table section could not be parsed.
+    Malformed {
+        /// The kind of the offending hash table section.
+        kind: SectionType,
+    },
+    /// The file has a hash table section, but no [`SectionType::DYNSYM`] section.
+    MissingDynamicSymbolTable {
+        /// The kind of the offending hash table section.
+        kind: SectionType,
+    },
+    /// The number of chain entries of the [`SectionType::HASH`] section does not match the number
+    /// of dynamic symbols.
+    ChainCountMismatch {
+        /// The number of chain entries specified by the hash table.
+        chain_count: u32,
+        /// The number of entries of the dynamic symbol table.
+        symbol_count: u64,
+    },
+    /// The index of the first hashed symbol of the [`SectionType::GNU_HASH`] section lies past the
+    /// end of the dynamic symbol table.
+    SymbolOffsetOutOfBounds {
+        /// The index of the first hashed symbol.
+        symbol_offset: u32,
+        /// The number of entries of the dynamic symbol table.
+        symbol_count: usize,
+    },
+    /// The hash table has no buckets, but contains symbols.
+    NoBuckets {
+        /// The kind of the offending hash table section.
+        kind: SectionType,
+    },
+    /// A chain refers to a symbol outside of the dynamic symbol table or the hash table.
+    ChainOutOfBounds {
+        /// The kind of the offending hash table section.
+        kind: SectionType,
+        /// The bucket at which the offending chain starts.
+        bucket: u32,
+    },
+    /// A chain of the [`SectionType::HASH`] section does not terminate.
+    ChainCycle {
+        /// The kind of the offending hash table section.
+        kind: SectionType,
+        /// The bucket at which the offending chain starts.
+        bucket: u32,
+    },
+    /// A dynamic symbol cannot be found by looking up its name in the hash table.
+    UnreachableSymbol {
+        /// The kind of the offending hash table section.
+        kind: SectionType,
+        /// The index of the unreachable symbol in the dynamic symbol table.
+        index: usize,
+    },
+}
+
+/// Verifies that the version definition and version requirement chains of `file` stay within the
+/// bounds of their [`SectionType::GNU_VERDEF`] and [`SectionType::GNU_VERNEED`] sections, and that
+/// their lengths match the counts recorded by the [`ElfDynamicTag::VERDEF_NUM`] and
+/// [`ElfDynamicTag::VERNEED_NUM`] entries.
+///
+/// If the dynamic array does not record a count, the count stored in the section header is used.
+///
+/// # Errors
+///
+/// Returns the first [`VersionLintError`] found.
+pub fn check_version_chains<C: ClassParse, E: EncodingParse>(
+    file: ElfFile<C, E>,
+) -> Result<(), VersionLintError> {
+    let Some(section_header_table) = file.section_header_table() else {
+        return Ok(());
+    };
+
+    for section_header in section_header_table.iter() {
+        let (layout, count_tag) = match section_header.kind() {
+            SectionType::GNU_VERDEF => (ChainLayout::VERDEF, ElfDynamicTag::VERDEF_NUM),
+            SectionType::GNU_VERNEED => (ChainLayout::VERNEED, ElfDynamicTag::VERNEED_NUM),
+            _ => continue,
+        };
+        let kind = section_header.kind();
+
+        let data = file
+            .section_bytes(section_header)
+            .ok_or(VersionLintError::EntryOutOfBounds { kind, offset: 0 })?;
+        let expected = dynamic_value(file, count_tag).unwrap_or(section_header.info().into());
+        check_version_chain(data, expected, kind, layout, file.encoding)?;
+    }
+
+    Ok(())
+}
+
+/// The location of the fields of a version chain needed to walk it.
+struct ChainLayout {
+    /// The size of a primary entry.
+    entry_size: usize,
+    /// The offset of the [`u16`] holding the number of auxiliary entries of a primary entry.
+    auxiliary_count: usize,
+    /// The offset of the [`u32`] holding the offset of the first auxiliary entry.
+    auxiliary_offset: usize,
+    /// The offset of the [`u32`] holding the offset of the next primary entry.
+    next_offset: usize,
+    /// The size of an auxiliary entry.
+    auxiliary_size: usize,
+    /// The offset of the [`u32`] holding the offset of the next auxiliary entry.
+    auxiliary_next_offset: usize,
+}
+
+impl ChainLayout {
+    /// The [`ChainLayout`] of a version definition chain, which is identical for both classes.
+    const VERDEF: Self = Self {
+        entry_size: mem::size_of::<Elf64Verdef>(),
+        auxiliary_count: mem::offset_of!(Elf64Verdef, auxiliary_count),
+        auxiliary_offset: mem::offset_of!(Elf64Verdef, auxiliary_offset),
+        next_offset: mem::offset_of!(Elf64Verdef, next_offset),
+        auxiliary_size: mem::size_of::<Elf64Verdaux>(),
+        auxiliary_next_offset: mem::offset_of!(Elf64Verdaux, next_offset),
+    };
+
+    /// The [`ChainLayout`] of a version requirement chain, which is identical for both classes.
+    const VERNEED: Self = Self {
+        entry_size: mem::size_of::<Elf64Verneed>(),
+        auxiliary_count: mem::offset_of!(Elf64Verneed, auxiliary_count),
+        auxiliary_offset: mem::offset_of!(Elf64Verneed, auxiliary_offset),
+        next_offset: mem::offset_of!(Elf64Verneed, next_offset),
+        auxiliary_size: mem::size_of::<Elf64Vernaux>(),
+        auxiliary_next_offset: mem::offset_of!(Elf64Vernaux, next_offset),
+    };
+}
+
+/// Walks the version chain stored in `data`, checking that it consists of exactly `expected`
+/// entries that all lie within `data`.
+fn check_version_chain<E: EncodingParse>(
+    data: &[u8],
+    expected: u64,
+    kind: SectionType,
+    layout: ChainLayout,
+    encoding: E,
+) -> Result<(), VersionLintError> {
+    let entry_in_bounds = |offset: usize, size: usize| {
+        offset
+            .checked_add(size)
+            .is_some_and(|end| end <= data.len())
+    };
+
+    let mut offset = 0usize;
+    let mut count = 0u64;
+    while count < expected {
+        if !entry_in_bounds(offset, layout.entry_size) {
+            return Err(VersionLintError::EntryOutOfBounds {
+                kind,
+                offset: offset as u64,
+            });
+        }
+
+        let auxiliary_count =
+            encoding.parse_u16_at(offset.saturating_add(layout.auxiliary_count), data);
+        let mut auxiliary = offset.checked_add(
+            encoding.parse_u32_at(offset.saturating_add(layout.auxiliary_offset), data) as usize,
+        );
+        for auxiliary_index in 0..auxiliary_count {
+            let Some(auxiliary_offset) = auxiliary.filter(|&auxiliary_offset| {
+                entry_in_bounds(auxiliary_offset, layout.auxiliary_size)
+            }) else {
+                return Err(VersionLintError::AuxiliaryEntryOutOfBounds {
+                    kind,
+                    offset: offset as u64,
+                });
+            };
+
+            let next = encoding.parse_u32_at(
+                auxiliary_offset.saturating_add(layout.auxiliary_next_offset),
+                data,
+            );
+            let is_last = auxiliary_index.checked_add(1) == Some(auxiliary_count);
+            if (next == 0) != is_last {
+                return Err(VersionLintError::AuxiliaryChainLengthMismatch {
+                    kind,
+                    offset: offset as u64,
+                    expected: auxiliary_count,
+                });
+            }
+
+            auxiliary = auxiliary_offset.checked_add(next as usize);
+        }
+
+        count = count.saturating_add(1);
+        let next = encoding.parse_u32_at(offset.saturating_add(layout.next_offset), data);
+        if (next == 0) != (count == expected) {
+            return Err(VersionLintError::ChainLengthMismatch { kind, expected });
+        }
+
+        offset = offset
+            .checked_add(next as usize)
+            .ok_or(VersionLintError::EntryOutOfBounds {
+                kind,
+                offset: u64::MAX,
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the first entry tagged with `tag` in the [`SectionType::DYNAMIC`] section
+/// of `file`.
+fn dynamic_value<C: ClassParse, E: EncodingParse>(
+    file: ElfFile<C, E>,
+    tag: ElfDynamicTag,
+) -> Option<u64> {
+    let section_header = file
+        .section_header_table()?
+        .iter()
+        .find(|section_header| section_header.kind() == SectionType::DYNAMIC)?;
+    let data = file.section_bytes(section_header)?;
+
+    let (entry_size, value_offset) = match file.class.into_class() {
+        Class::Class32 => (
+            mem::size_of::<Elf32Dynamic>(),
+            mem::offset_of!(Elf32Dynamic, value),
+        ),
+        Class::Class64 => (
+            mem::size_of::<Elf64Dynamic>(),
+            mem::offset_of!(Elf64Dynamic, value),
+        ),
+    };
+
+    data.chunks_exact(entry_size).find_map(|entry| {
+        let (entry_tag, value) = match file.class.into_class() {
+            Class::Class32 => (
+                i64::from(file.encoding.parse_i32_at(0, entry)),
+                u64::from(file.encoding.parse_u32_at(value_offset, entry)),
+            ),
+            Class::Class64 => (
+                file.encoding.parse_i64_at(0, entry),
+                file.encoding.parse_u64_at(value_offset, entry),
+            ),
+        };
+
+        (entry_tag == i64::from(tag.0)).then_some(value)
+    })
+}
+
+/// Various inconsistencies in the version definition and version requirement chains reported by
+/// [`check_version_chains`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum VersionLintError {
+    /// An entry of the chain is not located inside of its section.
+    EntryOutOfBounds {
+        /// The kind of the offending section.
+        kind: SectionType,
+        /// The offset of the offending entry from the start of the section.
+        offset: u64,
+    },
+    /// An auxiliary entry of the chain is not located inside of its section.
+    AuxiliaryEntryOutOfBounds {
+        /// The kind of the offending section.
+        kind: SectionType,
+        /// The offset of the entry owning the offending auxiliary entry.
+        offset: u64,
+    },
+    /// The chain ends before, or continues after, the number of entries recorded for it.
+    ChainLengthMismatch {
+        /// The kind of the offending section.
+        kind: SectionType,
+        /// The number of entries recorded for the chain.
+        expected: u64,
+    },
+    /// The auxiliary chain of an entry ends before, or continues after, the number of auxiliary
+    /// entries recorded by that entry.
+    AuxiliaryChainLengthMismatch {
+        /// The kind of the offending section.
+        kind: SectionType,
+        /// The offset of the entry owning the offending auxiliary chain.
+        offset: u64,
+        /// The number of auxiliary entries recorded by the entry.
+        expected: u16,
+    },
+}
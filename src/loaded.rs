@@ -0,0 +1,303 @@
+//! Definitions and interfaces for inspecting ELF objects that have already been loaded into
+//! memory, such as the vDSO.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_dynamic::ElfDynamicTable,
+    elf_hash::{ElfGnuHashTable, ElfHashTable},
+    elf_header::{ElfHeader, ParseElfHeaderError},
+    elf_program_header::{
+        ElfProgramHeader, ElfProgramHeaderTable, OfType, ParseElfProgramHeaderTableError,
+    },
+    elf_section_header::{ElfSectionHeader, ElfSectionHeaderTable},
+    elf_string_table::ElfStringTable,
+    elf_symbol::{ElfSymbol, ElfSymbolTable},
+    encoding::EncodingParse,
+    raw::{
+        elf_program_header::SegmentType,
+        elf_section_header::SectionFlags,
+        elf_symbol::{Elf32Symbol, Elf64Symbol},
+    },
+    ElfFile,
+};
+
+/// An ELF object whose [`SegmentType::LOAD`] segments have been mapped into memory.
+///
+/// Unlike an [`ElfFile`], the data referenced by the headers of a [`LoadedElf`] is located using
+/// virtual addresses relative to [`LoadedElf::image_base`] rather than file offsets. The
+/// [`ElfHeader`] is expected at the start of the image, as is the case when the first
+/// [`SegmentType::LOAD`] segment maps the start of the file.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct LoadedElf<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] viewing the start of the image.
+    file: ElfFile<'slice, C, E>,
+    /// The virtual address corresponding to the start of the image.
+    image_base: u64,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> LoadedElf<'slice, C, E> {
+    /// Parses a [`LoadedElf`] from the provided `image`, checking that the [`ElfHeader`] is valid
+    /// and that the [`ElfProgramHeaderTable`] lies within `image`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseLoadedElfError`] if the [`ElfHeader`] is invalid, if the
+    /// [`ElfProgramHeaderTable`] does not fit within `image`, or if the image base cannot be
+    /// determined from the first [`SegmentType::LOAD`] segment.
+    pub fn parse(image: &'slice [u8]) -> Result<Self, ParseLoadedElfError> {
+        let elf_header = ElfHeader::<C, E>::parse(image)?;
+        let file = ElfFile {
+            slice: image,
+            class: elf_header.elf_ident().class_parse(),
+            encoding: elf_header.elf_ident().encoding_parse(),
+        };
+
+        let program_header_offset: usize = elf_header
+            .program_header_offset()
+            .try_into()
+            .map_err(|_| ParseElfProgramHeaderTableError::SliceTooSmall)?;
+        let program_header_slice = image
+            .get(program_header_offset..)
+            .ok_or(ParseElfProgramHeaderTableError::SliceTooSmall)?;
+        ElfProgramHeaderTable::from_raw_parts(
+            program_header_slice,
+            elf_header.program_header_count().into(),
+            elf_header.program_header_entry_size().into(),
+            file.class,
+            file.encoding,
+        )?;
+
+        let first_load = file
+            .load_segments()
+            .next()
+            .ok_or(ParseLoadedElfError::MissingLoadSegment)?;
+        let image_base = first_load
+            .virtual_address()
+            .checked_sub(first_load.file_offset())
+            .ok_or(ParseLoadedElfError::InvalidImageBase)?;
+
+        Ok(Self { file, image_base })
+    }
+
+    /// Returns the virtual address corresponding to the start of the image.
+    ///
+    /// This is the address the object was linked to load at, which is zero for the vDSO and
+    /// shared objects, and is unaffected by the address the image was actually mapped at.
+    pub fn image_base(&self) -> u64 {
+        self.image_base
+    }
+
+    /// Returns the bytes of the image.
+    pub fn image(&self) -> &'slice [u8] {
+        self.file.slice
+    }
+
+    /// Returns the [`ElfHeader`] of this [`LoadedElf`].
+    pub fn header(&self) -> ElfHeader<'slice, C, E> {
+        self.file.header()
+    }
+
+    /// Returns the [`ElfProgramHeaderTable`] of this [`LoadedElf`].
+    pub fn program_header_table(&self) -> Option<ElfProgramHeaderTable<'slice, C, E>> {
+        self.file.program_header_table()
+    }
+
+    /// Returns an iterator over the [`ElfProgramHeader`]s of this [`LoadedElf`] whose
+    /// [`SegmentType`] is `segment_type`.
+    pub fn segments_of_type(&self, segment_type: SegmentType) -> OfType<'slice, C, E> {
+        self.file.segments_of_type(segment_type)
+    }
+
+    /// Returns the [`ElfSectionHeaderTable`] of this [`LoadedElf`], or [`None`] if the section
+    /// headers were not loaded as part of the image.
+    pub fn section_header_table(&self) -> Option<ElfSectionHeaderTable<'slice, C, E>> {
+        self.file.section_header_table()
+    }
+
+    /// Returns the `size` bytes of the image located at the virtual address `vaddr`, or [`None`]
+    /// if they do not all lie within the image.
+    pub fn vaddr_bytes(&self, vaddr: u64, size: u64) -> Option<&'slice [u8]> {
+        self.vaddr_bytes_to_end(vaddr)?.get(..size.try_into().ok()?)
+    }
+
+    /// Returns the bytes of the image from the virtual address `vaddr` to the end of the image.
+    ///
+    /// This is useful for structures whose size is only known once they have been parsed.
+    pub fn vaddr_bytes_to_end(&self, vaddr: u64) -> Option<&'slice [u8]> {
+        let offset: usize = vaddr.checked_sub(self.image_base)?.try_into().ok()?;
+        self.file.slice.get(offset..)
+    }
+
+    /// Returns the in-memory bytes of the segment described by `program_header`, or [`None`] if
+    /// they do not all lie within the image.
+    pub fn segment_bytes(
+        &self,
+        program_header: ElfProgramHeader<'slice, C, E>,
+    ) -> Option<&'slice [u8]> {
+        self.vaddr_bytes(
+            program_header.virtual_address(),
+            program_header.memory_size(),
+        )
+    }
+
+    /// Returns the bytes of the section described by `section_header`, or [`None`] if they do
+    /// not all lie within the image.
+    ///
+    /// [`SectionFlags::ALLOC`] sections are located using their virtual address, while other
+    /// sections are only available if the file image was loaded in its entirety.
+    pub fn section_bytes(
+        &self,
+        section_header: ElfSectionHeader<'slice, C, E>,
+    ) -> Option<&'slice [u8]> {
+        if section_header.flags().contains(SectionFlags::ALLOC) {
+            return self.vaddr_bytes(section_header.address(), section_header.size());
+        }
+
+        self.file.section_bytes(section_header)
+    }
+
+    /// Returns the [`ElfDynamicTable`] of this [`LoadedElf`], located using the
+    /// [`SegmentType::DYNAMIC`] segment.
+    pub fn dynamic_table(&self) -> Option<ElfDynamicTable<'slice, C, E>> {
+        let program_header = self.segments_of_type(SegmentType::DYNAMIC).next()?;
+        Some(ElfDynamicTable::parse(
+            self.segment_bytes(program_header)?,
+            self.file.class,
+            self.file.encoding,
+        ))
+    }
+
+    /// Returns the [`ElfStringTable`] referenced by the [`ElfDynamicTag::STRING_TABLE`][dt] and
+    /// [`ElfDynamicTag::STRING_TABLE_SIZE`][dt] entries of the [`ElfDynamicTable`].
+    ///
+    /// [dt]: crate::raw::elf_dynamic::ElfDynamicTag
+    pub fn string_table_from_dynamic(&self) -> Option<ElfStringTable<'slice>> {
+        let dynamic_table = self.dynamic_table()?;
+        let slice = self.vaddr_bytes(
+            dynamic_table.string_table_address()?,
+            dynamic_table.string_table_size()?,
+        )?;
+
+        Some(ElfStringTable::new(slice))
+    }
+
+    /// Returns the [`ElfHashTable`] referenced by the [`ElfDynamicTag::HASH`][dt] entry of the
+    /// [`ElfDynamicTable`].
+    ///
+    /// [dt]: crate::raw::elf_dynamic::ElfDynamicTag
+    pub fn hash_table_from_dynamic(&self) -> Option<ElfHashTable<'slice, E>> {
+        let slice = self.vaddr_bytes_to_end(self.dynamic_table()?.hash_address()?)?;
+        ElfHashTable::parse(slice, self.file.encoding).ok()
+    }
+
+    /// Returns the [`ElfGnuHashTable`] referenced by the [`ElfDynamicTag::GNU_HASH`][dt] entry of
+    /// the [`ElfDynamicTable`].
+    ///
+    /// [dt]: crate::raw::elf_dynamic::ElfDynamicTag
+    pub fn gnu_hash_table_from_dynamic(&self) -> Option<ElfGnuHashTable<'slice, C, E>> {
+        let slice = self.vaddr_bytes_to_end(self.dynamic_table()?.gnu_hash_address()?)?;
+        ElfGnuHashTable::parse(slice, self.file.class, self.file.encoding).ok()
+    }
+
+    /// Returns the [`ElfSymbolTable`] referenced by the [`ElfDynamicTag::SYMBOL_TABLE`][dt]
+    /// entry of the [`ElfDynamicTable`].
+    ///
+    /// The number of symbols is taken from the [`ElfHashTable`] or, failing that, the
+    /// [`ElfGnuHashTable`], as described by [`ElfFile::symbol_table_from_dynamic`].
+    ///
+    /// [dt]: crate::raw::elf_dynamic::ElfDynamicTag
+    pub fn symbol_table_from_dynamic(&self) -> Option<ElfSymbolTable<'slice, C, E>> {
+        let dynamic_table = self.dynamic_table()?;
+        let entry_size = match self.file.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Symbol>() as u64,
+            Class::Class64 => mem::size_of::<Elf64Symbol>() as u64,
+        };
+        if dynamic_table
+            .symbol_entry_size()
+            .is_some_and(|size| size != entry_size)
+        {
+            return None;
+        }
+
+        let symbol_count = match self.hash_table_from_dynamic() {
+            Some(hash_table) => hash_table.chain_count(),
+            None => self.gnu_hash_table_from_dynamic()?.symbol_count()?,
+        };
+        let size = u64::from(symbol_count).checked_mul(entry_size)?;
+        let slice = self.vaddr_bytes(dynamic_table.symbol_table_address()?, size)?;
+        ElfSymbolTable::parse(
+            slice,
+            self.string_table_from_dynamic()?,
+            self.file.class,
+            self.file.encoding,
+        )
+        .ok()
+    }
+
+    /// Returns the defined dynamic [`ElfSymbol`] named `name`, looked up through the
+    /// [`ElfHashTable`] if present and the [`ElfGnuHashTable`] otherwise.
+    ///
+    /// Only the [`SegmentType::DYNAMIC`] segment and the structures it references are read, so
+    /// this works for images such as the vDSO whose section headers are not needed.
+    pub fn lookup_symbol(&self, name: &[u8]) -> Option<ElfSymbol<'slice, C, E>> {
+        let symbol_table = self.symbol_table_from_dynamic()?;
+        match self.hash_table_from_dynamic() {
+            Some(hash_table) => hash_table.lookup(name, symbol_table),
+            None => self
+                .gnu_hash_table_from_dynamic()?
+                .lookup(name, symbol_table),
+        }
+    }
+
+    /// Returns the offset from the start of the image of the defined dynamic [`ElfSymbol`] named
+    /// `name`, such as `__vdso_clock_gettime`.
+    ///
+    /// Adding the offset to the address the image is mapped at yields the runtime address of the
+    /// symbol.
+    pub fn symbol_offset(&self, name: &[u8]) -> Option<u64> {
+        self.lookup_symbol(name)?
+            .value()
+            .checked_sub(self.image_base)
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Parses a [`LoadedElf`] from the provided `image`, as described by [`LoadedElf::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseLoadedElfError`] if `image` is not a valid loaded ELF object.
+    pub fn parse_loaded(
+        image: &'slice [u8],
+    ) -> Result<LoadedElf<'slice, C, E>, ParseLoadedElfError> {
+        LoadedElf::parse(image)
+    }
+}
+
+/// Various errors that can occur while parsing a [`LoadedElf`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseLoadedElfError {
+    /// An error ocurred while parsing the [`ElfHeader`].
+    ParseElfHeaderError(ParseElfHeaderError),
+    /// An error ocurred while parsing the [`ElfProgramHeaderTable`].
+    ParseElfProgramHeaderTableError(ParseElfProgramHeaderTableError),
+    /// The [`ElfProgramHeaderTable`] has no [`SegmentType::LOAD`] segment.
+    MissingLoadSegment,
+    /// The file offset of the first [`SegmentType::LOAD`] segment is greater than its virtual
+    /// address.
+    InvalidImageBase,
+}
+
+impl From<ParseElfHeaderError> for ParseLoadedElfError {
+    fn from(value: ParseElfHeaderError) -> Self {
+        Self::ParseElfHeaderError(value)
+    }
+}
+
+impl From<ParseElfProgramHeaderTableError> for ParseLoadedElfError {
+    fn from(value: ParseElfProgramHeaderTableError) -> Self {
+        Self::ParseElfProgramHeaderTableError(value)
+    }
+}
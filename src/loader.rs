@@ -0,0 +1,586 @@
+//! Helpers for loading the segments of an ELF file into memory.
+
+use core::{iter::Peekable, ops::Range};
+
+use crate::{
+    class::ClassParse,
+    elf_program_header::{ElfProgramHeader, OfType, SegmentDataError, SegmentRangeError},
+    encoding::EncodingParse,
+    raw::{
+        elf_header::ElfType,
+        elf_program_header::{SegmentFlags, SegmentType},
+    },
+    ElfFile,
+};
+
+/// Selects which address of a segment the loader helpers place the segment at.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum AddressMode {
+    /// Segments are placed at their [`ElfProgramHeader::virtual_address`].
+    #[default]
+    Virtual,
+    /// Segments are placed at their [`ElfProgramHeader::physical_address`], as is common for
+    /// firmware-stage loaders running without address translation.
+    Physical,
+}
+
+/// A type describing the access permissions of mapped memory, such as the flags of a page table
+/// entry or the protection argument of `mprotect`, that can be derived from [`SegmentFlags`].
+pub trait PagePermissions {
+    /// Returns the permissions required to map a segment with the given [`SegmentFlags`].
+    fn from_segment_flags(flags: SegmentFlags) -> Self;
+}
+
+impl PagePermissions for SegmentFlags {
+    fn from_segment_flags(flags: SegmentFlags) -> Self {
+        flags
+    }
+}
+
+/// The permissions of a common memory model in which read, write and execute access are
+/// controlled independently.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Rwx {
+    /// Whether the memory may be read.
+    pub read: bool,
+    /// Whether the memory may be written.
+    pub write: bool,
+    /// Whether the memory may be executed.
+    pub execute: bool,
+}
+
+impl PagePermissions for Rwx {
+    fn from_segment_flags(flags: SegmentFlags) -> Self {
+        Self {
+            read: flags.contains(SegmentFlags::READ),
+            write: flags.contains(SegmentFlags::WRITE),
+            execute: flags.contains(SegmentFlags::EXECUTE),
+        }
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfProgramHeader<'slice, C, E> {
+    /// Returns the address at which the segment described by this [`ElfProgramHeader`] is placed
+    /// when loading using `mode`.
+    pub fn load_address(&self, mode: AddressMode) -> u64 {
+        match mode {
+            AddressMode::Virtual => self.virtual_address(),
+            AddressMode::Physical => self.physical_address(),
+        }
+    }
+
+    /// Returns the range of addresses occupied by the memory image of the segment when loading
+    /// using `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SegmentRangeError`] if the end of the range overflows.
+    pub fn load_range(&self, mode: AddressMode) -> Result<Range<u64>, SegmentRangeError> {
+        let start = self.load_address(mode);
+        let end = start
+            .checked_add(self.memory_size())
+            .ok_or(SegmentRangeError)?;
+        Ok(start..end)
+    }
+
+    /// Returns the permissions required to map the segment described by this
+    /// [`ElfProgramHeader`], translated into `P`.
+    pub fn permissions<P: PagePermissions>(&self) -> P {
+        P::from_segment_flags(self.flags())
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`SegmentType::LOAD`] segment whose memory image contains `address`, using
+    /// `mode` to select which address of each segment is considered.
+    pub fn segment_containing(
+        &self,
+        address: u64,
+        mode: AddressMode,
+    ) -> Option<ElfProgramHeader<'slice, C, E>> {
+        self.program_header_table()?.iter().find(|program_header| {
+            let start = program_header.load_address(mode);
+            program_header.segment_type() == SegmentType::LOAD
+                && address >= start
+                && address.wrapping_sub(start) < program_header.memory_size()
+        })
+    }
+
+    /// Returns the range of addresses spanned by the [`SegmentType::LOAD`] segments of this
+    /// [`ElfFile`] when loading using `mode`, with the bounds of each segment expanded to its
+    /// alignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadSpanError::NoLoadSegments`] if this [`ElfFile`] has no
+    /// [`SegmentType::LOAD`] segments, and [`LoadSpanError::Overflow`] if the bounds of a segment
+    /// overflow.
+    pub fn load_span(&self, mode: AddressMode) -> Result<LoadSpan, LoadSpanError> {
+        let mut span: Option<(u64, u64)> = None;
+        for program_header in self.load_segments() {
+            let range = program_header
+                .load_range(mode)
+                .map_err(|_| LoadSpanError::Overflow)?;
+            let alignment = program_header.alignment();
+            let start = align_down(range.start, alignment);
+            let end = align_up(range.end, alignment).ok_or(LoadSpanError::Overflow)?;
+
+            span = Some(match span {
+                Some((min, max)) => (min.min(start), max.max(end)),
+                None => (start, end),
+            });
+        }
+
+        let (min_address, max_address) = span.ok_or(LoadSpanError::NoLoadSegments)?;
+        Ok(LoadSpan {
+            min_address,
+            max_address,
+            size: max_address.saturating_sub(min_address),
+        })
+    }
+
+    /// Returns the largest alignment of the [`SegmentType::LOAD`] segments of this [`ElfFile`],
+    /// which is the alignment an [`ElfType::SHARED`] image must be loaded at, or one if no
+    /// segment requires alignment.
+    pub fn max_load_alignment(&self) -> u64 {
+        self.load_segments()
+            .map(|program_header| program_header.alignment())
+            .fold(1, u64::max)
+    }
+
+    /// Returns the bias that must be added to the virtual addresses of this [`ElfFile`] to place
+    /// its lowest [`SegmentType::LOAD`] segment at `base`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadBiasError::LoadSpanError`] if the [`LoadSpan`] of this [`ElfFile`] could not
+    /// be computed, [`LoadBiasError::Misaligned`] if `base` is not a multiple of
+    /// [`ElfFile::max_load_alignment`], and [`LoadBiasError::NotRelocatable`] if this
+    /// [`ElfFile`] is not an [`ElfType::SHARED`] object and `base` differs from the address it
+    /// was linked at.
+    pub fn load_bias(&self, base: u64) -> Result<u64, LoadBiasError> {
+        let span = self.load_span(AddressMode::Virtual)?;
+
+        let alignment = self.max_load_alignment();
+        if base.checked_rem(alignment) != Some(0) {
+            return Err(LoadBiasError::Misaligned { alignment });
+        }
+
+        if self.header().elf_type() != ElfType::SHARED && base != span.min_address {
+            return Err(LoadBiasError::NotRelocatable);
+        }
+
+        Ok(base.wrapping_sub(span.min_address))
+    }
+
+    /// Returns the values of the auxiliary vector entries that describe this [`ElfFile`] to a
+    /// process, once its virtual addresses have been offset by `bias`.
+    pub fn auxv_values(&self, bias: u64) -> AuxvValues {
+        let header = self.header();
+        AuxvValues {
+            phdr: self
+                .program_header_address()
+                .map(|address| address.wrapping_add(bias)),
+            phent: header.program_header_entry_size().into(),
+            phnum: header.program_header_count().into(),
+            entry: header.entry().wrapping_add(bias),
+        }
+    }
+
+    /// Returns the virtual address of the [`ElfProgramHeaderTable`][epht] of this [`ElfFile`], as
+    /// recorded by the [`SegmentType::PHDR`] segment or located within a
+    /// [`SegmentType::LOAD`] segment.
+    ///
+    /// [epht]: crate::elf_program_header::ElfProgramHeaderTable
+    fn program_header_address(&self) -> Option<u64> {
+        if let Some(program_header) = self.segments_of_type(SegmentType::PHDR).next() {
+            return Some(program_header.virtual_address());
+        }
+
+        let header = self.header();
+        let offset = header.program_header_offset();
+        let size = u64::from(header.program_header_count())
+            .checked_mul(header.program_header_entry_size().into())?;
+        let end = offset.checked_add(size)?;
+        self.load_segments().find_map(|program_header| {
+            let file = program_header.file_range().ok()?;
+            if offset < file.start || end > file.end {
+                return None;
+            }
+
+            program_header
+                .virtual_address()
+                .checked_add(offset.wrapping_sub(file.start))
+        })
+    }
+
+    /// Walks the [`SegmentType::LOAD`] segments of this [`ElfFile`] in order, passing the file
+    /// image and zero-fill requirements of each segment to `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError::SegmentDataError`] if the file image of a segment could not be
+    /// retrieved, and [`LoadError::SinkError`] if `sink` fails to load a segment. No further
+    /// segments are passed to `sink` after an error.
+    pub fn load_with<S: SegmentSink + ?Sized>(
+        &self,
+        sink: &mut S,
+    ) -> Result<(), LoadError<S::Error>> {
+        for program_header in self.load_segments() {
+            let (data, zero_fill) = program_header.segment_data(self)?;
+            sink.map(LoadSegment {
+                vaddr: program_header.virtual_address(),
+                paddr: program_header.physical_address(),
+                data,
+                zero_fill,
+                alignment: program_header.alignment(),
+                permissions: program_header.flags(),
+            })
+            .map_err(LoadError::SinkError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads the [`SegmentType::LOAD`] segments of this [`ElfFile`] into `dest`, which holds the
+    /// memory starting at `base_address`, zeroing the portion of each segment's memory image not
+    /// backed by the file.
+    ///
+    /// Each segment is placed at the address selected by `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadIntoError::SegmentDataError`] if the file image of a segment could not be
+    /// retrieved, and [`LoadIntoError::OutOfBounds`] if a segment does not fit in `dest`.
+    pub fn load_into(
+        &self,
+        dest: &mut [u8],
+        base_address: u64,
+        mode: AddressMode,
+    ) -> Result<(), LoadIntoError> {
+        let mut sink = SliceSink {
+            dest,
+            base_address,
+            mode,
+        };
+        self.load_with(&mut sink).map_err(|error| match error {
+            LoadError::SegmentDataError(error) => LoadIntoError::SegmentDataError(error),
+            LoadError::SinkError(error) => error,
+        })
+    }
+
+    /// Returns a [`LoadPlan`] describing how to map the [`SegmentType::LOAD`] segments of this
+    /// [`ElfFile`] using pages of `page_size` bytes, placing each segment at the address selected
+    /// by `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadPlanError::InvalidPageSize`] if `page_size` is not a power of two.
+    pub fn load_plan(
+        &self,
+        page_size: u64,
+        mode: AddressMode,
+    ) -> Result<LoadPlan<'slice, C, E>, LoadPlanError> {
+        if !page_size.is_power_of_two() {
+            return Err(LoadPlanError::InvalidPageSize);
+        }
+
+        Ok(LoadPlan {
+            segments: self.load_segments().peekable(),
+            page_size,
+            mode,
+            previous: None,
+        })
+    }
+}
+
+/// An iterator over the [`LoadMapping`]s required to load the [`SegmentType::LOAD`] segments of
+/// an [`ElfFile`], in order of increasing address.
+///
+/// The memory ranges of the yielded [`LoadMapping`]s never overlap: a page shared by two
+/// segments belongs to the [`LoadMapping`] of the first segment, whose permissions are widened
+/// to cover both. Iteration stops after the first error.
+pub struct LoadPlan<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`SegmentType::LOAD`] segments that have not been planned yet.
+    segments: Peekable<OfType<'slice, C, E>>,
+    /// The size of a page, which is a power of two.
+    page_size: u64,
+    /// Selects which address of each segment is used to place it.
+    mode: AddressMode,
+    /// The end of the previous segment's memory image and the end of its [`LoadMapping`].
+    previous: Option<(u64, u64)>,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> LoadPlan<'slice, C, E> {
+    /// Plans the [`LoadMapping`] of `program_header`.
+    fn plan(
+        &mut self,
+        program_header: ElfProgramHeader<'slice, C, E>,
+    ) -> Result<LoadMapping, LoadPlanError> {
+        let memory = program_header
+            .load_range(self.mode)
+            .map_err(|_| LoadPlanError::Overflow)?;
+        let file = program_header
+            .file_range()
+            .map_err(|_| LoadPlanError::Overflow)?;
+        if program_header.file_size() > program_header.memory_size() {
+            return Err(LoadPlanError::InvalidSizing);
+        }
+
+        let mut start = align_down(memory.start, self.page_size);
+        let end = align_up(memory.end, self.page_size).ok_or(LoadPlanError::Overflow)?;
+        if let Some((previous_end, previous_mapping_end)) = self.previous {
+            if memory.start < previous_end {
+                return Err(LoadPlanError::OverlappingSegments);
+            }
+            start = start.max(previous_mapping_end);
+        }
+        let end = end.max(start);
+
+        let mut permissions = program_header.flags();
+        if let Some(next) = self.segments.peek() {
+            if align_down(next.load_address(self.mode), self.page_size) < end {
+                permissions = SegmentFlags(permissions.0 | next.flags().0);
+            }
+        }
+
+        let file_end = memory
+            .start
+            .checked_add(program_header.file_size())
+            .ok_or(LoadPlanError::Overflow)?;
+        self.previous = Some((memory.end, end));
+        Ok(LoadMapping {
+            memory: start..end,
+            file,
+            file_address: memory.start,
+            zero_fill: file_end..memory.end,
+            permissions,
+        })
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for LoadPlan<'slice, C, E> {
+    type Item = Result<LoadMapping, LoadPlanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let program_header = self.segments.next()?;
+        let mapping = self.plan(program_header);
+        if mapping.is_err() {
+            while self.segments.next().is_some() {}
+        }
+
+        Some(mapping)
+    }
+}
+
+/// A single page-aligned mapping of a [`LoadPlan`], which covers one [`SegmentType::LOAD`]
+/// segment.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LoadMapping {
+    /// The page-aligned range of addresses to map.
+    ///
+    /// This excludes any page already covered by the previous [`LoadMapping`], and so may be
+    /// empty if the segment lies entirely within such a page.
+    pub memory: Range<u64>,
+    /// The range of offsets into the file holding the file image of the segment.
+    pub file: Range<u64>,
+    /// The address at which the first byte of [`LoadMapping::file`] is placed.
+    pub file_address: u64,
+    /// The range of addresses following the file image that must be zeroed.
+    pub zero_fill: Range<u64>,
+    /// The permissions with which to map [`LoadMapping::memory`].
+    pub permissions: SegmentFlags,
+}
+
+/// A destination for the [`SegmentType::LOAD`] segments of an [`ElfFile`], which allows loaders
+/// for different environments to share the traversal performed by [`ElfFile::load_with`].
+pub trait SegmentSink {
+    /// The error returned when a segment could not be loaded.
+    type Error;
+
+    /// Loads `segment`, copying [`LoadSegment::data`] to [`LoadSegment::vaddr`] and zeroing the
+    /// [`LoadSegment::zero_fill`] bytes that follow it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SegmentSink::Error`] if `segment` could not be loaded.
+    fn map(&mut self, segment: LoadSegment<'_>) -> Result<(), Self::Error>;
+}
+
+/// A [`SegmentType::LOAD`] segment passed to a [`SegmentSink`] by [`ElfFile::load_with`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct LoadSegment<'slice> {
+    /// The virtual address of the first byte of the segment.
+    pub vaddr: u64,
+    /// The physical address of the first byte of the segment.
+    pub paddr: u64,
+    /// The file image of the segment, which occupies the start of its memory image.
+    pub data: &'slice [u8],
+    /// The number of zero bytes that follow [`LoadSegment::data`] in the memory image of the
+    /// segment.
+    pub zero_fill: u64,
+    /// The alignment of the segment in memory and in the file.
+    pub alignment: u64,
+    /// The permissions with which the segment should be mapped.
+    pub permissions: SegmentFlags,
+}
+
+impl LoadSegment<'_> {
+    /// Returns the address at which this [`LoadSegment`] is placed when loading using `mode`.
+    pub fn load_address(&self, mode: AddressMode) -> u64 {
+        match mode {
+            AddressMode::Virtual => self.vaddr,
+            AddressMode::Physical => self.paddr,
+        }
+    }
+}
+
+/// A [`SegmentSink`] that copies segments into a slice holding the memory starting at
+/// `base_address`.
+struct SliceSink<'dest> {
+    /// The memory into which segments are loaded.
+    dest: &'dest mut [u8],
+    /// The address of the first byte of `dest`.
+    base_address: u64,
+    /// Selects which address of each segment is used to place it in `dest`.
+    mode: AddressMode,
+}
+
+impl SegmentSink for SliceSink<'_> {
+    type Error = LoadIntoError;
+
+    fn map(&mut self, segment: LoadSegment<'_>) -> Result<(), Self::Error> {
+        let start: usize = segment
+            .load_address(self.mode)
+            .checked_sub(self.base_address)
+            .and_then(|offset| offset.try_into().ok())
+            .ok_or(LoadIntoError::OutOfBounds)?;
+        let zero_fill: usize = segment
+            .zero_fill
+            .try_into()
+            .map_err(|_| LoadIntoError::OutOfBounds)?;
+        let file_end = start
+            .checked_add(segment.data.len())
+            .ok_or(LoadIntoError::OutOfBounds)?;
+        let end = file_end
+            .checked_add(zero_fill)
+            .ok_or(LoadIntoError::OutOfBounds)?;
+
+        let memory = self
+            .dest
+            .get_mut(start..end)
+            .ok_or(LoadIntoError::OutOfBounds)?;
+        let (file, zeroed) = memory.split_at_mut(segment.data.len());
+        file.copy_from_slice(segment.data);
+        zeroed.fill(0);
+
+        Ok(())
+    }
+}
+
+/// Various errors that can occur while loading an [`ElfFile`] using [`ElfFile::load_into`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LoadIntoError {
+    /// An error occurred while retrieving the file image of a segment.
+    SegmentDataError(SegmentDataError),
+    /// The memory image of a segment does not fit in the destination buffer.
+    OutOfBounds,
+}
+
+/// Various errors that can occur while loading an [`ElfFile`] using [`ElfFile::load_with`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LoadError<E> {
+    /// An error occurred while retrieving the file image of a segment.
+    SegmentDataError(SegmentDataError),
+    /// The [`SegmentSink`] failed to load a segment.
+    SinkError(E),
+}
+
+impl<E> From<SegmentDataError> for LoadError<E> {
+    fn from(value: SegmentDataError) -> Self {
+        Self::SegmentDataError(value)
+    }
+}
+
+/// The values of the auxiliary vector entries that describe an [`ElfFile`] to a process, as
+/// computed by [`ElfFile::auxv_values`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct AuxvValues {
+    /// The value of `AT_PHDR`, which is the address of the program header table, or [`None`] if
+    /// the program header table is not loaded.
+    pub phdr: Option<u64>,
+    /// The value of `AT_PHENT`, which is the size of a program header.
+    pub phent: u64,
+    /// The value of `AT_PHNUM`, which is the number of program headers.
+    pub phnum: u64,
+    /// The value of `AT_ENTRY`, which is the address of the entry point.
+    pub entry: u64,
+}
+
+/// Various errors that can occur while computing the load bias of an [`ElfFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LoadBiasError {
+    /// An error occurred while computing the [`LoadSpan`].
+    LoadSpanError(LoadSpanError),
+    /// The requested base address is not a multiple of `alignment`.
+    Misaligned {
+        /// The alignment required by the [`SegmentType::LOAD`] segments.
+        alignment: u64,
+    },
+    /// The [`ElfFile`] can only be loaded at the address it was linked at.
+    NotRelocatable,
+}
+
+impl From<LoadSpanError> for LoadBiasError {
+    fn from(value: LoadSpanError) -> Self {
+        Self::LoadSpanError(value)
+    }
+}
+
+/// Various errors that can occur while planning the mappings of a [`LoadPlan`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LoadPlanError {
+    /// The requested page size is not a power of two.
+    InvalidPageSize,
+    /// The bounds of a segment overflowed while being computed.
+    Overflow,
+    /// The file image of a segment is larger than its memory image.
+    InvalidSizing,
+    /// A segment begins before the end of the previous segment.
+    OverlappingSegments,
+}
+
+/// The range of addresses spanned by the [`SegmentType::LOAD`] segments of an [`ElfFile`], as
+/// computed by [`ElfFile::load_span`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct LoadSpan {
+    /// The lowest address occupied by a segment.
+    pub min_address: u64,
+    /// The address one past the highest address occupied by a segment.
+    pub max_address: u64,
+    /// The number of bytes between [`LoadSpan::min_address`] and [`LoadSpan::max_address`],
+    /// which is the size of the allocation required to hold every segment.
+    pub size: u64,
+}
+
+/// Various errors that can occur while computing a [`LoadSpan`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LoadSpanError {
+    /// The [`ElfFile`] has no [`SegmentType::LOAD`] segments.
+    NoLoadSegments,
+    /// The bounds of a segment overflowed while being computed.
+    Overflow,
+}
+
+/// Rounds `value` down to a multiple of `alignment`, where zero means no alignment.
+fn align_down(value: u64, alignment: u64) -> u64 {
+    value.saturating_sub(value.checked_rem(alignment).unwrap_or(0))
+}
+
+/// Rounds `value` up to a multiple of `alignment`, where zero means no alignment.
+fn align_up(value: u64, alignment: u64) -> Option<u64> {
+    match value.checked_rem(alignment) {
+        None | Some(0) => Some(value),
+        Some(remainder) => value.checked_add(alignment.checked_sub(remainder)?),
+    }
+}
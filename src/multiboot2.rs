@@ -0,0 +1,112 @@
+//! Definitions and interfaces for locating the Multiboot2 header of a kernel image.
+
+use core::mem;
+
+use crate::{
+    class::ClassParse,
+    encoding::EncodingParse,
+    raw::multiboot2::{
+        Multiboot2Architecture, Multiboot2Header, MULTIBOOT2_HEADER_ALIGN, MULTIBOOT2_HEADER_MAGIC,
+        MULTIBOOT2_SEARCH,
+    },
+    ElfFile,
+};
+
+/// A [`Multiboot2Header`] located within an image.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Multiboot2HeaderLocation<'slice> {
+    /// The offset of the [`Multiboot2Header`] from the start of the image.
+    pub offset: usize,
+    /// The virtual address at which the [`Multiboot2Header`] is loaded, or [`None`] if it does
+    /// not lie within a [`SegmentType::LOAD`][st] segment.
+    ///
+    /// [st]: crate::raw::elf_program_header::SegmentType::LOAD
+    pub virtual_address: Option<u64>,
+    /// The CPU instruction set architecture the image targets.
+    pub architecture: Multiboot2Architecture,
+    /// The bytes of the [`Multiboot2Header`], including its tags.
+    pub header: &'slice [u8],
+    /// The bytes of the tags following the fixed part of the [`Multiboot2Header`].
+    pub tags: &'slice [u8],
+}
+
+impl<'slice> Multiboot2HeaderLocation<'slice> {
+    /// Searches the first [`MULTIBOOT2_SEARCH`] bytes of `image` for a [`Multiboot2Header`]
+    /// whose checksum is valid and which lies completely within those bytes.
+    ///
+    /// The returned [`Multiboot2HeaderLocation::virtual_address`] is always [`None`].
+    pub fn find<E: EncodingParse>(image: &'slice [u8], encoding: E) -> Option<Self> {
+        let search = image.get(..MULTIBOOT2_SEARCH).unwrap_or(image);
+
+        (0..search.len())
+            .step_by(MULTIBOOT2_HEADER_ALIGN)
+            .find_map(|offset| Self::parse_at(search, offset, encoding))
+    }
+
+    /// Parses the [`Multiboot2Header`] located at `offset` in `search`, checking its magic,
+    /// checksum and length.
+    fn parse_at<E: EncodingParse>(
+        search: &'slice [u8],
+        offset: usize,
+        encoding: E,
+    ) -> Option<Self> {
+        let fixed = search.get(offset..offset.checked_add(mem::size_of::<Multiboot2Header>())?)?;
+        let field = |field_offset| encoding.parse_u32_at(field_offset, fixed);
+
+        let magic = field(mem::offset_of!(Multiboot2Header, magic));
+        if magic != MULTIBOOT2_HEADER_MAGIC {
+            return None;
+        }
+
+        let architecture = field(mem::offset_of!(Multiboot2Header, architecture));
+        let header_length = field(mem::offset_of!(Multiboot2Header, header_length));
+        let checksum = field(mem::offset_of!(Multiboot2Header, checksum));
+        let sum = magic
+            .wrapping_add(architecture)
+            .wrapping_add(header_length)
+            .wrapping_add(checksum);
+        if sum != 0 {
+            return None;
+        }
+
+        let header_length = usize::try_from(header_length).ok()?;
+        let header = search.get(offset..offset.checked_add(header_length)?)?;
+        let tags = header.get(mem::size_of::<Multiboot2Header>()..)?;
+
+        Some(Self {
+            offset,
+            virtual_address: None,
+            architecture: Multiboot2Architecture(architecture),
+            header,
+            tags,
+        })
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Searches the start of this [`ElfFile`] for a [`Multiboot2Header`], as described by
+    /// [`Multiboot2HeaderLocation::find`].
+    ///
+    /// If the header lies within the file data of a [`SegmentType::LOAD`][st] segment,
+    /// [`Multiboot2HeaderLocation::virtual_address`] is set to the address it is loaded at.
+    ///
+    /// [st]: crate::raw::elf_program_header::SegmentType::LOAD
+    pub fn multiboot2_header(&self) -> Option<Multiboot2HeaderLocation<'slice>> {
+        let mut location = Multiboot2HeaderLocation::find(self.slice, self.encoding)?;
+
+        let offset = u64::try_from(location.offset).ok()?;
+        location.virtual_address = self.program_header_table().and_then(|table| {
+            table
+                .load_segments()
+                .filter_map(|segment| Some((segment.file_range().ok()?, segment)))
+                .find(|(range, _)| range.contains(&offset))
+                .and_then(|(range, segment)| {
+                    segment
+                        .virtual_address()
+                        .checked_add(offset.checked_sub(range.start)?)
+                })
+        });
+
+        Some(location)
+    }
+}
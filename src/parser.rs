@@ -0,0 +1,224 @@
+//! A pull-based parser for the headers of an ELF file that performs no I/O itself.
+//!
+//! An [`ElfParser`] tells its caller which byte range of the file to supply next, which allows the
+//! headers to be parsed from asynchronous, networked or disk-backed sources.
+
+use core::{marker::PhantomData, mem};
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_header::{ElfHeader, ParseElfHeaderError},
+    elf_ident::ElfIdent,
+    elf_program_header::{ElfProgramHeaderTable, ParseElfProgramHeaderTableError},
+    encoding::EncodingParse,
+    raw::{
+        elf_header::{Elf32Header, Elf64Header},
+        elf_ident::ElfIdent as RawElfIdent,
+    },
+};
+
+/// A sans-I/O parser for the [`ElfHeader`] and [`ElfProgramHeaderTable`] of an ELF file.
+///
+/// Each call to [`ElfParser::advance`] must be given the bytes of the range most recently
+/// requested through [`Next::NeedRange`]. The first range is requested by calling
+/// [`ElfParser::advance`] with an empty slice.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfParser<C: ClassParse, E: EncodingParse> {
+    /// The range of the file that is currently requested.
+    state: State,
+    /// The bytes of the [`ElfHeader`] that have been supplied so far.
+    header: [u8; mem::size_of::<Elf64Header>()],
+    /// Marker for the [`ClassParse`] and [`EncodingParse`] used to parse the ELF file.
+    phantom: PhantomData<(C, E)>,
+}
+
+/// The part of an ELF file an [`ElfParser`] is waiting for.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+enum State {
+    /// The parser is waiting for the identification bytes at the start of the file.
+    Ident,
+    /// The parser is waiting for the remainder of the [`ElfHeader`], which is `header_size` bytes
+    /// long.
+    Header {
+        /// The size of the [`ElfHeader`].
+        header_size: usize,
+    },
+    /// The parser is waiting for the [`ElfProgramHeaderTable`].
+    ProgramHeaders {
+        /// The size of the [`ElfHeader`].
+        header_size: usize,
+        /// The offset of the [`ElfProgramHeaderTable`].
+        offset: u64,
+        /// The size of the [`ElfProgramHeaderTable`].
+        len: usize,
+    },
+    /// The ELF file has no [`ElfProgramHeaderTable`], and so parsing is complete.
+    Done {
+        /// The size of the [`ElfHeader`].
+        header_size: usize,
+    },
+}
+
+impl<C: ClassParse, E: EncodingParse> ElfParser<C, E> {
+    /// Creates a new [`ElfParser`] that has not been supplied with any bytes.
+    pub const fn new() -> Self {
+        Self {
+            state: State::Ident,
+            header: [0; mem::size_of::<Elf64Header>()],
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the byte range of the file that the next call to [`ElfParser::advance`] must be
+    /// given, as an offset and a length, or [`None`] if parsing is complete.
+    pub fn pending_range(&self) -> Option<(u64, usize)> {
+        let ident_size = mem::size_of::<RawElfIdent>();
+        match self.state {
+            State::Ident => Some((0, ident_size)),
+            State::Header { header_size } => {
+                Some((ident_size as u64, header_size.saturating_sub(ident_size)))
+            }
+            State::ProgramHeaders { offset, len, .. } => Some((offset, len)),
+            State::Done { .. } => None,
+        }
+    }
+
+    /// Supplies `bytes`, which must hold the range returned by [`ElfParser::pending_range`], and
+    /// returns what the parser needs next.
+    ///
+    /// If `bytes` is shorter than the pending range, the same range is requested again. Once
+    /// [`Next::Done`] has been returned, further calls supplying the same bytes return it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfParserError`] if the [`ElfHeader`] is invalid or if the location of the
+    /// [`ElfProgramHeaderTable`] overflows.
+    pub fn advance<'parser>(
+        &'parser mut self,
+        bytes: &'parser [u8],
+    ) -> Result<Next<'parser, C, E>, ElfParserError> {
+        let ident_size = mem::size_of::<RawElfIdent>();
+        let (offset, len) = self.pending_range().unwrap_or((0, 0));
+        let Some(bytes) = bytes.get(..len) else {
+            return Ok(Next::NeedRange { offset, len });
+        };
+
+        match self.state {
+            State::Ident => {
+                let elf_ident =
+                    ElfIdent::<C, E>::parse(bytes).map_err(ParseElfHeaderError::from)?;
+                let header_size = match elf_ident.class_parse().into_class() {
+                    Class::Class32 => mem::size_of::<Elf32Header>(),
+                    Class::Class64 => mem::size_of::<Elf64Header>(),
+                };
+
+                self.header[..ident_size].copy_from_slice(bytes);
+                self.state = State::Header { header_size };
+            }
+            State::Header { header_size } => {
+                self.header[ident_size..header_size].copy_from_slice(bytes);
+                let elf_header = ElfHeader::<C, E>::parse(&self.header[..header_size])?;
+
+                let count = usize::from(elf_header.program_header_count());
+                if count == 0 {
+                    self.state = State::Done { header_size };
+                    return self.done(header_size, None);
+                }
+
+                let len = count
+                    .checked_mul(elf_header.program_header_entry_size().into())
+                    .ok_or(ElfParserError::OffsetOverflow)?;
+                self.state = State::ProgramHeaders {
+                    header_size,
+                    offset: elf_header.program_header_offset(),
+                    len,
+                };
+            }
+            State::ProgramHeaders { header_size, .. } => {
+                return self.done(header_size, Some(bytes));
+            }
+            State::Done { header_size } => return self.done(header_size, None),
+        }
+
+        let (offset, len) = self.pending_range().unwrap_or((0, 0));
+        Ok(Next::NeedRange { offset, len })
+    }
+
+    /// Returns the [`ParsedHeaders`] of the ELF file, whose [`ElfHeader`] is `header_size` bytes
+    /// long and whose [`ElfProgramHeaderTable`] is held by `program_header_bytes`.
+    fn done<'parser>(
+        &'parser self,
+        header_size: usize,
+        program_header_bytes: Option<&'parser [u8]>,
+    ) -> Result<Next<'parser, C, E>, ElfParserError> {
+        let header = ElfHeader::<C, E>::parse(&self.header[..header_size])?;
+        let program_header_table = program_header_bytes
+            .map(|bytes| {
+                ElfProgramHeaderTable::from_raw_parts(
+                    bytes,
+                    header.program_header_count().into(),
+                    header.program_header_entry_size().into(),
+                    header.elf_ident().class_parse(),
+                    header.elf_ident().encoding_parse(),
+                )
+            })
+            .transpose()?;
+
+        Ok(Next::Done(ParsedHeaders {
+            header,
+            program_header_table,
+        }))
+    }
+}
+
+impl<C: ClassParse, E: EncodingParse> Default for ElfParser<C, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The result of supplying bytes to an [`ElfParser`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Next<'parser, C: ClassParse, E: EncodingParse> {
+    /// The parser needs the `len` bytes of the file located at `offset`.
+    NeedRange {
+        /// The offset of the requested bytes from the start of the file.
+        offset: u64,
+        /// The number of requested bytes.
+        len: usize,
+    },
+    /// The headers of the ELF file have been parsed.
+    Done(ParsedHeaders<'parser, C, E>),
+}
+
+/// The headers of an ELF file parsed by an [`ElfParser`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ParsedHeaders<'parser, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfHeader`] of the ELF file.
+    pub header: ElfHeader<'parser, C, E>,
+    /// The [`ElfProgramHeaderTable`] of the ELF file, or [`None`] if it has no program headers.
+    pub program_header_table: Option<ElfProgramHeaderTable<'parser, C, E>>,
+}
+
+/// Various errors that can occur while parsing an ELF file with an [`ElfParser`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ElfParserError {
+    /// An error ocurred while parsing the [`ElfHeader`].
+    ParseElfHeaderError(ParseElfHeaderError),
+    /// An error ocurred while parsing the [`ElfProgramHeaderTable`].
+    ParseElfProgramHeaderTableError(ParseElfProgramHeaderTableError),
+    /// The size of the [`ElfProgramHeaderTable`] overflowed while being computed.
+    OffsetOverflow,
+}
+
+impl From<ParseElfHeaderError> for ElfParserError {
+    fn from(value: ParseElfHeaderError) -> Self {
+        Self::ParseElfHeaderError(value)
+    }
+}
+
+impl From<ParseElfProgramHeaderTableError> for ElfParserError {
+    fn from(value: ParseElfProgramHeaderTableError) -> Self {
+        Self::ParseElfProgramHeaderTableError(value)
+    }
+}
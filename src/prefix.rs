@@ -0,0 +1,163 @@
+//! Definitions and interfaces for parsing the headers of an ELF file from a prefix of the file.
+//!
+//! Loaders that read a file piecewise can use [`ElfPrefix::required_len`] to learn how many bytes
+//! must be read before the [`ElfHeader`] and [`ElfProgramHeaderTable`] can be parsed.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_header::{ElfHeader, ParseElfHeaderError},
+    elf_ident::ElfIdent,
+    elf_program_header::ElfProgramHeaderTable,
+    encoding::EncodingParse,
+    raw::{
+        elf_header::{Elf32Header, Elf64Header},
+        elf_ident::ElfIdent as RawElfIdent,
+    },
+    ElfFile,
+};
+
+/// A prefix of an ELF file that covers its [`ElfHeader`] and [`ElfProgramHeaderTable`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ElfPrefix<'slice, C: ClassParse, E: EncodingParse> {
+    /// The bytes of the prefix.
+    slice: &'slice [u8],
+    /// The [`ClassParse`] used to parse the prefix.
+    class: C,
+    /// The [`EncodingParse`] used to parse the prefix.
+    encoding: E,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfPrefix<'slice, C, E> {
+    /// Returns the number of bytes from the start of the file that must be available to parse
+    /// an [`ElfPrefix`], given the first bytes of the file in `prefix`.
+    ///
+    /// While `prefix` is too short to hold the [`ElfHeader`], only the number of bytes needed to
+    /// parse the next part of the [`ElfHeader`] can be reported, so reading the reported number
+    /// of bytes and calling this again eventually yields the full length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfPrefixError::ParseElfHeaderError`] if the [`ElfHeader`] is invalid, and
+    /// [`ParseElfPrefixError::OffsetOverflow`] if the end of the [`ElfProgramHeaderTable`]
+    /// overflows.
+    pub fn required_len(prefix: &[u8]) -> Result<usize, ParseElfPrefixError> {
+        let ident_size = mem::size_of::<RawElfIdent>();
+        if prefix.len() < ident_size {
+            return Ok(ident_size);
+        }
+
+        let elf_ident = ElfIdent::<C, E>::parse(prefix).map_err(ParseElfHeaderError::from)?;
+        let header_size = match elf_ident.class_parse().into_class() {
+            Class::Class32 => mem::size_of::<Elf32Header>(),
+            Class::Class64 => mem::size_of::<Elf64Header>(),
+        };
+        if prefix.len() < header_size {
+            return Ok(header_size);
+        }
+
+        let elf_header = ElfHeader::<C, E>::parse(prefix)?;
+        let program_header_count = usize::from(elf_header.program_header_count());
+        if program_header_count == 0 {
+            return Ok(header_size);
+        }
+
+        let table_size = program_header_count
+            .checked_mul(elf_header.program_header_entry_size().into())
+            .ok_or(ParseElfPrefixError::OffsetOverflow)?;
+        let table_end = usize::try_from(elf_header.program_header_offset())
+            .ok()
+            .and_then(|offset| offset.checked_add(table_size))
+            .ok_or(ParseElfPrefixError::OffsetOverflow)?;
+
+        Ok(table_end.max(header_size))
+    }
+
+    /// Parses an [`ElfPrefix`] from `prefix`, which holds the first bytes of an ELF file.
+    ///
+    /// As with [`ElfFile::parse`], the individual [`ElfProgramHeader`][ph]s are not validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfPrefixError::NeedMoreData`] with the length reported by
+    /// [`ElfPrefix::required_len`] if `prefix` is too short, and another [`ParseElfPrefixError`]
+    /// if the [`ElfHeader`] is invalid.
+    ///
+    /// [ph]: crate::elf_program_header::ElfProgramHeader
+    pub fn parse(prefix: &'slice [u8]) -> Result<Self, ParseElfPrefixError> {
+        let required = Self::required_len(prefix)?;
+        if prefix.len() < required {
+            return Err(ParseElfPrefixError::NeedMoreData { required });
+        }
+
+        let elf_header = ElfHeader::<C, E>::parse(prefix)?;
+        Ok(Self {
+            slice: prefix,
+            class: elf_header.elf_ident().class_parse(),
+            encoding: elf_header.elf_ident().encoding_parse(),
+        })
+    }
+
+    /// Returns the [`ElfHeader`] of this [`ElfPrefix`].
+    pub fn header(&self) -> ElfHeader<'slice, C, E> {
+        ElfHeader {
+            slice: self.slice,
+            class: self.class,
+            encoding: self.encoding,
+        }
+    }
+
+    /// Returns the [`ElfProgramHeaderTable`] of this [`ElfPrefix`], or [`None`] if the ELF file
+    /// has no program headers.
+    pub fn program_header_table(&self) -> Option<ElfProgramHeaderTable<'slice, C, E>> {
+        let header = self.header();
+        if header.program_header_count() == 0 {
+            return None;
+        }
+
+        let offset: usize = header.program_header_offset().try_into().ok()?;
+        ElfProgramHeaderTable::from_raw_parts(
+            self.slice.get(offset..)?,
+            header.program_header_count().into(),
+            header.program_header_entry_size().into(),
+            self.class,
+            self.encoding,
+        )
+        .ok()
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the number of bytes from the start of the file that must be available to parse
+    /// its [`ElfHeader`] and [`ElfProgramHeaderTable`], as described by
+    /// [`ElfPrefix::required_len`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseElfPrefixError`] if the [`ElfHeader`] in `prefix` is invalid.
+    pub fn required_prefix_len(prefix: &[u8]) -> Result<usize, ParseElfPrefixError> {
+        ElfPrefix::<C, E>::required_len(prefix)
+    }
+}
+
+/// Various errors that can occur while parsing an [`ElfPrefix`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseElfPrefixError {
+    /// The prefix is too short, and must cover at least `required` bytes from the start of the
+    /// file.
+    NeedMoreData {
+        /// The number of bytes the prefix must cover.
+        required: usize,
+    },
+    /// An error ocurred while parsing the [`ElfHeader`].
+    ParseElfHeaderError(ParseElfHeaderError),
+    /// The end of the [`ElfProgramHeaderTable`] overflowed while being computed.
+    OffsetOverflow,
+}
+
+impl From<ParseElfHeaderError> for ParseElfPrefixError {
+    fn from(value: ParseElfHeaderError) -> Self {
+        Self::ParseElfHeaderError(value)
+    }
+}
@@ -0,0 +1,83 @@
+//! Definitions related to the auxiliary vector.
+
+/// 32-bit version of an auxiliary vector entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf32Auxv {
+    /// The type of the entry, which determines how [`Elf32Auxv::value`] should be interpreted.
+    pub kind: u32,
+    /// The value associated with this [`Elf32Auxv`].
+    pub value: u32,
+}
+
+/// 64-bit version of an auxiliary vector entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64Auxv {
+    /// The type of the entry, which determines how [`Elf64Auxv::value`] should be interpreted.
+    pub kind: u64,
+    /// The value associated with this [`Elf64Auxv`].
+    pub value: u64,
+}
+
+/// [`Class`][c] independent version of the type of an auxiliary vector entry.
+///
+/// [c]: crate::class::Class
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AuxvType(pub u64);
+
+impl AuxvType {
+    /// Marks the end of the auxiliary vector.
+    pub const NULL: Self = Self(0);
+    /// The entry should be ignored.
+    pub const IGNORE: Self = Self(1);
+    /// The file descriptor of the program.
+    pub const EXECFD: Self = Self(2);
+    /// The address of the program header table of the program.
+    pub const PHDR: Self = Self(3);
+    /// The size of an entry of the program header table of the program.
+    pub const PHENT: Self = Self(4);
+    /// The number of entries in the program header table of the program.
+    pub const PHNUM: Self = Self(5);
+    /// The system page size.
+    pub const PAGESZ: Self = Self(6);
+    /// The base address of the interpreter.
+    pub const BASE: Self = Self(7);
+    /// Flags.
+    pub const FLAGS: Self = Self(8);
+    /// The entry point of the program.
+    pub const ENTRY: Self = Self(9);
+    /// The program is not an ELF file.
+    pub const NOTELF: Self = Self(10);
+    /// The real user ID of the process.
+    pub const UID: Self = Self(11);
+    /// The effective user ID of the process.
+    pub const EUID: Self = Self(12);
+    /// The real group ID of the process.
+    pub const GID: Self = Self(13);
+    /// The effective group ID of the process.
+    pub const EGID: Self = Self(14);
+    /// The address of a string identifying the platform.
+    pub const PLATFORM: Self = Self(15);
+    /// The hardware capabilities of the processor.
+    pub const HWCAP: Self = Self(16);
+    /// The frequency at which `times` counts.
+    pub const CLKTCK: Self = Self(17);
+    /// Whether the program is being run in secure mode.
+    pub const SECURE: Self = Self(23);
+    /// The address of a string identifying the real platform.
+    pub const BASE_PLATFORM: Self = Self(24);
+    /// The address of 16 random bytes.
+    pub const RANDOM: Self = Self(25);
+    /// Additional hardware capabilities of the processor.
+    pub const HWCAP2: Self = Self(26);
+    /// The address of the path used to execute the program.
+    pub const EXECFN: Self = Self(31);
+    /// The address of the system call entry point in the vDSO.
+    pub const SYSINFO: Self = Self(32);
+    /// The address of the ELF header of the vDSO.
+    pub const SYSINFO_EHDR: Self = Self(33);
+    /// The minimal size of a signal stack.
+    pub const MINSIGSTKSZ: Self = Self(51);
+}
@@ -0,0 +1,163 @@
+//! Definitions related to the contents of ELF core dump notes.
+
+/// The general-purpose register set of an x86_64 thread, as stored in the `pr_reg` field of an
+/// `NT_PRSTATUS` note (`struct user_regs_struct`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct X86_64UserRegisters {
+    /// The `r15` register.
+    pub r15: u64,
+    /// The `r14` register.
+    pub r14: u64,
+    /// The `r13` register.
+    pub r13: u64,
+    /// The `r12` register.
+    pub r12: u64,
+    /// The `rbp` register, which usually holds the frame pointer.
+    pub rbp: u64,
+    /// The `rbx` register.
+    pub rbx: u64,
+    /// The `r11` register.
+    pub r11: u64,
+    /// The `r10` register.
+    pub r10: u64,
+    /// The `r9` register.
+    pub r9: u64,
+    /// The `r8` register.
+    pub r8: u64,
+    /// The `rax` register.
+    pub rax: u64,
+    /// The `rcx` register.
+    pub rcx: u64,
+    /// The `rdx` register.
+    pub rdx: u64,
+    /// The `rsi` register.
+    pub rsi: u64,
+    /// The `rdi` register.
+    pub rdi: u64,
+    /// The value of `rax` on entry to the system call the thread was executing, if any.
+    pub orig_rax: u64,
+    /// The instruction pointer.
+    pub rip: u64,
+    /// The code segment selector.
+    pub cs: u64,
+    /// The flags register.
+    pub eflags: u64,
+    /// The stack pointer.
+    pub rsp: u64,
+    /// The stack segment selector.
+    pub ss: u64,
+    /// The base address of the `fs` segment.
+    pub fs_base: u64,
+    /// The base address of the `gs` segment.
+    pub gs_base: u64,
+    /// The `ds` segment selector.
+    pub ds: u64,
+    /// The `es` segment selector.
+    pub es: u64,
+    /// The `fs` segment selector.
+    pub fs: u64,
+    /// The `gs` segment selector.
+    pub gs: u64,
+}
+
+/// The general-purpose register set of an aarch64 thread, as stored in the `pr_reg` field of an
+/// `NT_PRSTATUS` note (`struct user_pt_regs`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Aarch64UserRegisters {
+    /// The general-purpose registers `x0` through `x30`, where `x29` usually holds the frame
+    /// pointer and `x30` holds the link register.
+    pub regs: [u64; 31],
+    /// The stack pointer.
+    pub sp: u64,
+    /// The program counter.
+    pub pc: u64,
+    /// The processor state.
+    pub pstate: u64,
+}
+
+/// The signal information stored at the start of an `NT_PRSTATUS` note (`struct elf_siginfo`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ElfSignalInfo {
+    /// The number of the signal.
+    pub signal_number: i32,
+    /// The code describing why the signal was sent.
+    pub code: i32,
+    /// The error number associated with the signal.
+    pub errno: i32,
+}
+
+/// A time value stored in an `NT_PRSTATUS` note of a 64-bit process (`struct timeval`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64TimeValue {
+    /// The number of whole seconds.
+    pub seconds: i64,
+    /// The number of additional microseconds.
+    pub microseconds: i64,
+}
+
+/// The fields of an `NT_PRSTATUS` note of a 64-bit process (`struct elf_prstatus`) that precede
+/// the `pr_reg` field, whose layout depends on the processor.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64PrStatus {
+    /// Information about the signal that terminated the process.
+    pub signal_info: ElfSignalInfo,
+    /// The signal currently being delivered to the thread.
+    pub current_signal: i16,
+    /// The set of pending signals.
+    pub pending_signals: u64,
+    /// The set of blocked signals.
+    pub held_signals: u64,
+    /// The ID of the thread.
+    pub pid: i32,
+    /// The ID of the parent process.
+    pub ppid: i32,
+    /// The ID of the process group.
+    pub pgrp: i32,
+    /// The ID of the session.
+    pub sid: i32,
+    /// The user time consumed by the thread.
+    pub user_time: Elf64TimeValue,
+    /// The system time consumed by the thread.
+    pub system_time: Elf64TimeValue,
+    /// The cumulative user time consumed by the children of the process.
+    pub cumulative_user_time: Elf64TimeValue,
+    /// The cumulative system time consumed by the children of the process.
+    pub cumulative_system_time: Elf64TimeValue,
+}
+
+/// The contents of an `NT_PRPSINFO` note of a 64-bit process (`struct elf_prpsinfo`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64PrPsInfo {
+    /// The numeric state of the process.
+    pub state: u8,
+    /// The character describing the state of the process, as displayed by `ps`.
+    pub state_name: u8,
+    /// Whether the process is a zombie.
+    pub zombie: u8,
+    /// The nice value of the process.
+    pub nice: i8,
+    /// The flags of the process.
+    pub flags: u64,
+    /// The user ID of the process.
+    pub uid: u32,
+    /// The group ID of the process.
+    pub gid: u32,
+    /// The ID of the process.
+    pub pid: i32,
+    /// The ID of the parent process.
+    pub ppid: i32,
+    /// The ID of the process group.
+    pub pgrp: i32,
+    /// The ID of the session.
+    pub sid: i32,
+    /// The NUL-padded file name of the program.
+    pub file_name: [u8; 16],
+    /// The NUL-padded initial part of the argument list of the program.
+    pub arguments: [u8; 80],
+}
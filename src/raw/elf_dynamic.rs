@@ -142,6 +142,70 @@ impl ElfDynamicTag {
     /// Holds the address of the [`SHT_SYMTAB_SHNDX`] section associated with the dynamic symbol
     /// table referenced by the [`ElfDynamicTag::SYMBOL_TABLE`] element.
     pub const SYMBOL_TABLE_SECTION_INDEX: Self = Self(34);
+    /// Holds the total size, in bytes, of the relative relocation table pointed to by the
+    /// [`ElfDynamicTag::RELR_TABLE`] entry.
+    pub const RELR_SIZE: Self = Self(35);
+    /// Holds the address of a relative relocation table, in the compact RELR format.
+    ///
+    /// If this entry is present, the dynamic array must also have [`ElfDynamicTag::RELR_SIZE`]
+    /// and [`ElfDynamicTag::RELR_ENTRY_SIZE`] entries.
+    pub const RELR_TABLE: Self = Self(36);
+    /// Holds the size, in bytes, of an entry in the relative relocation table pointed to by the
+    /// [`ElfDynamicTag::RELR_TABLE`] entry.
+    pub const RELR_ENTRY_SIZE: Self = Self(37);
+
+    /// The start of the range of tags reserved for operating system-specific semantics.
+    pub const LOOS: Self = Self(0x6000_000D);
+    /// The end of the range of tags reserved for operating system-specific semantics.
+    pub const HIOS: Self = Self(0x6FFF_F000);
+
+    /// Holds the address of the GNU-style symbol hash table, which refers to the symbol table
+    /// referenced in an [`ElfDynamicTag::SYMBOL_TABLE`] entry.
+    pub const GNU_HASH: Self = Self(0x6FFF_FEF5);
+    /// Holds the address of the symbol version table, which has one entry for each symbol of the
+    /// symbol table referenced in an [`ElfDynamicTag::SYMBOL_TABLE`] entry.
+    pub const VERSYM: Self = Self(0x6FFF_FFF0);
+    /// Holds the number of relative relocations at the start of the relocation table pointed to
+    /// by the [`ElfDynamicTag::RELA_TABLE`] entry.
+    pub const RELA_COUNT: Self = Self(0x6FFF_FFF9);
+    /// Holds the number of relative relocations at the start of the relocation table pointed to
+    /// by the [`ElfDynamicTag::REL_TABLE`] entry.
+    pub const REL_COUNT: Self = Self(0x6FFF_FFFA);
+    /// Holds additional flag values specific to the object being loaded.
+    pub const FLAGS_1: Self = Self(0x6FFF_FFFB);
+
+    /// Holds the address of the version definition table.
+    pub const VERDEF: Self = Self(0x6FFF_FFFC);
+    /// Holds the number of entries in the version definition table.
+    pub const VERDEF_NUM: Self = Self(0x6FFF_FFFD);
+    /// Holds the address of the version requirement table.
+    pub const VERNEED: Self = Self(0x6FFF_FFFE);
+    /// Holds the number of entries in the version requirement table.
+    pub const VERNEED_NUM: Self = Self(0x6FFF_FFFF);
+
+    /// The start of the range of tags reserved for processor-specific semantics.
+    pub const LOPROC: Self = Self(0x7000_0000);
+    /// Holds the string table offset of a null-terminated string naming a shared object whose
+    /// symbol table is used in preference to the symbol table of this shared object.
+    pub const AUXILIARY: Self = Self(0x7FFF_FFFD);
+    /// Holds the string table offset of a null-terminated string naming a shared object that
+    /// supplies the definitions for the symbol table of this shared object.
+    pub const FILTER: Self = Self(0x7FFF_FFFF);
+    /// The end of the range of tags reserved for processor-specific semantics.
+    pub const HIPROC: Self = Self(0x7FFF_FFFF);
+
+    /// Returns `true` if this [`ElfDynamicTag`] lies in the range reserved for operating
+    /// system-specific semantics.
+    pub const fn is_os_specific(self) -> bool {
+        self.0 >= Self::LOOS.0 && self.0 <= Self::HIOS.0
+    }
+
+    /// Returns `true` if this [`ElfDynamicTag`] lies in the range reserved for
+    /// processor-specific semantics.
+    pub const fn is_processor_specific(self) -> bool {
+        // `HIPROC` is the largest representable tag, so only the lower bound needs checking.
+        self.0 >= Self::LOPROC.0
+    }
 }
 
 impl From<Elf32DynamicTag> for ElfDynamicTag {
@@ -155,3 +219,63 @@ impl From<Elf64DynamicTag> for ElfDynamicTag {
         Self(TryInto::<i32>::try_into(value.0).expect("out of range according to specification"))
     }
 }
+
+/// The flags stored in the [`ElfDynamicTag::FLAGS`] entry.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DynamicFlags(pub u64);
+
+impl DynamicFlags {
+    /// The object may reference the `$ORIGIN` substitution string.
+    pub const ORIGIN: Self = Self(0x1);
+    /// Symbol resolution starts from the object itself.
+    pub const SYMBOLIC: Self = Self(0x2);
+    /// Relocations may modify a non-writable segment.
+    pub const TEXTREL: Self = Self(0x4);
+    /// All relocations must be processed before control is transferred to the program.
+    pub const BIND_NOW: Self = Self(0x8);
+    /// The object uses the static thread-local storage model.
+    pub const STATIC_TLS: Self = Self(0x10);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The flags stored in the [`ElfDynamicTag::FLAGS_1`] entry.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DynamicFlags1(pub u64);
+
+impl DynamicFlags1 {
+    /// All relocations must be processed before control is transferred to the program.
+    pub const NOW: Self = Self(0x1);
+    /// The symbols of the object are made available for global symbol resolution.
+    pub const GLOBAL: Self = Self(0x2);
+    /// The object is a member of a group.
+    pub const GROUP: Self = Self(0x4);
+    /// The object cannot be unloaded.
+    pub const NODELETE: Self = Self(0x8);
+    /// The filtees of the object are loaded immediately.
+    pub const LOADFLTR: Self = Self(0x10);
+    /// The initialization of the object runs before that of any other object.
+    pub const INITFIRST: Self = Self(0x20);
+    /// The object cannot be opened using `dlopen`.
+    pub const NOOPEN: Self = Self(0x40);
+    /// The object may reference the `$ORIGIN` substitution string.
+    pub const ORIGIN: Self = Self(0x80);
+    /// Direct binding is enabled for the object.
+    pub const DIRECT: Self = Self(0x100);
+    /// The symbols of the object interpose on those of all other objects except the executable.
+    pub const INTERPOSE: Self = Self(0x400);
+    /// The default library search path is ignored when resolving the dependencies of the object.
+    pub const NODEFLIB: Self = Self(0x800);
+    /// The object is a position-independent executable.
+    pub const PIE: Self = Self(0x0800_0000);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -147,6 +147,20 @@ pub struct Machine(pub u16);
 impl Machine {
     /// No required machine.
     pub const NONE: Self = Self(0);
+    /// ELF file requires the Intel 80386 architecture.
+    pub const I386: Self = Self(3);
+    /// ELF file requires the MIPS architecture.
+    pub const MIPS: Self = Self(8);
+    /// ELF file requires the PowerPC architecture.
+    pub const PPC: Self = Self(20);
+    /// ELF file requires the 64-bit PowerPC architecture.
+    pub const PPC64: Self = Self(21);
+    /// ELF file requires the ARM 32-bit architecture.
+    pub const ARM: Self = Self(40);
     /// ELF file requires the AMD x86_64 architecture.
     pub const X86_64: Self = Self(62);
+    /// ELF file requires the ARM 64-bit architecture.
+    pub const AARCH64: Self = Self(183);
+    /// ELF file requires the RISC-V architecture.
+    pub const RISCV: Self = Self(243);
 }
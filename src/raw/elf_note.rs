@@ -0,0 +1,95 @@
+//! Definitions related to ELF notes.
+
+/// 32-bit version of an ELF note header.
+///
+/// The header is followed by the name of the note and then by its descriptor, each padded to the
+/// alignment of the containing segment or section.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf32Nhdr {
+    /// The size of the name of the note, including its NUL terminator.
+    pub name_size: u32,
+    /// The size of the descriptor of the note.
+    pub descriptor_size: u32,
+    /// The type of the note, whose interpretation depends on the name of the note.
+    pub kind: u32,
+}
+
+/// 64-bit version of an ELF note header.
+///
+/// The header is followed by the name of the note and then by its descriptor, each padded to the
+/// alignment of the containing segment or section.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64Nhdr {
+    /// The size of the name of the note, including its NUL terminator.
+    pub name_size: u32,
+    /// The size of the descriptor of the note.
+    pub descriptor_size: u32,
+    /// The type of the note, whose interpretation depends on the name of the note.
+    pub kind: u32,
+}
+
+/// The name of the notes owned by the GNU toolchain.
+pub const ELF_NOTE_GNU: &[u8] = b"GNU";
+/// The name of the notes describing the state of a process in a core dump.
+pub const ELF_NOTE_CORE: &[u8] = b"CORE";
+/// The name of the Linux-specific notes of a core dump.
+pub const ELF_NOTE_LINUX: &[u8] = b"LINUX";
+
+/// The type of the `GNU` note that identifies the operating system and ABI the object file
+/// targets.
+pub const NT_GNU_ABI_TAG: u32 = 1;
+/// The type of the `GNU` note that holds synthetic hardware capabilities.
+pub const NT_GNU_HWCAP: u32 = 2;
+/// The type of the `GNU` note that holds a unique identifier of the build of the object file.
+pub const NT_GNU_BUILD_ID: u32 = 3;
+/// The type of the `GNU` note that holds the version of gold that produced the object file.
+pub const NT_GNU_GOLD_VERSION: u32 = 4;
+/// The type of the `GNU` note that holds the program properties of an object file.
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+/// The operating system recorded by an [`NT_GNU_ABI_TAG`] note.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GnuAbiTagOs(pub u32);
+
+impl GnuAbiTagOs {
+    /// Linux.
+    pub const LINUX: Self = Self(0);
+    /// GNU/Hurd.
+    pub const GNU: Self = Self(1);
+    /// Solaris.
+    pub const SOLARIS: Self = Self(2);
+    /// FreeBSD.
+    pub const FREEBSD: Self = Self(3);
+    /// NetBSD.
+    pub const NETBSD: Self = Self(4);
+    /// Syllable.
+    pub const SYLLABLE: Self = Self(5);
+    /// Native Client.
+    pub const NACL: Self = Self(6);
+}
+
+/// The type of the `CORE` note that holds the status and general-purpose registers of a thread.
+pub const NT_PRSTATUS: u32 = 1;
+/// The type of the `CORE` note that holds the floating-point registers of a thread.
+pub const NT_PRFPREG: u32 = 2;
+/// The type of the `CORE` note that holds information about the process.
+pub const NT_PRPSINFO: u32 = 3;
+/// The type of the `CORE` note that holds the task structure of the process.
+pub const NT_TASKSTRUCT: u32 = 4;
+/// The type of the `CORE` note that holds the auxiliary vector of the process.
+pub const NT_AUXV: u32 = 6;
+/// The type of the `CORE` note that holds the signal information of the signal that caused the
+/// process to dump core.
+pub const NT_SIGINFO: u32 = 0x5349_4749;
+/// The type of the `CORE` note that lists the files mapped into the address space of the
+/// process.
+pub const NT_FILE: u32 = 0x4649_4C45;
+
+/// The type of the `LINUX` note that holds the extended floating-point registers of an x86
+/// thread.
+pub const NT_PRXFPREG: u32 = 0x46E6_2B7F;
+/// The type of the `LINUX` note that holds the extended processor state of an x86 thread.
+pub const NT_X86_XSTATE: u32 = 0x202;
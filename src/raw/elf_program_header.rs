@@ -72,6 +72,36 @@ impl SegmentType {
     pub const PHDR: Self = Self(6);
     /// Thread local storage.
     pub const TLS: Self = Self(7);
+
+    /// Start of the range reserved for operating system-specific semantics.
+    pub const LOOS: Self = Self(0x6000_0000);
+    /// The `.eh_frame_hdr` section, used to locate unwind information.
+    pub const GNU_EH_FRAME: Self = Self(0x6474_E550);
+    /// Indicates whether the stack should be executable.
+    pub const GNU_STACK: Self = Self(0x6474_E551);
+    /// The region that should be made read-only after relocation.
+    pub const GNU_RELRO: Self = Self(0x6474_E552);
+    /// The program properties of the object file, stored as an `NT_GNU_PROPERTY_TYPE_0` note.
+    pub const GNU_PROPERTY: Self = Self(0x6474_E553);
+    /// End of the range reserved for operating system-specific semantics.
+    pub const HIOS: Self = Self(0x6FFF_FFFF);
+
+    /// Start of the range reserved for processor-specific semantics.
+    pub const LOPROC: Self = Self(0x7000_0000);
+    /// End of the range reserved for processor-specific semantics.
+    pub const HIPROC: Self = Self(0x7FFF_FFFF);
+
+    /// Returns `true` if this [`SegmentType`] lies in the range reserved for operating
+    /// system-specific semantics.
+    pub const fn is_os_specific(self) -> bool {
+        self.0 >= Self::LOOS.0 && self.0 <= Self::HIOS.0
+    }
+
+    /// Returns `true` if this [`SegmentType`] lies in the range reserved for processor-specific
+    /// semantics.
+    pub const fn is_processor_specific(self) -> bool {
+        self.0 >= Self::LOPROC.0 && self.0 <= Self::HIPROC.0
+    }
 }
 
 /// The permissions of the loaded segment.
@@ -86,4 +116,9 @@ impl SegmentFlags {
     pub const WRITE: Self = Self(2);
     /// The segment is readable.
     pub const READ: Self = Self(4);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
 }
@@ -61,3 +61,115 @@ pub struct Elf64Rela {
     /// field.
     pub addend: i64,
 }
+
+/// No relocation.
+pub const R_X86_64_NONE: u32 = 0;
+/// The relocated field holds `S + A`.
+pub const R_X86_64_64: u32 = 1;
+/// The relocated field holds `S + A - P`, truncated to 32 bits.
+pub const R_X86_64_PC32: u32 = 2;
+/// The relocated field holds `G + A`, truncated to 32 bits.
+pub const R_X86_64_GOT32: u32 = 3;
+/// The relocated field holds `L + A - P`, truncated to 32 bits.
+pub const R_X86_64_PLT32: u32 = 4;
+/// The dynamic linker copies the data of the referenced symbol into the relocated location.
+pub const R_X86_64_COPY: u32 = 5;
+/// The global offset table entry is set to the address of the referenced symbol.
+pub const R_X86_64_GLOB_DAT: u32 = 6;
+/// The procedure linkage table entry is set to the address of the referenced symbol.
+pub const R_X86_64_JUMP_SLOT: u32 = 7;
+/// The relocated field holds `B + A`.
+pub const R_X86_64_RELATIVE: u32 = 8;
+/// The relocated field holds the value returned by calling the function at `B + A`.
+pub const R_X86_64_IRELATIVE: u32 = 37;
+
+/// No relocation.
+pub const R_AARCH64_NONE: u32 = 0;
+/// The relocated field holds `S + A`.
+pub const R_AARCH64_ABS64: u32 = 257;
+/// The dynamic linker copies the data of the referenced symbol into the relocated location.
+pub const R_AARCH64_COPY: u32 = 1024;
+/// The global offset table entry is set to `S + A`.
+pub const R_AARCH64_GLOB_DAT: u32 = 1025;
+/// The procedure linkage table entry is set to `S + A`.
+pub const R_AARCH64_JUMP_SLOT: u32 = 1026;
+/// The relocated field holds `B + A`.
+pub const R_AARCH64_RELATIVE: u32 = 1027;
+/// The relocated field holds the value returned by calling the function at `B + A`.
+pub const R_AARCH64_IRELATIVE: u32 = 1032;
+
+/// No relocation.
+pub const R_ARM_NONE: u32 = 0;
+/// The relocated field holds `S + A`.
+pub const R_ARM_ABS32: u32 = 2;
+/// The relocated field holds `S + A - P`.
+pub const R_ARM_REL32: u32 = 3;
+/// The relocated field holds a TLS descriptor for the referenced symbol.
+pub const R_ARM_TLS_DESC: u32 = 13;
+/// The relocated field holds the module index of the referenced symbol's TLS block.
+pub const R_ARM_TLS_DTPMOD32: u32 = 17;
+/// The relocated field holds the offset of the referenced symbol within its TLS block.
+pub const R_ARM_TLS_DTPOFF32: u32 = 18;
+/// The relocated field holds the offset of the referenced symbol from the thread pointer.
+pub const R_ARM_TLS_TPOFF32: u32 = 19;
+/// The dynamic linker copies the data of the referenced symbol into the relocated location.
+pub const R_ARM_COPY: u32 = 20;
+/// The global offset table entry is set to `S + A`.
+pub const R_ARM_GLOB_DAT: u32 = 21;
+/// The procedure linkage table entry is set to `S + A`.
+pub const R_ARM_JUMP_SLOT: u32 = 22;
+/// The relocated field holds `B + A`.
+pub const R_ARM_RELATIVE: u32 = 23;
+/// The relocated field holds the value returned by calling the function at `B + A`.
+pub const R_ARM_IRELATIVE: u32 = 160;
+
+/// No relocation.
+pub const R_386_NONE: u32 = 0;
+/// The relocated field holds `S + A`.
+pub const R_386_32: u32 = 1;
+/// The relocated field holds `S + A - P`.
+pub const R_386_PC32: u32 = 2;
+/// The relocated field holds `G + A - P`.
+pub const R_386_GOT32: u32 = 3;
+/// The relocated field holds `L + A - P`.
+pub const R_386_PLT32: u32 = 4;
+/// The dynamic linker copies the data of the referenced symbol into the relocated location.
+pub const R_386_COPY: u32 = 5;
+/// The global offset table entry is set to the address of the referenced symbol.
+pub const R_386_GLOB_DAT: u32 = 6;
+/// The procedure linkage table entry is set to the address of the referenced symbol.
+pub const R_386_JMP_SLOT: u32 = 7;
+/// The relocated field holds `B + A`.
+pub const R_386_RELATIVE: u32 = 8;
+/// The relocated field holds `S + A - GOT`.
+pub const R_386_GOTOFF: u32 = 9;
+/// The relocated field holds `GOT + A - P`.
+pub const R_386_GOTPC: u32 = 10;
+/// The relocated field holds the negated offset of the referenced symbol from the thread
+/// pointer.
+pub const R_386_TLS_TPOFF: u32 = 14;
+/// The relocated field holds the address of the global offset table entry holding the negated
+/// thread pointer offset of the referenced symbol.
+pub const R_386_TLS_IE: u32 = 15;
+/// The relocated field holds the global offset table offset of the entry holding the negated
+/// thread pointer offset of the referenced symbol.
+pub const R_386_TLS_GOTIE: u32 = 16;
+/// The relocated field holds the negated offset of the referenced symbol from the thread
+/// pointer, computed at link time.
+pub const R_386_TLS_LE: u32 = 17;
+/// The relocated field holds the global offset table offset of the TLS index of the referenced
+/// symbol, for the general dynamic model.
+pub const R_386_TLS_GD: u32 = 18;
+/// The relocated field holds the global offset table offset of the TLS index of the current
+/// module, for the local dynamic model.
+pub const R_386_TLS_LDM: u32 = 19;
+/// The relocated field holds the module index of the referenced symbol's TLS block.
+pub const R_386_TLS_DTPMOD32: u32 = 35;
+/// The relocated field holds the offset of the referenced symbol within its TLS block.
+pub const R_386_TLS_DTPOFF32: u32 = 36;
+/// The relocated field holds the offset of the referenced symbol from the thread pointer.
+pub const R_386_TLS_TPOFF32: u32 = 37;
+/// The relocated field holds a TLS descriptor for the referenced symbol.
+pub const R_386_TLS_DESC: u32 = 41;
+/// The relocated field holds the value returned by calling the function at `B + A`.
+pub const R_386_IRELATIVE: u32 = 42;
@@ -9,7 +9,7 @@ pub struct Elf32SectionHeader {
     /// The index into the section name string table that identifies the name of the section.
     pub name: u32,
     /// The kind of the section.
-    pub kind: u32,
+    pub kind: SectionType,
     /// Additional information about a section.
     pub flags: u32,
     /// The virtual address of the section at execution.
@@ -38,7 +38,7 @@ pub struct Elf64SectionHeader {
     /// The index into the section name string table that identifies the name of the section.
     pub name: u32,
     /// The kind of the section.
-    pub kind: u32,
+    pub kind: SectionType,
     /// Additional information about a section.
     pub flags: u64,
     /// The virtual address of the section at execution.
@@ -57,3 +57,170 @@ pub struct Elf64SectionHeader {
     /// The size of an entry contained in the section if the section holds a table of etnries.
     pub entry_size: u64,
 }
+
+/// The kind of a section, which determines how its contents should be interpreted.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SectionType(pub u32);
+
+impl SectionType {
+    /// Inactive section header, which does not have an associated section.
+    pub const NULL: Self = Self(0);
+    /// Information defined by the program.
+    pub const PROGBITS: Self = Self(1);
+    /// A symbol table, typically for link editing.
+    pub const SYMTAB: Self = Self(2);
+    /// A string table.
+    pub const STRTAB: Self = Self(3);
+    /// Relocation entries with explicit addends.
+    pub const RELA: Self = Self(4);
+    /// A symbol hash table.
+    pub const HASH: Self = Self(5);
+    /// Information for dynamic linking.
+    pub const DYNAMIC: Self = Self(6);
+    /// Information that marks the file in some way.
+    pub const NOTE: Self = Self(7);
+    /// A section that occupies no space in the file.
+    pub const NOBITS: Self = Self(8);
+    /// Relocation entries without explicit addends.
+    pub const REL: Self = Self(9);
+    /// Reserved.
+    pub const SHLIB: Self = Self(10);
+    /// A minimal set of dynamic linking symbols.
+    pub const DYNSYM: Self = Self(11);
+    /// An array of pointers to initialization functions.
+    pub const INIT_ARRAY: Self = Self(14);
+    /// An array of pointers to termination functions.
+    pub const FINI_ARRAY: Self = Self(15);
+    /// An array of pointers to functions invoked before all other initialization functions.
+    pub const PREINIT_ARRAY: Self = Self(16);
+    /// A section group.
+    pub const GROUP: Self = Self(17);
+    /// Extended section indices associated with a symbol table.
+    pub const SYMTAB_SHNDX: Self = Self(18);
+
+    /// Start of the range reserved for os-specific semantics.
+    pub const OS_SPECIFIC_START: Self = Self(0x6000_0000);
+    /// Object attributes.
+    pub const GNU_ATTRIBUTES: Self = Self(0x6FFF_FFF5);
+    /// GNU-style symbol hash table.
+    pub const GNU_HASH: Self = Self(0x6FFF_FFF6);
+    /// Prelink library list.
+    pub const GNU_LIBLIST: Self = Self(0x6FFF_FFF7);
+    /// Version definitions.
+    pub const GNU_VERDEF: Self = Self(0x6FFF_FFFD);
+    /// Versions needed.
+    pub const GNU_VERNEED: Self = Self(0x6FFF_FFFE);
+    /// Version symbol table.
+    pub const GNU_VERSYM: Self = Self(0x6FFF_FFFF);
+    /// End of the range reserved for os-specific semantics.
+    pub const OS_SPECIFIC_END: Self = Self(0x6FFF_FFFF);
+
+    /// Start of the range reserved for processor-specific semantics.
+    pub const PROCESSOR_SPECIFIC_START: Self = Self(0x7000_0000);
+    /// End of the range reserved for processor-specific semantics.
+    pub const PROCESSOR_SPECIFIC_END: Self = Self(0x7FFF_FFFF);
+
+    /// Start of the range reserved for application programs.
+    pub const USER_START: Self = Self(0x8000_0000);
+    /// End of the range reserved for application programs.
+    pub const USER_END: Self = Self(0xFFFF_FFFF);
+}
+
+/// Flags describing miscellaneous attributes of a section.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SectionFlags(pub u64);
+
+impl SectionFlags {
+    /// The section contains data that should be writable during process execution.
+    pub const WRITE: Self = Self(0x1);
+    /// The section occupies memory during process execution.
+    pub const ALLOC: Self = Self(0x2);
+    /// The section contains executable machine instructions.
+    pub const EXECINSTR: Self = Self(0x4);
+    /// The data in the section may be merged to eliminate duplication.
+    pub const MERGE: Self = Self(0x10);
+    /// The data elements in the section consist of NUL-terminated strings.
+    pub const STRINGS: Self = Self(0x20);
+    /// The info field of this section header holds a section header table index.
+    pub const INFO_LINK: Self = Self(0x40);
+    /// Adds special ordering requirements for link editors.
+    pub const LINK_ORDER: Self = Self(0x80);
+    /// The section requires special OS-specific processing to avoid incorrect behavior.
+    pub const OS_NONCONFORMING: Self = Self(0x100);
+    /// The section is a member of a section group.
+    pub const GROUP: Self = Self(0x200);
+    /// The section holds thread-local storage.
+    pub const TLS: Self = Self(0x400);
+    /// The section holds compressed data.
+    pub const COMPRESSED: Self = Self(0x800);
+    /// The bits reserved for operating system-specific semantics.
+    pub const MASKOS: Self = Self(0x0FF0_0000);
+    /// The bits reserved for processor-specific semantics.
+    pub const MASKPROC: Self = Self(0xF000_0000);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the flags of `self` that lie in [`SectionFlags::MASKOS`].
+    pub const fn os_specific_bits(self) -> Self {
+        Self(self.0 & Self::MASKOS.0)
+    }
+
+    /// Returns the flags of `self` that lie in [`SectionFlags::MASKPROC`].
+    pub const fn processor_specific_bits(self) -> Self {
+        Self(self.0 & Self::MASKPROC.0)
+    }
+
+    /// Returns the flags of `self` that lie in neither [`SectionFlags::MASKOS`] nor
+    /// [`SectionFlags::MASKPROC`].
+    pub const fn standard_bits(self) -> Self {
+        Self(self.0 & !(Self::MASKOS.0 | Self::MASKPROC.0))
+    }
+
+    /// Returns `true` if any flag of `self` lies in [`SectionFlags::MASKOS`].
+    pub const fn has_os_specific_bits(self) -> bool {
+        self.os_specific_bits().0 != 0
+    }
+
+    /// Returns `true` if any flag of `self` lies in [`SectionFlags::MASKPROC`].
+    pub const fn has_processor_specific_bits(self) -> bool {
+        self.processor_specific_bits().0 != 0
+    }
+}
+
+/// An index into the section header table, which may instead hold one of the reserved values.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SectionIndex(pub u16);
+
+impl SectionIndex {
+    /// An undefined, missing, irrelevant, or otherwise meaningless section reference.
+    pub const UNDEF: Self = Self(0);
+    /// Start of the range of reserved indices.
+    pub const LORESERVE: Self = Self(0xFF00);
+    /// Start of the range reserved for processor-specific semantics.
+    pub const LOPROC: Self = Self(0xFF00);
+    /// End of the range reserved for processor-specific semantics.
+    pub const HIPROC: Self = Self(0xFF1F);
+    /// Start of the range reserved for operating system-specific semantics.
+    pub const LOOS: Self = Self(0xFF20);
+    /// End of the range reserved for operating system-specific semantics.
+    pub const HIOS: Self = Self(0xFF3F);
+    /// Absolute values for the corresponding reference, which are not affected by relocation.
+    pub const ABS: Self = Self(0xFFF1);
+    /// Common symbols, such as unallocated C external variables.
+    pub const COMMON: Self = Self(0xFFF2);
+    /// The actual index is too large to fit and is stored elsewhere.
+    pub const XINDEX: Self = Self(0xFFFF);
+    /// End of the range of reserved indices.
+    pub const HIRESERVE: Self = Self(0xFFFF);
+
+    /// Returns `true` if this [`SectionIndex`] lies in the range of reserved indices.
+    pub const fn is_reserved(self) -> bool {
+        self.0 >= Self::LORESERVE.0
+    }
+}
@@ -69,7 +69,7 @@ impl SymbolInfo {
 
     /// The [`SymbolType`] that this [`SymbolInfo`] indicates.
     pub const fn symbol_type(self) -> SymbolType {
-        SymbolType(self.0 & 0x3)
+        SymbolType(self.0 & 0xF)
     }
 }
 
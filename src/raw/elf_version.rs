@@ -0,0 +1,197 @@
+//! Definitions related to GNU symbol versioning.
+
+/// 32-bit version of an ELF version definition entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf32Verdef {
+    /// The version of the structure.
+    pub version: VersionRevision,
+    /// Information about the version definition.
+    pub flags: VersionFlags,
+    /// The version index, as referenced by the symbol version table.
+    pub index: u16,
+    /// The number of associated version definition auxiliary entries.
+    pub auxiliary_count: u16,
+    /// The hash of the version name, computed as by the System V symbol hash function.
+    pub name_hash: u32,
+    /// The offset, in bytes, from the start of this entry to its first auxiliary entry.
+    pub auxiliary_offset: u32,
+    /// The offset, in bytes, from the start of this entry to the next version definition entry,
+    /// or zero if this is the last entry.
+    pub next_offset: u32,
+}
+
+/// 32-bit version of an ELF version definition auxiliary entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf32Verdaux {
+    /// The string table offset of the name of the version or of a parent version.
+    pub name: u32,
+    /// The offset, in bytes, from the start of this entry to the next auxiliary entry, or zero
+    /// if this is the last auxiliary entry.
+    pub next_offset: u32,
+}
+
+/// 32-bit version of an ELF version requirement entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf32Verneed {
+    /// The version of the structure.
+    pub version: VersionRevision,
+    /// The number of associated version requirement auxiliary entries.
+    pub auxiliary_count: u16,
+    /// The string table offset of the name of the file the versions are required from.
+    pub file: u32,
+    /// The offset, in bytes, from the start of this entry to its first auxiliary entry.
+    pub auxiliary_offset: u32,
+    /// The offset, in bytes, from the start of this entry to the next version requirement
+    /// entry, or zero if this is the last entry.
+    pub next_offset: u32,
+}
+
+/// 32-bit version of an ELF version requirement auxiliary entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf32Vernaux {
+    /// The hash of the version name, computed as by the System V symbol hash function.
+    pub name_hash: u32,
+    /// Information about the version requirement.
+    pub flags: VersionFlags,
+    /// The version index assigned to the required version, as referenced by the symbol version
+    /// table.
+    pub other: u16,
+    /// The string table offset of the name of the required version.
+    pub name: u32,
+    /// The offset, in bytes, from the start of this entry to the next auxiliary entry, or zero
+    /// if this is the last auxiliary entry.
+    pub next_offset: u32,
+}
+
+/// 64-bit version of an ELF version definition entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64Verdef {
+    /// The version of the structure.
+    pub version: VersionRevision,
+    /// Information about the version definition.
+    pub flags: VersionFlags,
+    /// The version index, as referenced by the symbol version table.
+    pub index: u16,
+    /// The number of associated version definition auxiliary entries.
+    pub auxiliary_count: u16,
+    /// The hash of the version name, computed as by the System V symbol hash function.
+    pub name_hash: u32,
+    /// The offset, in bytes, from the start of this entry to its first auxiliary entry.
+    pub auxiliary_offset: u32,
+    /// The offset, in bytes, from the start of this entry to the next version definition entry,
+    /// or zero if this is the last entry.
+    pub next_offset: u32,
+}
+
+/// 64-bit version of an ELF version definition auxiliary entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64Verdaux {
+    /// The string table offset of the name of the version or of a parent version.
+    pub name: u32,
+    /// The offset, in bytes, from the start of this entry to the next auxiliary entry, or zero
+    /// if this is the last auxiliary entry.
+    pub next_offset: u32,
+}
+
+/// 64-bit version of an ELF version requirement entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64Verneed {
+    /// The version of the structure.
+    pub version: VersionRevision,
+    /// The number of associated version requirement auxiliary entries.
+    pub auxiliary_count: u16,
+    /// The string table offset of the name of the file the versions are required from.
+    pub file: u32,
+    /// The offset, in bytes, from the start of this entry to its first auxiliary entry.
+    pub auxiliary_offset: u32,
+    /// The offset, in bytes, from the start of this entry to the next version requirement
+    /// entry, or zero if this is the last entry.
+    pub next_offset: u32,
+}
+
+/// 64-bit version of an ELF version requirement auxiliary entry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf64Vernaux {
+    /// The hash of the version name, computed as by the System V symbol hash function.
+    pub name_hash: u32,
+    /// Information about the version requirement.
+    pub flags: VersionFlags,
+    /// The version index assigned to the required version, as referenced by the symbol version
+    /// table.
+    pub other: u16,
+    /// The string table offset of the name of the required version.
+    pub name: u32,
+    /// The offset, in bytes, from the start of this entry to the next auxiliary entry, or zero
+    /// if this is the last auxiliary entry.
+    pub next_offset: u32,
+}
+
+/// The version of a version definition or version requirement structure.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VersionRevision(pub u16);
+
+impl VersionRevision {
+    /// Invalid version.
+    pub const NONE: Self = Self(0);
+    /// The current version.
+    pub const CURRENT: Self = Self(1);
+}
+
+/// Information about a version definition or version requirement.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VersionFlags(pub u16);
+
+impl VersionFlags {
+    /// The version definition describes the ELF file itself.
+    pub const BASE: Self = Self(0x1);
+    /// The version is weak, which means that it may be absent without causing an error.
+    pub const WEAK: Self = Self(0x2);
+    /// The version requirement is informational only.
+    pub const INFO: Self = Self(0x4);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// An entry of the symbol version table, which holds the version index of the symbol at the same
+/// index of the dynamic symbol table.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Versym(pub u16);
+
+impl Versym {
+    /// The symbol is local and not available outside of the ELF file.
+    pub const LOCAL: Self = Self(0);
+    /// The symbol is defined in the ELF file and is globally available.
+    pub const GLOBAL: Self = Self(1);
+    /// Start of the range of reserved version indices.
+    pub const LORESERVE: Self = Self(0xFF00);
+    /// The symbol is to be eliminated.
+    pub const ELIMINATE: Self = Self(0xFF01);
+
+    /// The bit that marks the symbol as hidden, which means that it is not the default version
+    /// of the symbol.
+    pub const HIDDEN: u16 = 0x8000;
+
+    /// Returns the version index of the [`Versym`], without the [`Versym::HIDDEN`] bit.
+    pub const fn index(self) -> u16 {
+        self.0 & !Self::HIDDEN
+    }
+
+    /// Returns `true` if the [`Versym::HIDDEN`] bit of the [`Versym`] is set.
+    pub const fn is_hidden(self) -> bool {
+        self.0 & Self::HIDDEN != 0
+    }
+}
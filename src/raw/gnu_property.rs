@@ -0,0 +1,62 @@
+//! Definitions related to GNU program properties.
+
+pub use crate::raw::elf_note::NT_GNU_PROPERTY_TYPE_0;
+
+/// The type of a program property stored in an [`NT_GNU_PROPERTY_TYPE_0`] note.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GnuPropertyType(pub u32);
+
+impl GnuPropertyType {
+    /// The size of the stack required by the object file.
+    pub const STACK_SIZE: Self = Self(1);
+    /// The object file does not need copy relocations against protected symbols.
+    pub const NO_COPY_ON_PROTECTED: Self = Self(2);
+    /// Start of the processor-specific property range.
+    pub const LOPROC: Self = Self(0xC000_0000);
+    /// The AArch64 features supported by every input of the object file, as
+    /// [`Aarch64Feature1`] flags.
+    pub const AARCH64_FEATURE_1_AND: Self = Self(0xC000_0000);
+    /// The x86 ISA extensions used by the object file.
+    pub const X86_ISA_1_USED: Self = Self(0xC001_0002);
+    /// The x86 ISA extensions needed by the object file.
+    pub const X86_ISA_1_NEEDED: Self = Self(0xC000_8002);
+    /// The x86 features supported by every input of the object file, as [`X86Feature1`] flags.
+    pub const X86_FEATURE_1_AND: Self = Self(0xC000_0002);
+    /// Inclusive end of the processor-specific property range.
+    pub const HIPROC: Self = Self(0xDFFF_FFFF);
+}
+
+/// The flags of a [`GnuPropertyType::X86_FEATURE_1_AND`] property.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct X86Feature1(pub u32);
+
+impl X86Feature1 {
+    /// The object file is compatible with Indirect Branch Tracking.
+    pub const IBT: Self = Self(1);
+    /// The object file is compatible with Shadow Stacks.
+    pub const SHSTK: Self = Self(2);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The flags of a [`GnuPropertyType::AARCH64_FEATURE_1_AND`] property.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Aarch64Feature1(pub u32);
+
+impl Aarch64Feature1 {
+    /// The object file is compatible with Branch Target Identification.
+    pub const BTI: Self = Self(1);
+    /// The object file is compatible with Pointer Authentication.
+    pub const PAC: Self = Self(2);
+
+    /// Returns `true` if all of the flags set in `other` are also set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,41 @@
+//! Definitions related to Linux kernel modules.
+
+/// The marker appended to the end of a signed kernel module, following its
+/// [`ModuleSignatureInfo`].
+pub const MODULE_SIGNATURE_MAGIC: &[u8] = b"~Module signature appended~\n";
+
+/// Describes the signature appended to a kernel module (`struct module_signature`).
+///
+/// The signer name, key identifier and signature precede this structure, in that order.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ModuleSignatureInfo {
+    /// The public-key crypto algorithm, which is zero for [`ModuleSignatureIdType::PKCS7`].
+    pub algorithm: u8,
+    /// The digest algorithm, which is zero for [`ModuleSignatureIdType::PKCS7`].
+    pub hash: u8,
+    /// The kind of key identifier.
+    pub id_type: ModuleSignatureIdType,
+    /// The length of the signer's name, which is zero for [`ModuleSignatureIdType::PKCS7`].
+    pub signer_length: u8,
+    /// The length of the key identifier, which is zero for [`ModuleSignatureIdType::PKCS7`].
+    pub key_id_length: u8,
+    /// Padding.
+    pub padding: [u8; 3],
+    /// The length of the signature, stored in big-endian byte order.
+    pub signature_length: u32,
+}
+
+/// The kind of key identifier used by a kernel module signature.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ModuleSignatureIdType(pub u8);
+
+impl ModuleSignatureIdType {
+    /// OpenPGP generated key identifier.
+    pub const PGP: Self = Self(0);
+    /// X.509 certificate identifier.
+    pub const X509: Self = Self(1);
+    /// Signature in PKCS#7 message format.
+    pub const PKCS7: Self = Self(2);
+}
@@ -1,9 +1,17 @@
 //! Definitions of raw ELF structures.
 
+pub mod elf_auxv;
+pub mod elf_core;
 pub mod elf_dynamic;
 pub mod elf_header;
 pub mod elf_ident;
+pub mod elf_note;
 pub mod elf_program_header;
 pub mod elf_relocation;
 pub mod elf_section_header;
 pub mod elf_symbol;
+pub mod elf_version;
+pub mod gnu_property;
+pub mod kernel_module;
+pub mod multiboot2;
+pub mod xen;
@@ -0,0 +1,83 @@
+//! Definitions related to Multiboot2 headers embedded in kernel images.
+
+/// The value of [`Multiboot2Header::magic`].
+pub const MULTIBOOT2_HEADER_MAGIC: u32 = 0xE852_50D6;
+
+/// The number of bytes at the start of an image that must completely contain its
+/// [`Multiboot2Header`] and the tags that follow it.
+pub const MULTIBOOT2_SEARCH: usize = 32768;
+
+/// The required alignment of a [`Multiboot2Header`] within an image.
+pub const MULTIBOOT2_HEADER_ALIGN: usize = 8;
+
+/// The fixed part of a Multiboot2 header, which is followed by its tags.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Multiboot2Header {
+    /// The identifying value of the header, which must be [`MULTIBOOT2_HEADER_MAGIC`].
+    pub magic: u32,
+    /// The CPU instruction set architecture the image targets.
+    pub architecture: Multiboot2Architecture,
+    /// The length of the header in bytes, including its tags.
+    pub header_length: u32,
+    /// The value that makes the sum of `magic`, `architecture`, `header_length` and `checksum`
+    /// zero, modulo 2^32.
+    pub checksum: u32,
+}
+
+/// The CPU instruction set architecture recorded by a [`Multiboot2Header`].
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Multiboot2Architecture(pub u32);
+
+impl Multiboot2Architecture {
+    /// 32-bit protected mode i386.
+    pub const I386: Self = Self(0);
+    /// 32-bit MIPS.
+    pub const MIPS32: Self = Self(4);
+}
+
+/// The fixed part of a tag following a [`Multiboot2Header`].
+///
+/// Each tag starts at an 8-byte aligned offset, and the list is terminated by a tag of type
+/// [`Multiboot2HeaderTagType::END`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Multiboot2HeaderTag {
+    /// The type of the tag.
+    pub kind: Multiboot2HeaderTagType,
+    /// The flags of the tag.
+    pub flags: u16,
+    /// The size of the tag in bytes, including this structure.
+    pub size: u32,
+}
+
+/// The type of a [`Multiboot2HeaderTag`].
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Multiboot2HeaderTagType(pub u16);
+
+impl Multiboot2HeaderTagType {
+    /// Terminates the tag list.
+    pub const END: Self = Self(0);
+    /// Requests information from the boot loader.
+    pub const INFORMATION_REQUEST: Self = Self(1);
+    /// Describes the physical addresses at which the image should be loaded.
+    pub const ADDRESS: Self = Self(2);
+    /// Holds the physical address of the 32-bit entry point.
+    pub const ENTRY_ADDRESS: Self = Self(3);
+    /// Describes the console requirements of the image.
+    pub const CONSOLE_FLAGS: Self = Self(4);
+    /// Describes the preferred framebuffer mode.
+    pub const FRAMEBUFFER: Self = Self(5);
+    /// Requests that modules be page aligned.
+    pub const MODULE_ALIGN: Self = Self(6);
+    /// Requests that EFI boot services not be terminated.
+    pub const EFI_BS: Self = Self(7);
+    /// Holds the physical address of the EFI i386 entry point.
+    pub const ENTRY_ADDRESS_EFI32: Self = Self(8);
+    /// Holds the physical address of the EFI amd64 entry point.
+    pub const ENTRY_ADDRESS_EFI64: Self = Self(9);
+    /// Describes how the image may be relocated.
+    pub const RELOCATABLE: Self = Self(10);
+}
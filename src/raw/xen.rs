@@ -0,0 +1,43 @@
+//! Definitions related to the ELF notes of Xen guest kernels.
+
+/// The name of the notes that describe how a Xen guest kernel should be booted.
+pub const ELF_NOTE_XEN: &[u8] = b"Xen";
+
+/// An informational note, which should be ignored.
+pub const XEN_ELFNOTE_INFO: u32 = 0;
+/// The virtual address of the entry point of the kernel.
+pub const XEN_ELFNOTE_ENTRY: u32 = 1;
+/// The virtual address of the hypercall page.
+pub const XEN_ELFNOTE_HYPERCALL_PAGE: u32 = 2;
+/// The virtual address at which the kernel expects to be mapped.
+pub const XEN_ELFNOTE_VIRT_BASE: u32 = 3;
+/// The offset between the virtual and physical addresses of the kernel.
+pub const XEN_ELFNOTE_PADDR_OFFSET: u32 = 4;
+/// The version of Xen the kernel targets, as a string.
+pub const XEN_ELFNOTE_XEN_VERSION: u32 = 5;
+/// The name of the guest operating system, as a string.
+pub const XEN_ELFNOTE_GUEST_OS: u32 = 6;
+/// The version of the guest operating system, as a string.
+pub const XEN_ELFNOTE_GUEST_VERSION: u32 = 7;
+/// The loader type, as a string.
+pub const XEN_ELFNOTE_LOADER: u32 = 8;
+/// The PAE mode the kernel supports, as a string.
+pub const XEN_ELFNOTE_PAE_MODE: u32 = 9;
+/// The features the kernel supports or requires, as a string of `|`-separated names.
+pub const XEN_ELFNOTE_FEATURES: u32 = 10;
+/// Whether the kernel requires a symbol table to be loaded, as a string.
+pub const XEN_ELFNOTE_BSD_SYMTAB: u32 = 11;
+/// The lowest address of the hypervisor hole.
+pub const XEN_ELFNOTE_HV_START_LOW: u32 = 12;
+/// The mask and value used to identify valid L1 page table entries.
+pub const XEN_ELFNOTE_L1_MFN_VALID: u32 = 13;
+/// Whether the kernel supports cancelled suspends.
+pub const XEN_ELFNOTE_SUSPEND_CANCEL: u32 = 14;
+/// The virtual address at which the initial physical-to-machine table should be mapped.
+pub const XEN_ELFNOTE_INIT_P2M: u32 = 15;
+/// Whether the kernel accepts the start of its modules as a page frame number.
+pub const XEN_ELFNOTE_MOD_START_PFN: u32 = 16;
+/// The features the kernel supports, as a bitmap.
+pub const XEN_ELFNOTE_SUPPORTED_FEATURES: u32 = 17;
+/// The 32-bit physical address of the PVH entry point of the kernel.
+pub const XEN_ELFNOTE_PHYS32_ENTRY: u32 = 18;
@@ -0,0 +1,348 @@
+//! Application of the dynamic relocations of an ELF file to its loaded image.
+
+use crate::{
+    class::ClassParse,
+    elf_dynamic::ElfDynamicTable,
+    elf_program_header::SegmentDataError,
+    elf_relocation::{
+        read_implicit_addend, relocation_field, ElfRelocation, ElfRelocationTable,
+        ReadImplicitAddendError,
+    },
+    encoding::EncodingParse,
+    raw::{
+        elf_dynamic::ElfDynamicTag,
+        elf_header::Machine,
+        elf_program_header::SegmentType,
+        elf_relocation::{
+            R_AARCH64_ABS64, R_AARCH64_GLOB_DAT, R_AARCH64_IRELATIVE, R_AARCH64_JUMP_SLOT,
+            R_AARCH64_NONE, R_AARCH64_RELATIVE, R_X86_64_64, R_X86_64_GLOB_DAT, R_X86_64_IRELATIVE,
+            R_X86_64_JUMP_SLOT, R_X86_64_NONE, R_X86_64_RELATIVE,
+        },
+    },
+    ElfFile,
+};
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Computes the value of each relocation in the [`ElfDynamicTag::RELA_TABLE`],
+    /// [`ElfDynamicTag::REL_TABLE`] and [`ElfDynamicTag::JMP_REL`] tables of this [`ElfFile`],
+    /// passing each computed value to `write`.
+    ///
+    /// `bias` is the difference between the address at which this [`ElfFile`] was loaded and the
+    /// address it was linked at, while `resolve` returns the runtime address of the symbol with
+    /// the given index in the dynamic symbol table. `resolve` is never called for the null
+    /// symbol, whose address is zero.
+    ///
+    /// Implicit addends are read from the contents of this [`ElfFile`], so relocations must be
+    /// applied before the loaded image is modified.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApplyRelocationsError::UnsupportedMachine`]: relocations for the [`Machine`] of this
+    ///   [`ElfFile`] are not supported.
+    /// - [`ApplyRelocationsError::SegmentDataError`]: the [`SegmentType::DYNAMIC`] segment could
+    ///   not be read.
+    /// - [`ApplyRelocationsError::InvalidTable`]: a relocation table is not backed by the file or
+    ///   its entries have an unexpected size.
+    /// - [`ApplyRelocationsError::UnsupportedRelocationType`]: a relocation has a type that is
+    ///   not supported.
+    /// - [`ApplyRelocationsError::UnresolvedSymbol`]: `resolve` failed to resolve a symbol.
+    /// - [`ApplyRelocationsError::ReadImplicitAddendError`]: an implicit addend could not be
+    ///   read.
+    /// - [`ApplyRelocationsError::WriteError`]: `write` failed to write a value.
+    pub fn apply_relocations<R, W, WE>(
+        &self,
+        bias: u64,
+        mut resolve: R,
+        mut write: W,
+    ) -> Result<(), ApplyRelocationsError<WE>>
+    where
+        R: FnMut(u32) -> Option<u64>,
+        W: FnMut(RelocationWrite) -> Result<(), WE>,
+    {
+        let machine = self.header().machine();
+        if machine != Machine::X86_64 && machine != Machine::AARCH64 {
+            return Err(ApplyRelocationsError::UnsupportedMachine);
+        }
+
+        for relocation in self
+            .relocation_tables()?
+            .into_iter()
+            .flatten()
+            .flat_map(|table| table.iter())
+        {
+            let offset = relocation.offset();
+            let symbol = relocation.symbol_index();
+            let kind = relocation.relocation_type();
+            let addend = || self.relocation_addend(machine, relocation);
+
+            let mut symbol_address = || match symbol {
+                0 => Ok(0),
+                symbol => resolve(symbol).ok_or(ApplyRelocationsError::UnresolvedSymbol(symbol)),
+            };
+            let value = match (machine, kind) {
+                (Machine::X86_64, R_X86_64_NONE | R_X86_64_IRELATIVE)
+                | (Machine::AARCH64, R_AARCH64_NONE | R_AARCH64_IRELATIVE) => continue,
+                (Machine::X86_64, R_X86_64_RELATIVE) | (Machine::AARCH64, R_AARCH64_RELATIVE) => {
+                    bias.wrapping_add_signed(addend()?)
+                }
+                (Machine::X86_64, R_X86_64_GLOB_DAT | R_X86_64_JUMP_SLOT) => symbol_address()?,
+                (Machine::X86_64, R_X86_64_64)
+                | (Machine::AARCH64, R_AARCH64_ABS64 | R_AARCH64_GLOB_DAT | R_AARCH64_JUMP_SLOT) => {
+                    symbol_address()?.wrapping_add_signed(addend()?)
+                }
+                _ => return Err(ApplyRelocationsError::UnsupportedRelocationType(kind)),
+            };
+
+            write(RelocationWrite {
+                address: offset.wrapping_add(bias),
+                value,
+                size: relocation_field(machine, kind)
+                    .ok_or(ApplyRelocationsError::UnsupportedRelocationType(kind))?
+                    .size,
+            })
+            .map_err(ApplyRelocationsError::WriteError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the [`R_X86_64_IRELATIVE`] and [`R_AARCH64_IRELATIVE`]
+    /// relocations in the [`ElfDynamicTag::RELA_TABLE`], [`ElfDynamicTag::REL_TABLE`] and
+    /// [`ElfDynamicTag::JMP_REL`] tables of this [`ElfFile`], with their addresses offset by
+    /// `bias`.
+    ///
+    /// These relocations are skipped by [`ElfFile::apply_relocations`], as their resolvers can
+    /// only be called once the image has been mapped and relocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RelocationTablesError`] if the relocation tables could not be located.
+    pub fn irelative_relocations(
+        &self,
+        bias: u64,
+    ) -> Result<IRelativeRelocations<'slice, C, E>, RelocationTablesError> {
+        Ok(IRelativeRelocations {
+            file: *self,
+            bias,
+            tables: self.relocation_tables()?,
+            table: 0,
+            index: 0,
+        })
+    }
+
+    /// Returns the [`ElfDynamicTag::RELA_TABLE`], [`ElfDynamicTag::REL_TABLE`] and
+    /// [`ElfDynamicTag::JMP_REL`] tables described by the [`SegmentType::DYNAMIC`] segment of
+    /// this [`ElfFile`].
+    fn relocation_tables(
+        &self,
+    ) -> Result<[Option<ElfRelocationTable<'slice, C, E>>; 3], RelocationTablesError> {
+        let Some(dynamic) = self.segments_of_type(SegmentType::DYNAMIC).next() else {
+            return Ok([None, None, None]);
+        };
+        let (data, _) = dynamic.segment_data(self)?;
+
+        let dynamic_table = ElfDynamicTable::parse(data, self.class, self.encoding);
+        let mut values = [None; 9];
+        let tags = [
+            ElfDynamicTag::RELA_TABLE,
+            ElfDynamicTag::RELA_SIZE,
+            ElfDynamicTag::RELA_ENTRY_SIZE,
+            ElfDynamicTag::REL_TABLE,
+            ElfDynamicTag::REL_SIZE,
+            ElfDynamicTag::REL_ENTRY_SIZE,
+            ElfDynamicTag::JMP_REL,
+            ElfDynamicTag::PLT_REL_SIZE,
+            ElfDynamicTag::PLT_REL,
+        ];
+        for entry in dynamic_table.iter() {
+            if let Some(index) = tags.iter().position(|&tag| entry.tag() == Some(tag)) {
+                values[index] = Some(entry.value());
+            }
+        }
+
+        let [rela, rela_size, rela_entry_size, rel, rel_size, rel_entry_size, jmp_rel, plt_rel_size, plt_rel] =
+            values;
+        let jmp_rel_has_addend = match plt_rel.and_then(|value| i32::try_from(value).ok()) {
+            Some(value) if value == ElfDynamicTag::RELA_TABLE.0 => true,
+            Some(value) if value == ElfDynamicTag::REL_TABLE.0 => false,
+            _ if jmp_rel.is_none() => false,
+            _ => return Err(RelocationTablesError::InvalidTable),
+        };
+        let table = |address: Option<u64>,
+                     size: Option<u64>,
+                     recorded_entry_size: Option<u64>,
+                     has_addend: bool|
+         -> Result<Option<ElfRelocationTable<'slice, C, E>>, RelocationTablesError> {
+            let Some(address) = address else {
+                return Ok(None);
+            };
+
+            let entries = size
+                .and_then(|size| self.vaddr_bytes(address, size))
+                .ok_or(RelocationTablesError::InvalidTable)?;
+            let table = ElfRelocationTable::parse(
+                entries,
+                has_addend,
+                self.header().machine(),
+                self.class,
+                self.encoding,
+            )
+            .map_err(|_| RelocationTablesError::InvalidTable)?;
+            if recorded_entry_size.is_some_and(|size| size != table.entry_size() as u64) {
+                return Err(RelocationTablesError::InvalidTable);
+            }
+
+            Ok(Some(table))
+        };
+
+        Ok([
+            table(rela, rela_size, rela_entry_size, true)?,
+            table(rel, rel_size, rel_entry_size, false)?,
+            table(jmp_rel, plt_rel_size, None, jmp_rel_has_addend)?,
+        ])
+    }
+
+    /// Returns the addend of `relocation`, reading its implicit addend from this [`ElfFile`] if
+    /// it has no explicit addend.
+    fn relocation_addend(
+        &self,
+        machine: Machine,
+        relocation: ElfRelocation<'slice, C, E>,
+    ) -> Result<i64, ReadImplicitAddendError> {
+        match relocation.addend() {
+            Some(addend) => Ok(addend),
+            None => read_implicit_addend(
+                machine,
+                relocation.relocation_type(),
+                self.slice,
+                self.vaddr_to_file_offset(relocation.offset())
+                    .ok_or(ReadImplicitAddendError::OutOfBounds)?,
+                self.encoding,
+            ),
+        }
+    }
+}
+
+/// An iterator over the [`R_X86_64_IRELATIVE`] and [`R_AARCH64_IRELATIVE`] relocations of an
+/// [`ElfFile`].
+pub struct IRelativeRelocations<'slice, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfFile`] whose relocations are yielded.
+    file: ElfFile<'slice, C, E>,
+    /// The value added to the addresses of each relocation.
+    bias: u64,
+    /// The relocation tables of [`IRelativeRelocations::file`].
+    tables: [Option<ElfRelocationTable<'slice, C, E>>; 3],
+    /// The index of the table currently being searched.
+    table: usize,
+    /// The index of the next entry of the table currently being searched.
+    index: usize,
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> Iterator for IRelativeRelocations<'slice, C, E> {
+    type Item = Result<IRelative, ReadImplicitAddendError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let machine = self.file.header().machine();
+        let irelative = match machine {
+            Machine::X86_64 => R_X86_64_IRELATIVE,
+            Machine::AARCH64 => R_AARCH64_IRELATIVE,
+            _ => return None,
+        };
+
+        loop {
+            let table = self.tables.get(self.table)?;
+            let Some(relocation) = table.and_then(|table| table.get(self.index)) else {
+                self.table = self.table.saturating_add(1);
+                self.index = 0;
+                continue;
+            };
+            self.index = self.index.saturating_add(1);
+
+            if relocation.relocation_type() != irelative {
+                continue;
+            }
+
+            let addend = match self.file.relocation_addend(machine, relocation) {
+                Ok(addend) => addend,
+                Err(error) => return Some(Err(error)),
+            };
+            return Some(Ok(IRelative {
+                target: relocation.offset().wrapping_add(self.bias),
+                resolver: self.bias.wrapping_add_signed(addend),
+            }));
+        }
+    }
+}
+
+/// An [`R_X86_64_IRELATIVE`] or [`R_AARCH64_IRELATIVE`] relocation, which is applied by storing
+/// the value returned by the function at [`IRelative::resolver`] to [`IRelative::target`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct IRelative {
+    /// The runtime address of the field to write.
+    pub target: u64,
+    /// The runtime address of the resolver function.
+    pub resolver: u64,
+}
+
+/// A value computed by [`ElfFile::apply_relocations`] that must be written to the loaded image.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct RelocationWrite {
+    /// The runtime address of the field to write.
+    pub address: u64,
+    /// The value to write, which must be truncated to [`RelocationWrite::size`] bytes.
+    pub value: u64,
+    /// The size, in bytes, of the field to write.
+    pub size: usize,
+}
+
+/// Various errors that can occur while applying relocations using
+/// [`ElfFile::apply_relocations`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ApplyRelocationsError<E> {
+    /// Relocations for the [`Machine`] of the [`ElfFile`] are not supported.
+    UnsupportedMachine,
+    /// An error occurred while reading the [`SegmentType::DYNAMIC`] segment.
+    SegmentDataError(SegmentDataError),
+    /// A relocation table is not backed by the file, is missing its size, or has entries of an
+    /// unexpected size.
+    InvalidTable,
+    /// A relocation has a type that is not supported.
+    UnsupportedRelocationType(u32),
+    /// The symbol with the given index could not be resolved.
+    UnresolvedSymbol(u32),
+    /// An error occurred while reading an implicit addend.
+    ReadImplicitAddendError(ReadImplicitAddendError),
+    /// An error occurred while writing a computed value.
+    WriteError(E),
+}
+
+impl<E> From<RelocationTablesError> for ApplyRelocationsError<E> {
+    fn from(value: RelocationTablesError) -> Self {
+        match value {
+            RelocationTablesError::SegmentDataError(error) => Self::SegmentDataError(error),
+            RelocationTablesError::InvalidTable => Self::InvalidTable,
+        }
+    }
+}
+
+impl<E> From<ReadImplicitAddendError> for ApplyRelocationsError<E> {
+    fn from(value: ReadImplicitAddendError) -> Self {
+        Self::ReadImplicitAddendError(value)
+    }
+}
+
+/// Various errors that can occur while locating the relocation tables of an [`ElfFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum RelocationTablesError {
+    /// An error occurred while reading the [`SegmentType::DYNAMIC`] segment.
+    SegmentDataError(SegmentDataError),
+    /// A relocation table is not backed by the file, is missing its size, or has entries of an
+    /// unexpected size.
+    InvalidTable,
+}
+
+impl From<SegmentDataError> for RelocationTablesError {
+    fn from(value: SegmentDataError) -> Self {
+        Self::SegmentDataError(value)
+    }
+}
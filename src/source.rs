@@ -0,0 +1,439 @@
+//! Definitions and interfaces for parsing ELF files that are not completely held in memory.
+//!
+//! An [`ElfSource`] provides random access to the bytes of an ELF file, such as a block device or
+//! a firmware file protocol. [`SourceElfFile`] reads only the structures that are requested,
+//! copying each into a small fixed-size buffer so the borrowed views of this crate can be used on
+//! it.
+
+use core::mem;
+
+use crate::{
+    class::{Class, ClassParse},
+    elf_header::{ElfHeader, ParseElfHeaderError},
+    elf_ident::ElfIdent,
+    elf_program_header::ElfProgramHeader,
+    elf_section_header::ElfSectionHeader,
+    encoding::EncodingParse,
+    raw::{
+        elf_header::{Elf32Header, Elf64Header},
+        elf_ident::ElfIdent as RawElfIdent,
+        elf_program_header::{Elf32ProgramHeader, Elf64ProgramHeader},
+        elf_section_header::{Elf32SectionHeader, Elf64SectionHeader, SectionType},
+    },
+    TableIndexError,
+};
+
+/// A source of random-access bytes from which an ELF file can be read.
+pub trait ElfSource {
+    /// The error returned when a read fails.
+    type Error;
+
+    /// Fills `buffer` with the bytes located at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfSource::Error`] if the bytes could not be read, including when they extend
+    /// past the end of the source.
+    fn read_at(&mut self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<S: ElfSource + ?Sized> ElfSource for &mut S {
+    type Error = S::Error;
+
+    fn read_at(&mut self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).read_at(offset, buffer)
+    }
+}
+
+impl ElfSource for &[u8] {
+    type Error = SliceSourceError;
+
+    fn read_at(&mut self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        let start: usize = offset
+            .try_into()
+            .map_err(|_| SliceSourceError::OutOfBounds)?;
+        let end = start
+            .checked_add(buffer.len())
+            .ok_or(SliceSourceError::OutOfBounds)?;
+        let bytes = self.get(start..end).ok_or(SliceSourceError::OutOfBounds)?;
+
+        buffer.copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Various errors that can occur while reading from a byte slice used as an [`ElfSource`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SliceSourceError {
+    /// The requested bytes extend past the end of the slice.
+    OutOfBounds,
+}
+
+/// An ELF file whose structures are read on demand from an [`ElfSource`].
+pub struct SourceElfFile<S: ElfSource, C: ClassParse, E: EncodingParse> {
+    /// The [`ElfSource`] holding the ELF file.
+    source: S,
+    /// The bytes of the [`ElfHeader`].
+    header: [u8; mem::size_of::<Elf64Header>()],
+    /// The [`ClassParse`] used to parse the ELF file.
+    class: C,
+    /// The [`EncodingParse`] used to parse the ELF file.
+    encoding: E,
+}
+
+impl<S: ElfSource, C: ClassParse, E: EncodingParse> SourceElfFile<S, C, E> {
+    /// Reads and parses the [`ElfHeader`] of the ELF file held by `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseSourceElfFileError::Read`] if the [`ElfHeader`] could not be read from
+    /// `source`, and [`ParseSourceElfFileError::ParseElfHeaderError`] if it is invalid.
+    pub fn parse(mut source: S) -> Result<Self, ParseSourceElfFileError<S::Error>> {
+        let mut header = [0; mem::size_of::<Elf64Header>()];
+
+        let ident_size = mem::size_of::<RawElfIdent>();
+        source
+            .read_at(0, &mut header[..ident_size])
+            .map_err(ParseSourceElfFileError::Read)?;
+        let elf_ident =
+            ElfIdent::<C, E>::parse(&header[..ident_size]).map_err(ParseElfHeaderError::from)?;
+        let (class, encoding) = (elf_ident.class_parse(), elf_ident.encoding_parse());
+
+        let header_size = match class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Header>(),
+            Class::Class64 => mem::size_of::<Elf64Header>(),
+        };
+        source
+            .read_at(0, &mut header[..header_size])
+            .map_err(ParseSourceElfFileError::Read)?;
+        ElfHeader::<C, E>::parse(&header[..header_size])?;
+
+        Ok(Self {
+            source,
+            header,
+            class,
+            encoding,
+        })
+    }
+
+    /// Returns the [`ElfHeader`] of this [`SourceElfFile`].
+    pub fn header(&self) -> ElfHeader<'_, C, E> {
+        ElfHeader {
+            slice: &self.header,
+            class: self.class,
+            encoding: self.encoding,
+        }
+    }
+
+    /// Returns the [`ElfSource`] holding this [`SourceElfFile`].
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    /// Fills `buffer` with the bytes of the underlying [`ElfSource`] located at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElfSource::Error`] if the bytes could not be read.
+    pub fn read_at(&mut self, offset: u64, buffer: &mut [u8]) -> Result<(), S::Error> {
+        self.source.read_at(offset, buffer)
+    }
+
+    /// Returns the number of [`ElfProgramHeader`]s of this [`SourceElfFile`].
+    pub fn program_header_count(&self) -> usize {
+        self.header().program_header_count().into()
+    }
+
+    /// Reads the [`ElfProgramHeader`] located at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceReadError::TableIndexError`] if `index` is out of bounds, and
+    /// [`SourceReadError::Read`] if the [`ElfProgramHeader`] could not be read.
+    pub fn read_program_header(
+        &mut self,
+        index: usize,
+    ) -> Result<ProgramHeaderBuf<C, E>, SourceReadError<S::Error>> {
+        let header = self.header();
+        let offset = table_entry_offset(
+            header.program_header_offset(),
+            header.program_header_entry_size().into(),
+            self.program_header_count(),
+            index,
+        )?;
+
+        let mut bytes = [0; mem::size_of::<Elf64ProgramHeader>()];
+        let size = match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32ProgramHeader>(),
+            Class::Class64 => mem::size_of::<Elf64ProgramHeader>(),
+        };
+        self.source
+            .read_at(offset, &mut bytes[..size])
+            .map_err(SourceReadError::Read)?;
+
+        Ok(ProgramHeaderBuf {
+            bytes,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Returns an iterator that reads each [`ElfProgramHeader`] of this [`SourceElfFile`].
+    pub fn program_headers(&mut self) -> ProgramHeaders<'_, S, C, E> {
+        ProgramHeaders {
+            file: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of [`ElfSectionHeader`]s of this [`SourceElfFile`], reading the first
+    /// [`ElfSectionHeader`] if the count is stored there.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceReadError`] if the first [`ElfSectionHeader`] is required but could not
+    /// be read.
+    pub fn section_header_count(&mut self) -> Result<usize, SourceReadError<S::Error>> {
+        let header = self.header();
+        let count = header.section_header_count();
+        if count != 0 || header.section_header_offset() == 0 {
+            return Ok(count.into());
+        }
+
+        let initial = self.read_section_header_unchecked(0)?;
+        initial
+            .get()
+            .size()
+            .try_into()
+            .map_err(|_| SourceReadError::TableIndexError(TableIndexError::OffsetOverflow))
+    }
+
+    /// Reads the [`ElfSectionHeader`] located at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceReadError::TableIndexError`] if `index` is out of bounds, and
+    /// [`SourceReadError::Read`] if the [`ElfSectionHeader`] could not be read.
+    pub fn read_section_header(
+        &mut self,
+        index: usize,
+    ) -> Result<SectionHeaderBuf<C, E>, SourceReadError<S::Error>> {
+        if index >= self.section_header_count()? {
+            return Err(SourceReadError::TableIndexError(
+                TableIndexError::IndexOutOfBounds,
+            ));
+        }
+
+        self.read_section_header_unchecked(index)
+    }
+
+    /// Reads the [`ElfSectionHeader`] located at `index` without checking `index` against the
+    /// number of [`ElfSectionHeader`]s.
+    fn read_section_header_unchecked(
+        &mut self,
+        index: usize,
+    ) -> Result<SectionHeaderBuf<C, E>, SourceReadError<S::Error>> {
+        let header = self.header();
+        let offset = table_entry_offset(
+            header.section_header_offset(),
+            header.section_header_entry_size().into(),
+            usize::MAX,
+            index,
+        )?;
+
+        let mut bytes = [0; mem::size_of::<Elf64SectionHeader>()];
+        let size = match self.class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32SectionHeader>(),
+            Class::Class64 => mem::size_of::<Elf64SectionHeader>(),
+        };
+        self.source
+            .read_at(offset, &mut bytes[..size])
+            .map_err(SourceReadError::Read)?;
+
+        Ok(SectionHeaderBuf {
+            bytes,
+            class: self.class,
+            encoding: self.encoding,
+        })
+    }
+
+    /// Reads the file image of the segment described by `program_header` into the start of
+    /// `buffer`, returning the number of bytes read.
+    ///
+    /// The remainder of the in-memory image of the segment is not touched, and so must be zeroed
+    /// by the caller when loading the segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceReadError::BufferTooSmall`] if `buffer` cannot hold the file image, and
+    /// [`SourceReadError::Read`] if it could not be read.
+    pub fn read_segment(
+        &mut self,
+        program_header: ElfProgramHeader<'_, C, E>,
+        buffer: &mut [u8],
+    ) -> Result<usize, SourceReadError<S::Error>> {
+        self.read_into(
+            program_header.file_offset(),
+            program_header.file_size(),
+            buffer,
+        )
+    }
+
+    /// Reads the contents of the section described by `section_header` into the start of
+    /// `buffer`, returning the number of bytes read.
+    ///
+    /// [`SectionType::NOBITS`] sections occupy no space in the file, and so read no bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceReadError::BufferTooSmall`] if `buffer` cannot hold the section, and
+    /// [`SourceReadError::Read`] if it could not be read.
+    pub fn read_section(
+        &mut self,
+        section_header: ElfSectionHeader<'_, C, E>,
+        buffer: &mut [u8],
+    ) -> Result<usize, SourceReadError<S::Error>> {
+        if section_header.kind() == SectionType::NOBITS {
+            return Ok(0);
+        }
+
+        self.read_into(section_header.offset(), section_header.size(), buffer)
+    }
+
+    /// Reads the `size` bytes located at `offset` into the start of `buffer`.
+    fn read_into(
+        &mut self,
+        offset: u64,
+        size: u64,
+        buffer: &mut [u8],
+    ) -> Result<usize, SourceReadError<S::Error>> {
+        let size: usize = size
+            .try_into()
+            .map_err(|_| SourceReadError::BufferTooSmall)?;
+        let buffer = buffer
+            .get_mut(..size)
+            .ok_or(SourceReadError::BufferTooSmall)?;
+
+        self.source
+            .read_at(offset, buffer)
+            .map_err(SourceReadError::Read)?;
+        Ok(size)
+    }
+}
+
+/// Returns the offset of the entry at `index` of a table located at `offset` that holds
+/// `entry_count` entries of `entry_size` bytes.
+fn table_entry_offset(
+    offset: u64,
+    entry_size: usize,
+    entry_count: usize,
+    index: usize,
+) -> Result<u64, TableIndexError> {
+    if index >= entry_count {
+        return Err(TableIndexError::IndexOutOfBounds);
+    }
+
+    index
+        .checked_mul(entry_size)
+        .and_then(|entry_offset| u64::try_from(entry_offset).ok())
+        .and_then(|entry_offset| offset.checked_add(entry_offset))
+        .ok_or(TableIndexError::OffsetOverflow)
+}
+
+/// An [`ElfProgramHeader`] read from an [`ElfSource`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ProgramHeaderBuf<C: ClassParse, E: EncodingParse> {
+    /// The bytes of the [`ElfProgramHeader`].
+    bytes: [u8; mem::size_of::<Elf64ProgramHeader>()],
+    /// The [`ClassParse`] used to parse the [`ElfProgramHeader`].
+    class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfProgramHeader`].
+    encoding: E,
+}
+
+impl<C: ClassParse, E: EncodingParse> ProgramHeaderBuf<C, E> {
+    /// Returns the [`ElfProgramHeader`] held by this [`ProgramHeaderBuf`].
+    pub fn get(&self) -> ElfProgramHeader<'_, C, E> {
+        ElfProgramHeader {
+            slice: &self.bytes,
+            class: self.class,
+            encoding: self.encoding,
+        }
+    }
+}
+
+/// An [`ElfSectionHeader`] read from an [`ElfSource`].
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct SectionHeaderBuf<C: ClassParse, E: EncodingParse> {
+    /// The bytes of the [`ElfSectionHeader`].
+    bytes: [u8; mem::size_of::<Elf64SectionHeader>()],
+    /// The [`ClassParse`] used to parse the [`ElfSectionHeader`].
+    class: C,
+    /// The [`EncodingParse`] used to parse the [`ElfSectionHeader`].
+    encoding: E,
+}
+
+impl<C: ClassParse, E: EncodingParse> SectionHeaderBuf<C, E> {
+    /// Returns the [`ElfSectionHeader`] held by this [`SectionHeaderBuf`].
+    pub fn get(&self) -> ElfSectionHeader<'_, C, E> {
+        ElfSectionHeader {
+            slice: &self.bytes,
+            class: self.class,
+            encoding: self.encoding,
+        }
+    }
+}
+
+/// An iterator that reads each [`ElfProgramHeader`] of a [`SourceElfFile`].
+pub struct ProgramHeaders<'file, S: ElfSource, C: ClassParse, E: EncodingParse> {
+    /// The [`SourceElfFile`] being read.
+    file: &'file mut SourceElfFile<S, C, E>,
+    /// The index of the next [`ElfProgramHeader`].
+    index: usize,
+}
+
+impl<S: ElfSource, C: ClassParse, E: EncodingParse> Iterator for ProgramHeaders<'_, S, C, E> {
+    type Item = Result<ProgramHeaderBuf<C, E>, SourceReadError<S::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.file.program_header_count() {
+            return None;
+        }
+
+        let program_header = self.file.read_program_header(self.index);
+        self.index = self.index.saturating_add(1);
+        Some(program_header)
+    }
+}
+
+/// Various errors that can occur while parsing a [`SourceElfFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseSourceElfFileError<R> {
+    /// An error occurred while reading from the [`ElfSource`].
+    Read(R),
+    /// An error ocurred while parsing the [`ElfHeader`].
+    ParseElfHeaderError(ParseElfHeaderError),
+}
+
+impl<R> From<ParseElfHeaderError> for ParseSourceElfFileError<R> {
+    fn from(value: ParseElfHeaderError) -> Self {
+        Self::ParseElfHeaderError(value)
+    }
+}
+
+/// Various errors that can occur while reading a structure of a [`SourceElfFile`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SourceReadError<R> {
+    /// An error occurred while reading from the [`ElfSource`].
+    Read(R),
+    /// An error occurred while locating an entry of a header table.
+    TableIndexError(TableIndexError),
+    /// The provided buffer is too small to hold the requested bytes.
+    BufferTooSmall,
+}
+
+impl<R> From<TableIndexError> for SourceReadError<R> {
+    fn from(value: TableIndexError) -> Self {
+        Self::TableIndexError(value)
+    }
+}
@@ -0,0 +1,1191 @@
+//! Facilities for generating ELF files.
+//!
+//! The builders of this module collect entries and write them out laid out for a requested
+//! [`Class`] and encoded using a requested [`EncodingParse`], so that the same description can
+//! produce both 32-bit and 64-bit objects of either endianness.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::mem;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    class::{Class, ClassParse},
+    encoding::{Encoding, EncodingParse},
+    raw::{
+        elf_header::{Elf32Header, Elf64Header, ElfType, Machine, CURRENT_OBJECT_FILE_VERSION},
+        elf_ident::{Class as RawClass, ElfIdent, Encoding as RawEncoding},
+        elf_program_header::{Elf32ProgramHeader, Elf64ProgramHeader, SegmentFlags, SegmentType},
+        elf_relocation::{Elf32Rel, Elf32Rela, Elf64Rel, Elf64Rela},
+        elf_section_header::SectionType,
+        elf_symbol::{Elf32Symbol, Elf64Symbol, SymbolBinding, SymbolInfo},
+    },
+};
+
+/// The index of a symbol added to a [`SymbolTableBuilder`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SymbolId(pub u32);
+
+impl SymbolId {
+    /// The null symbol, which every symbol table starts with.
+    pub const NULL: Self = Self(0);
+}
+
+/// A symbol described to a [`SymbolTableBuilder`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct PendingSymbol {
+    /// The offset of the name of the symbol in the string table.
+    name: u32,
+    /// The value of the symbol.
+    value: u64,
+    /// The size of the symbol.
+    size: u64,
+    /// The type and binding of the symbol.
+    info: SymbolInfo,
+    /// The visibility of the symbol.
+    other: u8,
+    /// The index of the section the symbol is defined in relation to.
+    section_index: u16,
+}
+
+/// Builds a [`SectionType::SYMTAB`] or [`SectionType::DYNSYM`] section and its associated string
+/// table.
+///
+/// The ELF specification requires all [`SymbolBinding::LOCAL`] symbols to precede the other
+/// symbols, and so local symbols should be added first.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SymbolTableBuilder {
+    /// The contents of the string table.
+    strings: Vec<u8>,
+    /// The symbols added so far, excluding the null symbol.
+    symbols: Vec<PendingSymbol>,
+}
+
+#[cfg(feature = "alloc")]
+impl SymbolTableBuilder {
+    /// Creates a new [`SymbolTableBuilder`] holding only the null symbol.
+    pub fn new() -> Self {
+        Self {
+            strings: alloc::vec![0],
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Adds a symbol named `name`, returning its [`SymbolId`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string table or the symbol table grows beyond the 4 GiB addressable by
+    /// their indices.
+    pub fn add(
+        &mut self,
+        name: &[u8],
+        value: u64,
+        size: u64,
+        info: SymbolInfo,
+        other: u8,
+        section_index: u16,
+    ) -> SymbolId {
+        let name = if name.is_empty() {
+            0
+        } else {
+            let offset = u32::try_from(self.strings.len()).expect("string table too large");
+            self.strings.extend_from_slice(name);
+            self.strings.push(0);
+            offset
+        };
+
+        self.symbols.push(PendingSymbol {
+            name,
+            value,
+            size,
+            info,
+            other,
+            section_index,
+        });
+        SymbolId(u32::try_from(self.symbols.len()).expect("symbol table too large"))
+    }
+
+    /// Returns the number of symbols in the table, including the null symbol.
+    pub fn len(&self) -> usize {
+        self.symbols.len().saturating_add(1)
+    }
+
+    /// Returns `true` if `symbol` refers to a symbol of this table.
+    pub fn contains(&self, symbol: SymbolId) -> bool {
+        usize::try_from(symbol.0).is_ok_and(|index| index < self.len())
+    }
+
+    /// Returns `true` if the table holds only the null symbol.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns the index of the first symbol that is not [`SymbolBinding::LOCAL`], which is the
+    /// value of the `info` field of the symbol table's section header.
+    pub fn first_non_local(&self) -> u32 {
+        let index = self
+            .symbols
+            .iter()
+            .position(|symbol| symbol.info.binding() != SymbolBinding::LOCAL)
+            .unwrap_or(self.symbols.len());
+
+        u32::try_from(index.saturating_add(1)).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the contents of the string table holding the names of the symbols.
+    pub fn string_table(&self) -> &[u8] {
+        &self.strings
+    }
+
+    /// Returns the size, in bytes, of a single symbol laid out for `class`.
+    pub fn entry_size<C: ClassParse>(class: C) -> usize {
+        match class.into_class() {
+            Class::Class32 => mem::size_of::<Elf32Symbol>(),
+            Class::Class64 => mem::size_of::<Elf64Symbol>(),
+        }
+    }
+
+    /// Writes the symbol table to the start of `buffer`, laid out for `class` and encoded using
+    /// `encoding`, returning the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteTableError::BufferTooSmall`] if `buffer` cannot hold the table, and
+    /// [`WriteTableError::ValueOutOfRange`] if the value or size of a symbol does not fit into
+    /// its representation in `class`.
+    pub fn write_to<C: ClassParse, E: EncodingParse>(
+        &self,
+        buffer: &mut [u8],
+        class: C,
+        encoding: E,
+    ) -> Result<usize, WriteTableError> {
+        let entry_size = Self::entry_size(class);
+        let total_size = self
+            .len()
+            .checked_mul(entry_size)
+            .ok_or(WriteTableError::BufferTooSmall)?;
+        let buffer = buffer
+            .get_mut(..total_size)
+            .ok_or(WriteTableError::BufferTooSmall)?;
+
+        let (null, entries) = buffer.split_at_mut(entry_size);
+        null.fill(0);
+        for (index, (symbol, entry)) in self
+            .symbols
+            .iter()
+            .zip(entries.chunks_exact_mut(entry_size))
+            .enumerate()
+        {
+            let index = index.saturating_add(1);
+            match class.into_class() {
+                Class::Class32 => {
+                    let narrow = |value: u64| {
+                        u32::try_from(value).map_err(|_| WriteTableError::ValueOutOfRange { index })
+                    };
+
+                    encoding.write_u32_at(mem::offset_of!(Elf32Symbol, name), symbol.name, entry);
+                    encoding.write_u32_at(
+                        mem::offset_of!(Elf32Symbol, value),
+                        narrow(symbol.value)?,
+                        entry,
+                    );
+                    encoding.write_u32_at(
+                        mem::offset_of!(Elf32Symbol, size),
+                        narrow(symbol.size)?,
+                        entry,
+                    );
+                    encoding.write_u8_at(mem::offset_of!(Elf32Symbol, info), symbol.info.0, entry);
+                    encoding.write_u8_at(mem::offset_of!(Elf32Symbol, other), symbol.other, entry);
+                    encoding.write_u16_at(
+                        mem::offset_of!(Elf32Symbol, section_index),
+                        symbol.section_index,
+                        entry,
+                    );
+                }
+                Class::Class64 => {
+                    encoding.write_u32_at(mem::offset_of!(Elf64Symbol, name), symbol.name, entry);
+                    encoding.write_u8_at(mem::offset_of!(Elf64Symbol, info), symbol.info.0, entry);
+                    encoding.write_u8_at(mem::offset_of!(Elf64Symbol, other), symbol.other, entry);
+                    encoding.write_u16_at(
+                        mem::offset_of!(Elf64Symbol, section_index),
+                        symbol.section_index,
+                        entry,
+                    );
+                    encoding.write_u64_at(mem::offset_of!(Elf64Symbol, value), symbol.value, entry);
+                    encoding.write_u64_at(mem::offset_of!(Elf64Symbol, size), symbol.size, entry);
+                }
+            }
+        }
+
+        Ok(total_size)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for SymbolTableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A relocation described to a [`RelocationBuilder`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct PendingRelocation {
+    /// The location at which to apply the relocation.
+    offset: u64,
+    /// The symbol referenced by the relocation.
+    symbol: SymbolId,
+    /// The machine-specific type of the relocation.
+    kind: u32,
+    /// The addend of the relocation.
+    addend: i64,
+}
+
+/// Builds a [`SectionType::RELA`] or [`SectionType::REL`] section, whose entries reference
+/// symbols of a [`SymbolTableBuilder`].
+///
+/// The written entries may equally serve as the range described by the `DT_RELA` or `DT_REL`
+/// dynamic entries.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RelocationBuilder {
+    /// Whether the entries carry an explicit addend.
+    explicit_addends: bool,
+    /// The relocations added so far.
+    relocations: Vec<PendingRelocation>,
+}
+
+#[cfg(feature = "alloc")]
+impl RelocationBuilder {
+    /// Creates a new [`RelocationBuilder`] for a [`SectionType::RELA`] section.
+    pub fn rela() -> Self {
+        Self {
+            explicit_addends: true,
+            relocations: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`RelocationBuilder`] for a [`SectionType::REL`] section.
+    ///
+    /// The addends of such relocations are stored in the relocated field, and so every
+    /// relocation must have an addend of zero.
+    pub fn rel() -> Self {
+        Self {
+            explicit_addends: false,
+            relocations: Vec::new(),
+        }
+    }
+
+    /// Adds a relocation of type `kind` at `offset` that references `symbol`.
+    pub fn add(&mut self, offset: u64, symbol: SymbolId, kind: u32, addend: i64) {
+        self.relocations.push(PendingRelocation {
+            offset,
+            symbol,
+            kind,
+            addend,
+        });
+    }
+
+    /// Returns the number of relocations added.
+    pub fn len(&self) -> usize {
+        self.relocations.len()
+    }
+
+    /// Returns `true` if no relocations have been added.
+    pub fn is_empty(&self) -> bool {
+        self.relocations.is_empty()
+    }
+
+    /// Returns the [`SectionType`] of the section this [`RelocationBuilder`] builds.
+    pub fn section_type(&self) -> SectionType {
+        if self.explicit_addends {
+            SectionType::RELA
+        } else {
+            SectionType::REL
+        }
+    }
+
+    /// Returns the size, in bytes, of a single relocation laid out for `class`.
+    pub fn entry_size<C: ClassParse>(&self, class: C) -> usize {
+        match (class.into_class(), self.explicit_addends) {
+            (Class::Class32, false) => mem::size_of::<Elf32Rel>(),
+            (Class::Class32, true) => mem::size_of::<Elf32Rela>(),
+            (Class::Class64, false) => mem::size_of::<Elf64Rel>(),
+            (Class::Class64, true) => mem::size_of::<Elf64Rela>(),
+        }
+    }
+
+    /// Writes the relocations to the start of `buffer`, laid out for `class` and encoded using
+    /// `encoding`, returning the number of bytes written.
+    ///
+    /// `symbols` is the [`SymbolTableBuilder`] of the symbol table the section is linked to,
+    /// which every referenced [`SymbolId`] must belong to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteTableError::BufferTooSmall`] if `buffer` cannot hold the relocations,
+    /// [`WriteTableError::UnknownSymbol`] if a relocation references a symbol that is not part of
+    /// `symbols`, and [`WriteTableError::ValueOutOfRange`] if a field of a relocation does not
+    /// fit into its representation in `class`, or if a relocation of a [`SectionType::REL`]
+    /// section has a non-zero addend.
+    pub fn write_to<C: ClassParse, E: EncodingParse>(
+        &self,
+        buffer: &mut [u8],
+        symbols: &SymbolTableBuilder,
+        class: C,
+        encoding: E,
+    ) -> Result<usize, WriteTableError> {
+        let entry_size = self.entry_size(class);
+        let total_size = self
+            .len()
+            .checked_mul(entry_size)
+            .ok_or(WriteTableError::BufferTooSmall)?;
+        let buffer = buffer
+            .get_mut(..total_size)
+            .ok_or(WriteTableError::BufferTooSmall)?;
+
+        for (index, (relocation, entry)) in self
+            .relocations
+            .iter()
+            .zip(buffer.chunks_exact_mut(entry_size))
+            .enumerate()
+        {
+            if !symbols.contains(relocation.symbol) {
+                return Err(WriteTableError::UnknownSymbol { index });
+            }
+
+            let out_of_range = WriteTableError::ValueOutOfRange { index };
+            if !self.explicit_addends && relocation.addend != 0 {
+                return Err(out_of_range);
+            }
+
+            match class.into_class() {
+                Class::Class32 => {
+                    let kind = u8::try_from(relocation.kind).map_err(|_| out_of_range)?;
+                    if relocation.symbol.0 > 0x00FF_FFFF {
+                        return Err(out_of_range);
+                    }
+                    let info = relocation.symbol.0 << 8 | u32::from(kind);
+
+                    encoding.write_u32_at(
+                        mem::offset_of!(Elf32Rela, offset),
+                        u32::try_from(relocation.offset).map_err(|_| out_of_range)?,
+                        entry,
+                    );
+                    encoding.write_u32_at(mem::offset_of!(Elf32Rela, info), info, entry);
+                    if self.explicit_addends {
+                        let addend = i32::try_from(relocation.addend).map_err(|_| out_of_range)?;
+                        encoding.write_u32_at(
+                            mem::offset_of!(Elf32Rela, addend),
+                            addend as u32,
+                            entry,
+                        );
+                    }
+                }
+                Class::Class64 => {
+                    let info = u64::from(relocation.symbol.0) << 32 | u64::from(relocation.kind);
+
+                    encoding.write_u64_at(
+                        mem::offset_of!(Elf64Rela, offset),
+                        relocation.offset,
+                        entry,
+                    );
+                    encoding.write_u64_at(mem::offset_of!(Elf64Rela, info), info, entry);
+                    if self.explicit_addends {
+                        encoding.write_u64_at(
+                            mem::offset_of!(Elf64Rela, addend),
+                            relocation.addend as u64,
+                            entry,
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(total_size)
+    }
+}
+
+/// Various errors that can occur while writing a table built by one of the builders of this
+/// module.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum WriteTableError {
+    /// The given buffer was too small to contain the table.
+    BufferTooSmall,
+    /// A field of the entry at `index` does not fit into the requested class.
+    ValueOutOfRange {
+        /// The index of the entry that could not be written.
+        index: usize,
+    },
+    /// The entry at `index` references a symbol that is not part of the linked symbol table.
+    UnknownSymbol {
+        /// The index of the entry that could not be written.
+        index: usize,
+    },
+}
+
+/// Produces a minimal [`ElfType::EXECUTABLE`] image for `machine`, laid out for `class` and
+/// encoded using `encoding`, whose entry point is the start of `entry_code`.
+///
+/// The image consists of the ELF header, a program header table holding a single readable and
+/// executable [`SegmentType::LOAD`] segment, and `entry_code`. The segment covers the entire image
+/// and is loaded at `base_vaddr`, which must therefore be aligned to 4 KiB pages. No section header
+/// table is emitted.
+///
+/// # Errors
+///
+/// Returns [`WriteExecutableError::UnalignedBaseAddress`] if `base_vaddr` is not page aligned, and
+/// [`WriteExecutableError::ValueOutOfRange`] if an address or size of the image does not fit into
+/// its representation in `class`.
+#[cfg(feature = "alloc")]
+pub fn simple_executable<C: ClassParse, E: EncodingParse>(
+    machine: Machine,
+    entry_code: &[u8],
+    base_vaddr: u64,
+    class: C,
+    encoding: E,
+) -> Result<Vec<u8>, WriteExecutableError> {
+    /// The alignment of the emitted [`SegmentType::LOAD`] segment.
+    const SEGMENT_ALIGNMENT: u64 = 0x1000;
+
+    if base_vaddr.checked_rem(SEGMENT_ALIGNMENT) != Some(0) {
+        return Err(WriteExecutableError::UnalignedBaseAddress);
+    }
+
+    let (header_size, program_header_size) = match class.into_class() {
+        Class::Class32 => (
+            mem::size_of::<Elf32Header>(),
+            mem::size_of::<Elf32ProgramHeader>(),
+        ),
+        Class::Class64 => (
+            mem::size_of::<Elf64Header>(),
+            mem::size_of::<Elf64ProgramHeader>(),
+        ),
+    };
+    let code_offset = header_size
+        .checked_add(program_header_size)
+        .ok_or(WriteExecutableError::ValueOutOfRange)?;
+    let image_size = code_offset
+        .checked_add(entry_code.len())
+        .ok_or(WriteExecutableError::ValueOutOfRange)?;
+    let entry = u64::try_from(code_offset)
+        .ok()
+        .and_then(|offset| base_vaddr.checked_add(offset))
+        .ok_or(WriteExecutableError::ValueOutOfRange)?;
+    let segment_size =
+        u64::try_from(image_size).map_err(|_| WriteExecutableError::ValueOutOfRange)?;
+    base_vaddr
+        .checked_add(segment_size)
+        .ok_or(WriteExecutableError::ValueOutOfRange)?;
+
+    let mut image = alloc::vec![0; image_size];
+    image[..ElfIdent::MAGIC_BYTES.len()].copy_from_slice(&ElfIdent::MAGIC_BYTES);
+    image[mem::offset_of!(ElfIdent, class)] = match class.into_class() {
+        Class::Class32 => RawClass::CLASS32.0,
+        Class::Class64 => RawClass::CLASS64.0,
+    };
+    image[mem::offset_of!(ElfIdent, data)] = match encoding.into_encoding() {
+        Encoding::TwosComplementLittleEndian => RawEncoding::LITTLE_ENDIAN_TWOS.0,
+        Encoding::TwosComplementBigEndian => RawEncoding::BIG_ENDIAN_TWOS.0,
+    };
+    image[mem::offset_of!(ElfIdent, header_version)] = ElfIdent::CURRENT_VERSION;
+
+    let (header, rest) = image.split_at_mut(header_size);
+    let (program_header, code) = rest.split_at_mut(program_header_size);
+    code.copy_from_slice(entry_code);
+
+    match class.into_class() {
+        Class::Class32 => {
+            let narrow = |value: u64| {
+                u32::try_from(value).map_err(|_| WriteExecutableError::ValueOutOfRange)
+            };
+            let segment_size = narrow(segment_size)?;
+            let base_vaddr = narrow(base_vaddr)?;
+            base_vaddr
+                .checked_add(segment_size)
+                .ok_or(WriteExecutableError::ValueOutOfRange)?;
+
+            encoding.write_u16_at(
+                mem::offset_of!(Elf32Header, r#type),
+                ElfType::EXECUTABLE.0,
+                header,
+            );
+            encoding.write_u16_at(mem::offset_of!(Elf32Header, machine), machine.0, header);
+            encoding.write_u32_at(
+                mem::offset_of!(Elf32Header, object_file_version),
+                CURRENT_OBJECT_FILE_VERSION,
+                header,
+            );
+            encoding.write_u32_at(mem::offset_of!(Elf32Header, entry), narrow(entry)?, header);
+            encoding.write_u32_at(
+                mem::offset_of!(Elf32Header, program_header_offset),
+                header_size as u32,
+                header,
+            );
+            encoding.write_u16_at(
+                mem::offset_of!(Elf32Header, elf_header_size),
+                header_size as u16,
+                header,
+            );
+            encoding.write_u16_at(
+                mem::offset_of!(Elf32Header, program_header_entry_size),
+                program_header_size as u16,
+                header,
+            );
+            encoding.write_u16_at(
+                mem::offset_of!(Elf32Header, program_header_count),
+                1,
+                header,
+            );
+
+            for (offset, value) in [
+                (
+                    mem::offset_of!(Elf32ProgramHeader, r#type),
+                    SegmentType::LOAD.0,
+                ),
+                (
+                    mem::offset_of!(Elf32ProgramHeader, virtual_address),
+                    base_vaddr,
+                ),
+                (
+                    mem::offset_of!(Elf32ProgramHeader, physical_address),
+                    base_vaddr,
+                ),
+                (mem::offset_of!(Elf32ProgramHeader, file_size), segment_size),
+                (
+                    mem::offset_of!(Elf32ProgramHeader, memory_size),
+                    segment_size,
+                ),
+                (
+                    mem::offset_of!(Elf32ProgramHeader, flags),
+                    SegmentFlags::READ.0 | SegmentFlags::EXECUTE.0,
+                ),
+                (
+                    mem::offset_of!(Elf32ProgramHeader, alignment),
+                    SEGMENT_ALIGNMENT as u32,
+                ),
+            ] {
+                encoding.write_u32_at(offset, value, program_header);
+            }
+        }
+        Class::Class64 => {
+            encoding.write_u16_at(
+                mem::offset_of!(Elf64Header, r#type),
+                ElfType::EXECUTABLE.0,
+                header,
+            );
+            encoding.write_u16_at(mem::offset_of!(Elf64Header, machine), machine.0, header);
+            encoding.write_u32_at(
+                mem::offset_of!(Elf64Header, object_file_version),
+                CURRENT_OBJECT_FILE_VERSION,
+                header,
+            );
+            encoding.write_u64_at(mem::offset_of!(Elf64Header, entry), entry, header);
+            encoding.write_u64_at(
+                mem::offset_of!(Elf64Header, program_header_offset),
+                header_size as u64,
+                header,
+            );
+            encoding.write_u16_at(
+                mem::offset_of!(Elf64Header, elf_header_size),
+                header_size as u16,
+                header,
+            );
+            encoding.write_u16_at(
+                mem::offset_of!(Elf64Header, program_header_entry_size),
+                program_header_size as u16,
+                header,
+            );
+            encoding.write_u16_at(
+                mem::offset_of!(Elf64Header, program_header_count),
+                1,
+                header,
+            );
+
+            encoding.write_u32_at(
+                mem::offset_of!(Elf64ProgramHeader, r#type),
+                SegmentType::LOAD.0,
+                program_header,
+            );
+            encoding.write_u32_at(
+                mem::offset_of!(Elf64ProgramHeader, flags),
+                SegmentFlags::READ.0 | SegmentFlags::EXECUTE.0,
+                program_header,
+            );
+            for (offset, value) in [
+                (
+                    mem::offset_of!(Elf64ProgramHeader, virtual_address),
+                    base_vaddr,
+                ),
+                (
+                    mem::offset_of!(Elf64ProgramHeader, physical_address),
+                    base_vaddr,
+                ),
+                (mem::offset_of!(Elf64ProgramHeader, file_size), segment_size),
+                (
+                    mem::offset_of!(Elf64ProgramHeader, memory_size),
+                    segment_size,
+                ),
+                (
+                    mem::offset_of!(Elf64ProgramHeader, alignment),
+                    SEGMENT_ALIGNMENT,
+                ),
+            ] {
+                encoding.write_u64_at(offset, value, program_header);
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+/// Various errors that can occur while generating an executable using [`simple_executable`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum WriteExecutableError {
+    /// An address or size of the image does not fit into the requested class.
+    ValueOutOfRange,
+    /// The requested base address is not aligned to the alignment of the emitted segment.
+    UnalignedBaseAddress,
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    //! Round-trip tests that parse the images produced by the builders of this module back using
+    //! the readers of this crate.
+
+    use super::*;
+    use crate::{
+        class::{Class32, Class64},
+        elf_hash::sysv_hash,
+        encoding::{BigEndian, LittleEndian},
+        raw::{
+            elf_dynamic::ElfDynamicTag,
+            elf_relocation::{R_X86_64_64, R_X86_64_GLOB_DAT, R_X86_64_RELATIVE},
+            elf_section_header::Elf64SectionHeader,
+            elf_symbol::SymbolType,
+            elf_version::{
+                Elf64Verdaux, Elf64Verdef, Elf64Vernaux, Elf64Verneed, VersionFlags,
+                VersionRevision,
+            },
+        },
+        relocate::{ApplyRelocationsError, RelocationWrite},
+        ElfFile,
+    };
+
+    /// The address at which the test images are loaded.
+    const BASE: u64 = 0x40_0000;
+
+    /// The file offset of the payload passed to [`simple_executable`] by [`dynamic_image`].
+    const PAYLOAD_OFFSET: usize =
+        mem::size_of::<Elf64Header>() + mem::size_of::<Elf64ProgramHeader>();
+
+    /// The addresses of the three relocatable 8-byte slots of the images built by
+    /// [`dynamic_image`], which follow the space reserved for the second program header.
+    const SLOTS: [u64; 3] = {
+        let first = BASE + (PAYLOAD_OFFSET + mem::size_of::<Elf64ProgramHeader>()) as u64;
+        [first, first + 8, first + 16]
+    };
+
+    /// The [`SymbolInfo`] of a global function.
+    const GLOBAL_FUNCTION: SymbolInfo =
+        SymbolInfo(SymbolBinding::GLOBAL.0 << 4 | SymbolType::FUNCTION.0);
+
+    /// The [`SymbolInfo`] of a global object.
+    const GLOBAL_OBJECT: SymbolInfo =
+        SymbolInfo(SymbolBinding::GLOBAL.0 << 4 | SymbolType::OBJECT.0);
+
+    /// Builds a [`simple_executable`] laid out for `class` and encoded using `encoding`, and
+    /// checks that it parses back into the described image.
+    fn check_simple_executable<C: ClassParse, E: EncodingParse>(class: C, encoding: E) {
+        let code = [0x90, 0x90, 0xC3];
+        let image = simple_executable(Machine::X86_64, &code, BASE, class, encoding).unwrap();
+        let file = ElfFile::<C, E>::parse(&image).unwrap();
+
+        let header = file.header();
+        assert_eq!(header.elf_type(), ElfType::EXECUTABLE);
+        assert_eq!(header.machine(), Machine::X86_64);
+        assert_eq!(header.section_header_count(), 0);
+
+        let program_header_table = file.program_header_table().unwrap();
+        assert_eq!(program_header_table.len(), 1);
+        let segment = program_header_table.get_validated(0).unwrap();
+        assert_eq!(segment.segment_type(), SegmentType::LOAD);
+        assert_eq!(
+            segment.flags(),
+            SegmentFlags(SegmentFlags::READ.0 | SegmentFlags::EXECUTE.0)
+        );
+        assert_eq!(segment.virtual_address(), BASE);
+        assert_eq!(segment.file_offset(), 0);
+        assert_eq!(segment.file_size(), image.len() as u64);
+
+        assert_eq!(
+            file.vaddr_bytes(header.entry(), code.len() as u64),
+            Some(&code[..])
+        );
+    }
+
+    #[test]
+    fn simple_executable_round_trips() {
+        check_simple_executable(Class32, LittleEndian);
+        check_simple_executable(Class32, BigEndian);
+        check_simple_executable(Class64, LittleEndian);
+        check_simple_executable(Class64, BigEndian);
+    }
+
+    #[test]
+    fn simple_executable_rejects_unaligned_base_address() {
+        assert_eq!(
+            simple_executable(Machine::X86_64, &[0xC3], 0x40_0010, Class64, LittleEndian),
+            Err(WriteExecutableError::UnalignedBaseAddress)
+        );
+    }
+
+    /// Returns a [`SymbolTableBuilder`] holding the defined function `foo`, the undefined
+    /// function `bar` and the defined object `baz`, along with their [`SymbolId`]s.
+    fn symbols() -> (SymbolTableBuilder, [SymbolId; 3]) {
+        let mut symbols = SymbolTableBuilder::new();
+        let foo = symbols.add(b"foo", 0x40_1000, 0x10, GLOBAL_FUNCTION, 0, 1);
+        let bar = symbols.add(b"bar", 0, 0, GLOBAL_FUNCTION, 0, 0);
+        let baz = symbols.add(b"baz", 0x40_2000, 8, GLOBAL_OBJECT, 0, 1);
+        (symbols, [foo, bar, baz])
+    }
+
+    /// Appends `bytes` to `payload` at the next 8-byte boundary, returning their file offset.
+    fn place(payload: &mut Vec<u8>, bytes: &[u8]) -> usize {
+        payload.resize(payload.len().next_multiple_of(8), 0);
+        let offset = PAYLOAD_OFFSET.checked_add(payload.len()).unwrap();
+        payload.extend_from_slice(bytes);
+        offset
+    }
+
+    /// Returns the address at which the byte at file `offset` of an image built by
+    /// [`dynamic_image`] is loaded.
+    fn address(offset: usize) -> u64 {
+        BASE.checked_add(offset as u64).unwrap()
+    }
+
+    /// Appends `string` to `strings`, returning its offset.
+    fn add_string(strings: &mut Vec<u8>, string: &[u8]) -> u32 {
+        let offset = u32::try_from(strings.len()).unwrap();
+        strings.extend_from_slice(string);
+        strings.push(0);
+        offset
+    }
+
+    /// Builds a 64-bit little-endian [`simple_executable`] whose payload holds `symbols` as its
+    /// dynamic symbol table, a System V hash table, GNU version information and `relocations`,
+    /// all referenced by a [`SegmentType::DYNAMIC`] segment and described by section headers.
+    ///
+    /// `foo` is versioned as `FOO_1`, defined by `libfoo.so`, and `bar` as `BAR_1`, required from
+    /// `libbar.so.1`, while `baz` is unversioned.
+    fn dynamic_image(symbols: &SymbolTableBuilder, relocations: &RelocationBuilder) -> Vec<u8> {
+        let encoding = LittleEndian;
+        let mut payload = alloc::vec![0; mem::size_of::<Elf64ProgramHeader>()];
+        place(&mut payload, &[0; 24]);
+
+        let mut dynamic_symbols = alloc::vec![0; symbols.len() * 24];
+        symbols
+            .write_to(&mut dynamic_symbols, Class64, encoding)
+            .unwrap();
+        let dynamic_symbols = place(&mut payload, &dynamic_symbols);
+
+        let mut strings = symbols.string_table().to_vec();
+        let libfoo = add_string(&mut strings, b"libfoo.so");
+        let foo_1 = add_string(&mut strings, b"FOO_1");
+        let libbar = add_string(&mut strings, b"libbar.so.1");
+        let bar_1 = add_string(&mut strings, b"BAR_1");
+        let string_count = strings.len();
+        let strings = place(&mut payload, &strings);
+
+        let names: [&[u8]; 3] = [b"foo", b"bar", b"baz"];
+        let bucket_count = 2;
+        let mut buckets = [0u32; 2];
+        let mut chains = [0u32; 4];
+        for (index, name) in (1..).zip(names) {
+            let bucket = &mut buckets[sysv_hash(name).checked_rem(bucket_count).unwrap() as usize];
+            chains[index as usize] = *bucket;
+            *bucket = index;
+        }
+        let mut hash = Vec::new();
+        for word in [bucket_count, chains.len() as u32]
+            .into_iter()
+            .chain(buckets)
+            .chain(chains)
+        {
+            hash.extend_from_slice(&word.to_le_bytes());
+        }
+        let hash = place(&mut payload, &hash);
+
+        let mut versions = Vec::new();
+        for versym in [0u16, 2, 3, 1] {
+            versions.extend_from_slice(&versym.to_le_bytes());
+        }
+        let versions = place(&mut payload, &versions);
+
+        let definition_size = mem::size_of::<Elf64Verdef>() + mem::size_of::<Elf64Verdaux>();
+        let mut definitions = alloc::vec![0; 2 * definition_size];
+        for (index, (entry, (flags, name, name_offset))) in
+            (1..).zip(definitions.chunks_exact_mut(definition_size).zip([
+                (VersionFlags::BASE, &b"libfoo.so"[..], libfoo),
+                (VersionFlags(0), &b"FOO_1"[..], foo_1),
+            ]))
+        {
+            let (entry, auxiliary) = entry.split_at_mut(mem::size_of::<Elf64Verdef>());
+            encoding.write_u16_at(
+                mem::offset_of!(Elf64Verdef, version),
+                VersionRevision::CURRENT.0,
+                entry,
+            );
+            encoding.write_u16_at(mem::offset_of!(Elf64Verdef, flags), flags.0, entry);
+            encoding.write_u16_at(mem::offset_of!(Elf64Verdef, index), index, entry);
+            encoding.write_u16_at(mem::offset_of!(Elf64Verdef, auxiliary_count), 1, entry);
+            encoding.write_u32_at(
+                mem::offset_of!(Elf64Verdef, name_hash),
+                sysv_hash(name),
+                entry,
+            );
+            encoding.write_u32_at(
+                mem::offset_of!(Elf64Verdef, auxiliary_offset),
+                mem::size_of::<Elf64Verdef>() as u32,
+                entry,
+            );
+            encoding.write_u32_at(
+                mem::offset_of!(Elf64Verdef, next_offset),
+                if index == 1 {
+                    definition_size as u32
+                } else {
+                    0
+                },
+                entry,
+            );
+            encoding.write_u32_at(mem::offset_of!(Elf64Verdaux, name), name_offset, auxiliary);
+        }
+        let definitions_size = definitions.len();
+        let definitions = place(&mut payload, &definitions);
+
+        let mut requirements =
+            alloc::vec![0; mem::size_of::<Elf64Verneed>() + mem::size_of::<Elf64Vernaux>()];
+        let (entry, auxiliary) = requirements.split_at_mut(mem::size_of::<Elf64Verneed>());
+        encoding.write_u16_at(
+            mem::offset_of!(Elf64Verneed, version),
+            VersionRevision::CURRENT.0,
+            entry,
+        );
+        encoding.write_u16_at(mem::offset_of!(Elf64Verneed, auxiliary_count), 1, entry);
+        encoding.write_u32_at(mem::offset_of!(Elf64Verneed, file), libbar, entry);
+        encoding.write_u32_at(
+            mem::offset_of!(Elf64Verneed, auxiliary_offset),
+            mem::size_of::<Elf64Verneed>() as u32,
+            entry,
+        );
+        encoding.write_u32_at(
+            mem::offset_of!(Elf64Vernaux, name_hash),
+            sysv_hash(b"BAR_1"),
+            auxiliary,
+        );
+        encoding.write_u16_at(mem::offset_of!(Elf64Vernaux, other), 3, auxiliary);
+        encoding.write_u32_at(mem::offset_of!(Elf64Vernaux, name), bar_1, auxiliary);
+        let requirements_size = requirements.len();
+        let requirements = place(&mut payload, &requirements);
+
+        let mut relocation_bytes =
+            alloc::vec![0; relocations.len() * relocations.entry_size(Class64)];
+        relocations
+            .write_to(&mut relocation_bytes, symbols, Class64, encoding)
+            .unwrap();
+        let relocation_size = relocation_bytes.len();
+        let relocation_table = place(&mut payload, &relocation_bytes);
+
+        let (table_tag, size_tag, entry_size_tag) = match relocations.section_type() {
+            SectionType::RELA => (
+                ElfDynamicTag::RELA_TABLE,
+                ElfDynamicTag::RELA_SIZE,
+                ElfDynamicTag::RELA_ENTRY_SIZE,
+            ),
+            _ => (
+                ElfDynamicTag::REL_TABLE,
+                ElfDynamicTag::REL_SIZE,
+                ElfDynamicTag::REL_ENTRY_SIZE,
+            ),
+        };
+        let mut dynamic = Vec::new();
+        for (tag, value) in [
+            (ElfDynamicTag::HASH, address(hash)),
+            (ElfDynamicTag::STRING_TABLE, address(strings)),
+            (ElfDynamicTag::STRING_TABLE_SIZE, string_count as u64),
+            (ElfDynamicTag::SYMBOL_TABLE, address(dynamic_symbols)),
+            (ElfDynamicTag::SYMBOL_ENTRY_SIZE, 24),
+            (table_tag, address(relocation_table)),
+            (size_tag, relocation_size as u64),
+            (entry_size_tag, relocations.entry_size(Class64) as u64),
+            (ElfDynamicTag::NULL, 0),
+        ] {
+            dynamic.extend_from_slice(&i64::from(tag.0).to_le_bytes());
+            dynamic.extend_from_slice(&value.to_le_bytes());
+        }
+        let dynamic_size = dynamic.len();
+        let dynamic = place(&mut payload, &dynamic);
+
+        let section_header_size = mem::size_of::<Elf64SectionHeader>();
+        let mut section_headers = alloc::vec![0; 6 * section_header_size];
+        for (entry, (kind, offset, size, link, info, entry_size)) in section_headers
+            .chunks_exact_mut(section_header_size)
+            .skip(1)
+            .zip([
+                (
+                    SectionType::DYNSYM,
+                    dynamic_symbols,
+                    symbols.len() * 24,
+                    2,
+                    1,
+                    24,
+                ),
+                (SectionType::STRTAB, strings, string_count, 0, 0, 0),
+                (SectionType::GNU_VERSYM, versions, 8, 1, 0, 2),
+                (
+                    SectionType::GNU_VERDEF,
+                    definitions,
+                    definitions_size,
+                    2,
+                    2,
+                    0,
+                ),
+                (
+                    SectionType::GNU_VERNEED,
+                    requirements,
+                    requirements_size,
+                    2,
+                    1,
+                    0,
+                ),
+            ])
+        {
+            encoding.write_u32_at(mem::offset_of!(Elf64SectionHeader, kind), kind.0, entry);
+            encoding.write_u64_at(
+                mem::offset_of!(Elf64SectionHeader, address),
+                address(offset),
+                entry,
+            );
+            encoding.write_u64_at(
+                mem::offset_of!(Elf64SectionHeader, offset),
+                offset as u64,
+                entry,
+            );
+            encoding.write_u64_at(
+                mem::offset_of!(Elf64SectionHeader, size),
+                size as u64,
+                entry,
+            );
+            encoding.write_u32_at(mem::offset_of!(Elf64SectionHeader, link), link, entry);
+            encoding.write_u32_at(mem::offset_of!(Elf64SectionHeader, info), info, entry);
+            encoding.write_u64_at(
+                mem::offset_of!(Elf64SectionHeader, entry_size),
+                entry_size,
+                entry,
+            );
+        }
+        let section_headers = place(&mut payload, &section_headers);
+
+        let mut image =
+            simple_executable(Machine::X86_64, &payload, BASE, Class64, encoding).unwrap();
+        let (header, rest) = image.split_at_mut(mem::size_of::<Elf64Header>());
+        encoding.write_u16_at(
+            mem::offset_of!(Elf64Header, program_header_count),
+            2,
+            header,
+        );
+        encoding.write_u64_at(
+            mem::offset_of!(Elf64Header, section_header_offset),
+            section_headers as u64,
+            header,
+        );
+        encoding.write_u16_at(
+            mem::offset_of!(Elf64Header, section_header_entry_size),
+            section_header_size as u16,
+            header,
+        );
+        encoding.write_u16_at(
+            mem::offset_of!(Elf64Header, section_header_count),
+            6,
+            header,
+        );
+
+        let program_header = &mut rest[mem::size_of::<Elf64ProgramHeader>()..];
+        encoding.write_u32_at(
+            mem::offset_of!(Elf64ProgramHeader, r#type),
+            SegmentType::DYNAMIC.0,
+            program_header,
+        );
+        encoding.write_u32_at(
+            mem::offset_of!(Elf64ProgramHeader, flags),
+            SegmentFlags::READ.0,
+            program_header,
+        );
+        for (offset, value) in [
+            (
+                mem::offset_of!(Elf64ProgramHeader, file_offset),
+                dynamic as u64,
+            ),
+            (
+                mem::offset_of!(Elf64ProgramHeader, virtual_address),
+                address(dynamic),
+            ),
+            (
+                mem::offset_of!(Elf64ProgramHeader, physical_address),
+                address(dynamic),
+            ),
+            (
+                mem::offset_of!(Elf64ProgramHeader, file_size),
+                dynamic_size as u64,
+            ),
+            (
+                mem::offset_of!(Elf64ProgramHeader, memory_size),
+                dynamic_size as u64,
+            ),
+            (mem::offset_of!(Elf64ProgramHeader, alignment), 8),
+        ] {
+            encoding.write_u64_at(offset, value, program_header);
+        }
+
+        image
+    }
+
+    /// Applies the relocations of `file` with a bias of `bias`, resolving `foo` and `bar`, and
+    /// returns the values written along with the result.
+    fn apply(
+        file: ElfFile<Class64, LittleEndian>,
+        bias: u64,
+    ) -> (Vec<RelocationWrite>, Result<(), ApplyRelocationsError<()>>) {
+        let mut writes = Vec::new();
+        let result = file.apply_relocations(
+            bias,
+            |symbol| match symbol {
+                1 => Some(0xAAAA_0000),
+                2 => Some(0xBBBB_0000),
+                _ => None,
+            },
+            |write| {
+                writes.push(write);
+                Ok(())
+            },
+        );
+        (writes, result)
+    }
+
+    #[test]
+    fn rela_relocations_are_applied() {
+        let (symbols, [foo, bar, _]) = symbols();
+        let mut relocations = RelocationBuilder::rela();
+        relocations.add(SLOTS[0], SymbolId::NULL, R_X86_64_RELATIVE, 0x10);
+        relocations.add(SLOTS[1], foo, R_X86_64_64, 4);
+        relocations.add(SLOTS[2], bar, R_X86_64_GLOB_DAT, 0);
+
+        let image = dynamic_image(&symbols, &relocations);
+        let file = ElfFile::<Class64, LittleEndian>::parse(&image).unwrap();
+        let bias = 0x1000_0000;
+        let (writes, result) = apply(file, bias);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            writes,
+            [
+                RelocationWrite {
+                    address: SLOTS[0] + bias,
+                    value: bias + 0x10,
+                    size: 8,
+                },
+                RelocationWrite {
+                    address: SLOTS[1] + bias,
+                    value: 0xAAAA_0004,
+                    size: 8,
+                },
+                RelocationWrite {
+                    address: SLOTS[2] + bias,
+                    value: 0xBBBB_0000,
+                    size: 8,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rel_relocations_check_the_type_before_the_addend() {
+        let (symbols, _) = symbols();
+        let mut relocations = RelocationBuilder::rel();
+        relocations.add(SLOTS[0], SymbolId::NULL, R_X86_64_RELATIVE, 0);
+        relocations.add(SLOTS[1], SymbolId::NULL, 0xFF, 0);
+
+        let image = dynamic_image(&symbols, &relocations);
+        let file = ElfFile::<Class64, LittleEndian>::parse(&image).unwrap();
+        let (writes, result) = apply(file, 0x1000_0000);
+
+        assert_eq!(
+            writes,
+            [RelocationWrite {
+                address: SLOTS[0] + 0x1000_0000,
+                value: 0x1000_0000,
+                size: 8,
+            }]
+        );
+        assert_eq!(
+            result,
+            Err(ApplyRelocationsError::UnsupportedRelocationType(0xFF))
+        );
+    }
+
+    #[test]
+    fn dynamic_symbols_are_looked_up_by_hash() {
+        let (symbols, _) = symbols();
+        let image = dynamic_image(&symbols, &RelocationBuilder::rela());
+        let file = ElfFile::<Class64, LittleEndian>::parse(&image).unwrap();
+
+        assert_eq!(file.dynamic_symbol_count(), Some(4));
+        assert_eq!(
+            file.lookup_symbol(b"foo").map(|symbol| symbol.value()),
+            Some(0x40_1000)
+        );
+        assert_eq!(
+            file.merged_symbol(b"baz").map(|symbol| symbol.value()),
+            Some(0x40_2000)
+        );
+        assert!(file.lookup_symbol(b"bar").is_none());
+        assert!(file.merged_symbol(b"qux").is_none());
+    }
+
+    #[test]
+    fn symbol_versions_are_resolved() {
+        let (symbols, _) = symbols();
+        let image = dynamic_image(&symbols, &RelocationBuilder::rela());
+        let file = ElfFile::<Class64, LittleEndian>::parse(&image).unwrap();
+
+        let versions: Vec<_> = file
+            .versioned_dynamic_symbols()
+            .unwrap()
+            .map(|versioned| {
+                (
+                    versioned.symbol.name().unwrap(),
+                    versioned
+                        .version
+                        .map(|version| (version.index, version.name, version.file)),
+                )
+            })
+            .collect();
+        assert_eq!(
+            versions,
+            [
+                (&b""[..], None),
+                (&b"foo"[..], Some((2, &b"FOO_1"[..], None))),
+                (
+                    &b"bar"[..],
+                    Some((3, &b"BAR_1"[..], Some(&b"libbar.so.1"[..])))
+                ),
+                (&b"baz"[..], None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Definitions and interfaces for interacting with the ELF notes of Xen guest kernels.
+
+use crate::{
+    class::ClassParse,
+    encoding::EncodingParse,
+    raw::xen::{
+        ELF_NOTE_XEN, XEN_ELFNOTE_ENTRY, XEN_ELFNOTE_FEATURES, XEN_ELFNOTE_GUEST_OS,
+        XEN_ELFNOTE_GUEST_VERSION, XEN_ELFNOTE_HYPERCALL_PAGE, XEN_ELFNOTE_LOADER,
+        XEN_ELFNOTE_PADDR_OFFSET, XEN_ELFNOTE_PHYS32_ENTRY, XEN_ELFNOTE_VIRT_BASE,
+        XEN_ELFNOTE_XEN_VERSION,
+    },
+    ElfFile,
+};
+
+/// The information recorded by the [`ELF_NOTE_XEN`] notes of a Xen guest kernel.
+///
+/// Each field is [`None`] if the corresponding note is absent or malformed.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct XenNotes<'slice> {
+    /// The virtual address of the paravirtualized entry point, as recorded by the
+    /// [`XEN_ELFNOTE_ENTRY`] note.
+    pub entry: Option<u64>,
+    /// The virtual address of the hypercall page, as recorded by the
+    /// [`XEN_ELFNOTE_HYPERCALL_PAGE`] note.
+    pub hypercall_page: Option<u64>,
+    /// The virtual address at which the kernel expects to be mapped, as recorded by the
+    /// [`XEN_ELFNOTE_VIRT_BASE`] note.
+    pub virt_base: Option<u64>,
+    /// The offset between the virtual and physical addresses of the kernel, as recorded by the
+    /// [`XEN_ELFNOTE_PADDR_OFFSET`] note.
+    pub paddr_offset: Option<u64>,
+    /// The physical address of the PVH entry point, as recorded by the
+    /// [`XEN_ELFNOTE_PHYS32_ENTRY`] note.
+    pub phys32_entry: Option<u32>,
+    /// The name of the guest operating system, as recorded by the [`XEN_ELFNOTE_GUEST_OS`] note.
+    pub guest_os: Option<&'slice [u8]>,
+    /// The version of the guest operating system, as recorded by the
+    /// [`XEN_ELFNOTE_GUEST_VERSION`] note.
+    pub guest_version: Option<&'slice [u8]>,
+    /// The version of Xen the kernel targets, as recorded by the [`XEN_ELFNOTE_XEN_VERSION`]
+    /// note.
+    pub xen_version: Option<&'slice [u8]>,
+    /// The loader type, as recorded by the [`XEN_ELFNOTE_LOADER`] note.
+    pub loader: Option<&'slice [u8]>,
+    /// The `|`-separated features the kernel supports or requires, as recorded by the
+    /// [`XEN_ELFNOTE_FEATURES`] note.
+    pub features: Option<&'slice [u8]>,
+}
+
+impl<'slice> XenNotes<'slice> {
+    /// Returns an iterator over the names of the features recorded by
+    /// [`XenNotes::features`].
+    ///
+    /// A name prefixed with `!` denotes a feature the kernel requires.
+    pub fn feature_names(&self) -> impl Iterator<Item = &'slice [u8]> {
+        self.features
+            .unwrap_or(&[])
+            .split(|&byte| byte == b'|')
+            .filter(|name| !name.is_empty())
+    }
+}
+
+impl<'slice, C: ClassParse, E: EncodingParse> ElfFile<'slice, C, E> {
+    /// Returns the [`XenNotes`] recorded by the [`ELF_NOTE_XEN`] notes of the
+    /// [`SegmentType::NOTE`][st] segments of this [`ElfFile`], or [`None`] if there are no
+    /// such notes.
+    ///
+    /// [st]: crate::raw::elf_program_header::SegmentType::NOTE
+    pub fn xen_notes(&self) -> Option<XenNotes<'slice>> {
+        let mut xen_notes = XenNotes::default();
+        let mut found = false;
+
+        for note in self
+            .segment_notes()
+            .filter(|note| note.name == ELF_NOTE_XEN)
+        {
+            found = true;
+            let descriptor = note.descriptor;
+            match note.kind {
+                XEN_ELFNOTE_ENTRY => xen_notes.entry = self.xen_numeric(descriptor),
+                XEN_ELFNOTE_HYPERCALL_PAGE => {
+                    xen_notes.hypercall_page = self.xen_numeric(descriptor);
+                }
+                XEN_ELFNOTE_VIRT_BASE => xen_notes.virt_base = self.xen_numeric(descriptor),
+                XEN_ELFNOTE_PADDR_OFFSET => xen_notes.paddr_offset = self.xen_numeric(descriptor),
+                XEN_ELFNOTE_PHYS32_ENTRY => {
+                    xen_notes.phys32_entry = self
+                        .xen_numeric(descriptor)
+                        .and_then(|entry| entry.try_into().ok());
+                }
+                XEN_ELFNOTE_GUEST_OS => xen_notes.guest_os = Some(xen_string(descriptor)),
+                XEN_ELFNOTE_GUEST_VERSION => xen_notes.guest_version = Some(xen_string(descriptor)),
+                XEN_ELFNOTE_XEN_VERSION => xen_notes.xen_version = Some(xen_string(descriptor)),
+                XEN_ELFNOTE_LOADER => xen_notes.loader = Some(xen_string(descriptor)),
+                XEN_ELFNOTE_FEATURES => xen_notes.features = Some(xen_string(descriptor)),
+                _ => {}
+            }
+        }
+
+        found.then_some(xen_notes)
+    }
+
+    /// Decodes the numeric `descriptor` of a Xen note, which is either 4 or 8 bytes long.
+    fn xen_numeric(&self, descriptor: &[u8]) -> Option<u64> {
+        match descriptor.len() {
+            4 => Some(self.encoding.parse_u32_at(0, descriptor).into()),
+            8 => Some(self.encoding.parse_u64_at(0, descriptor)),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes the string `descriptor` of a Xen note, stripping its NUL terminator.
+fn xen_string(descriptor: &[u8]) -> &[u8] {
+    let length = descriptor
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(descriptor.len());
+    descriptor.get(..length).unwrap_or(descriptor)
+}